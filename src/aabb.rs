@@ -0,0 +1,139 @@
+#[cfg(test)]
+mod tests;
+
+use num_traits::Float;
+use num_traits::One;
+use num_traits::Zero;
+#[cfg(feature = "serde")]
+use serde::Deserialize;
+#[cfg(feature = "serde")]
+use serde::Serialize;
+
+use crate::components::Components;
+use crate::VectorRefOps;
+
+/// Axis-aligned box spanned by two corner points.
+///
+/// A point on the surface is contained in the box.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Aabb<V> {
+    /// The corner with the smallest components.
+    pub min: V,
+    /// The corner with the largest components.
+    pub max: V,
+}
+
+impl<V> Aabb<V> {
+    /// Creates a box from its corners without any checks.
+    #[inline]
+    pub const fn new(min: V, max: V) -> Self {
+        Self { min, max }
+    }
+}
+
+impl<V: Components> Aabb<V>
+where
+    for<'l> &'l V: VectorRefOps<V::Cmp, V>,
+{
+    /// Creates a box from two arbitrary corners.
+    pub fn from_corners(a: V, b: V) -> Self {
+        let mut min = a;
+        let mut max = a;
+        for i in 0..V::DIM {
+            min[i] = a[i].min(b[i]);
+            max[i] = a[i].max(b[i]);
+        }
+        Self { min, max }
+    }
+
+    /// Creates a cube from its center and half of the edge length.
+    pub fn from_center(center: V, half: V::Cmp) -> Self {
+        let mut min = center;
+        let mut max = center;
+        for i in 0..V::DIM {
+            min[i] -= half;
+            max[i] += half;
+        }
+        Self { min, max }
+    }
+
+    /// The smallest box containing all the points, or `None` for no points.
+    pub fn from_points<I: IntoIterator<Item = V>>(points: I) -> Option<Self> {
+        let mut points = points.into_iter();
+        let first = points.next()?;
+        Some(points.fold(Self::new(first, first), |b, p| b.including(p)))
+    }
+
+    /// The center of the box.
+    #[inline]
+    pub fn center(&self) -> V {
+        (self.min + self.max) / (V::Cmp::one() + V::Cmp::one())
+    }
+
+    /// The diagonal vector from `min` to `max`.
+    #[inline]
+    pub fn size(&self) -> V {
+        self.max - self.min
+    }
+
+    /// Checks if `min <= max` holds for all components.
+    pub fn is_valid(&self) -> bool {
+        (0..V::DIM).all(|i| self.min[i] <= self.max[i])
+    }
+
+    /// Checks if the point is inside of the box or on its surface.
+    pub fn contains(&self, point: V) -> bool {
+        (0..V::DIM).all(|i| self.min[i] <= point[i] && point[i] <= self.max[i])
+    }
+
+    /// Checks if the another box is completely inside of this box.
+    pub fn contains_aabb(&self, other: &Self) -> bool {
+        self.contains(other.min) && self.contains(other.max)
+    }
+
+    /// Checks if the boxes share any point.
+    pub fn intersects(&self, other: &Self) -> bool {
+        (0..V::DIM).all(|i| self.min[i] <= other.max[i] && other.min[i] <= self.max[i])
+    }
+
+    /// The smallest box containing both of this box and the point.
+    pub fn including(mut self, point: V) -> Self {
+        for i in 0..V::DIM {
+            self.min[i] = self.min[i].min(point[i]);
+            self.max[i] = self.max[i].max(point[i]);
+        }
+        self
+    }
+
+    /// The smallest box containing both of the boxes.
+    pub fn union(self, other: &Self) -> Self {
+        self.including(other.min).including(other.max)
+    }
+
+    /// The nearest point in the box.
+    pub fn clamp(&self, mut point: V) -> V {
+        for i in 0..V::DIM {
+            point[i] = point[i].max(self.min[i]).min(self.max[i]);
+        }
+        point
+    }
+
+    /// The square of the euclid distance from the point, zero if inside.
+    pub fn distance_sqr(&self, point: V) -> V::Cmp {
+        (0..V::DIM)
+            .map(|i| {
+                let d = (self.min[i] - point[i])
+                    .max(point[i] - self.max[i])
+                    .max(V::Cmp::zero());
+                d * d
+            })
+            .sum()
+    }
+
+    /// The euclid distance from the point, zero if inside.
+    #[inline]
+    pub fn distance(&self, point: V) -> V::Cmp {
+        self.distance_sqr(point).sqrt()
+    }
+}
@@ -0,0 +1,86 @@
+use crate::*;
+
+#[test]
+fn from_corners() {
+    assert_eq!(
+        Aabb::from_corners(Vec2D::new(3f64, -1f64), Vec2D::new(1f64, 2f64)),
+        Aabb::new(Vec2D::new(1f64, -1f64), Vec2D::new(3f64, 2f64))
+    );
+}
+
+#[test]
+fn from_center() {
+    assert_eq!(
+        Aabb::from_center(Vec3D::new(1f32, 2f32, 3f32), 0.5f32),
+        Aabb::new(
+            Vec3D::new(0.5f32, 1.5f32, 2.5f32),
+            Vec3D::new(1.5f32, 2.5f32, 3.5f32)
+        )
+    );
+}
+
+#[test]
+fn from_points() {
+    assert_eq!(Aabb::<V2D64>::from_points([]), None);
+    assert_eq!(
+        Aabb::from_points([
+            Vec2D::new(1f64, 5f64),
+            Vec2D::new(-2f64, 3f64),
+            Vec2D::new(4f64, 4f64)
+        ]),
+        Some(Aabb::new(Vec2D::new(-2f64, 3f64), Vec2D::new(4f64, 5f64)))
+    );
+}
+
+#[test]
+fn center_and_size() {
+    let b = Aabb::new(
+        VecND::from([0f64, 2f64, -4f64]),
+        VecND::from([2f64, 6f64, 4f64]),
+    );
+    assert_eq!(b.center(), VecND::from([1f64, 4f64, 0f64]));
+    assert_eq!(b.size(), VecND::from([2f64, 4f64, 8f64]));
+    assert!(b.is_valid());
+    assert!(!Aabb::new(b.max, b.min).is_valid());
+}
+
+#[test]
+fn contains() {
+    let b = Aabb::new(Vec2D::new(0f64, 0f64), Vec2D::new(2f64, 1f64));
+    assert!(b.contains(Vec2D::new(1f64, 0.5f64)));
+    assert!(b.contains(Vec2D::new(2f64, 0f64)), "surface");
+    assert!(!b.contains(Vec2D::new(2.5f64, 0.5f64)));
+    assert!(!b.contains(Vec2D::new(1f64, -0.5f64)));
+    assert!(b.contains_aabb(&Aabb::new(Vec2D::new(0.5, 0.5), Vec2D::new(1.0, 1.0))));
+    assert!(!b.contains_aabb(&Aabb::new(Vec2D::new(0.5, 0.5), Vec2D::new(3.0, 1.0))));
+}
+
+#[test]
+fn intersects() {
+    let b = Aabb::new(Vec2D::new(0f64, 0f64), Vec2D::new(2f64, 2f64));
+    assert!(b.intersects(&Aabb::new(Vec2D::new(1.0, 1.0), Vec2D::new(3.0, 3.0))));
+    assert!(b.intersects(&Aabb::new(Vec2D::new(2.0, 0.0), Vec2D::new(3.0, 1.0))));
+    assert!(!b.intersects(&Aabb::new(Vec2D::new(2.5, 0.0), Vec2D::new(3.0, 1.0))));
+    assert!(!b.intersects(&Aabb::new(Vec2D::new(0.0, -2.0), Vec2D::new(1.0, -1.0))));
+}
+
+#[test]
+fn union() {
+    let a = Aabb::new(Vec2D::new(0f64, 0f64), Vec2D::new(1f64, 1f64));
+    let b = Aabb::new(Vec2D::new(2f64, -1f64), Vec2D::new(3f64, 0f64));
+    assert_eq!(
+        a.union(&b),
+        Aabb::new(Vec2D::new(0f64, -1f64), Vec2D::new(3f64, 1f64))
+    );
+}
+
+#[test]
+fn distance() {
+    let b = Aabb::new(Vec3D::new(0f64, 0f64, 0f64), Vec3D::new(1f64, 1f64, 1f64));
+    assert_eq!(b.distance_sqr(Vec3D::new(0.5, 0.5, 0.5)), 0f64);
+    assert_eq!(b.distance(Vec3D::new(4.0, 0.5, -4.0)), 5f64);
+    assert_eq!(
+        b.clamp(Vec3D::new(4.0, 0.5, -4.0)),
+        Vec3D::new(1.0, 0.5, 0.0)
+    );
+}
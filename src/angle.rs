@@ -0,0 +1,384 @@
+#[cfg(test)]
+mod tests;
+
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::iter::Sum;
+use std::ops::Add;
+use std::ops::AddAssign;
+use std::ops::Div;
+use std::ops::DivAssign;
+use std::ops::Mul;
+use std::ops::MulAssign;
+use std::ops::Neg;
+use std::ops::Rem;
+use std::ops::Sub;
+use std::ops::SubAssign;
+
+use num_traits::Float;
+use num_traits::Zero;
+#[cfg(feature = "serde")]
+use serde::Deserialize;
+#[cfg(feature = "serde")]
+use serde::Serialize;
+
+/// Angle in radians.
+///
+/// The angle-taking and angle-returning APIs of the crate use it, so that
+/// degrees from [`Deg`] are converted rather than mistaken for radians.
+///
+/// # Examples
+///
+/// ```
+/// use ::ndvec::*;
+///
+/// let a = Rad::from(Deg(90f64));
+/// assert!((a.sin() - 1.0).abs() < 1e-15);
+/// assert!((Deg::from(a * 3.0).normalize_signed().0 + 90.0).abs() < 1e-13);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Rad<T>(pub T);
+
+/// Angle in degrees.
+///
+/// Converted into [`Rad`] for the angle-taking APIs of the crate, e.g.
+/// `Rad::from(Deg(45.0))` or `Deg(45.0).into()`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Deg<T>(pub T);
+
+impl<T: Float> Rad<T> {
+    /// The angle `π/2` of a right angle.
+    pub fn right() -> Self {
+        Self(Self::half_turn().0 / (T::one() + T::one()))
+    }
+
+    /// The angle `π` of a half turn.
+    pub fn half_turn() -> Self {
+        Self(T::from(std::f64::consts::PI).expect("π must be representable"))
+    }
+
+    /// The angle `2π` of a full turn.
+    pub fn full_turn() -> Self {
+        Self(T::from(std::f64::consts::TAU).expect("2π must be representable"))
+    }
+
+    /// The sine of the angle.
+    #[inline]
+    pub fn sin(self) -> T {
+        self.0.sin()
+    }
+
+    /// The cosine of the angle.
+    #[inline]
+    pub fn cos(self) -> T {
+        self.0.cos()
+    }
+
+    /// The tangent of the angle.
+    #[inline]
+    pub fn tan(self) -> T {
+        self.0.tan()
+    }
+
+    /// The sine and the cosine together.
+    #[inline]
+    pub fn sin_cos(self) -> (T, T) {
+        self.0.sin_cos()
+    }
+
+    /// The angle in `[-π/2, π/2]` whose sine is the value.
+    #[inline]
+    pub fn asin(x: T) -> Self {
+        Self(x.asin())
+    }
+
+    /// The angle in `[0, π]` whose cosine is the value.
+    #[inline]
+    pub fn acos(x: T) -> Self {
+        Self(x.acos())
+    }
+
+    /// The angle in `[-π/2, π/2]` whose tangent is the value.
+    #[inline]
+    pub fn atan(x: T) -> Self {
+        Self(x.atan())
+    }
+
+    /// The angle in `[-π, π]` of the point `(x, y)` counterclockwise from
+    /// the x-axis.
+    #[inline]
+    pub fn atan2(y: T, x: T) -> Self {
+        Self(y.atan2(x))
+    }
+}
+
+impl<T: Float> Deg<T> {
+    /// The angle `90°` of a right angle.
+    pub fn right() -> Self {
+        Self(T::from(90).expect("90 must be representable"))
+    }
+
+    /// The angle `180°` of a half turn.
+    pub fn half_turn() -> Self {
+        Self(T::from(180).expect("180 must be representable"))
+    }
+
+    /// The angle `360°` of a full turn.
+    pub fn full_turn() -> Self {
+        Self(T::from(360).expect("360 must be representable"))
+    }
+
+    /// The sine of the angle, of the conversion to radians.
+    #[inline]
+    pub fn sin(self) -> T {
+        Rad::from(self).sin()
+    }
+
+    /// The cosine of the angle, of the conversion to radians.
+    #[inline]
+    pub fn cos(self) -> T {
+        Rad::from(self).cos()
+    }
+
+    /// The tangent of the angle, of the conversion to radians.
+    #[inline]
+    pub fn tan(self) -> T {
+        Rad::from(self).tan()
+    }
+
+    /// The sine and the cosine together.
+    #[inline]
+    pub fn sin_cos(self) -> (T, T) {
+        Rad::from(self).sin_cos()
+    }
+
+    /// The angle in `[-90°, 90°]` whose sine is the value.
+    #[inline]
+    pub fn asin(x: T) -> Self {
+        Rad::asin(x).into()
+    }
+
+    /// The angle in `[0°, 180°]` whose cosine is the value.
+    #[inline]
+    pub fn acos(x: T) -> Self {
+        Rad::acos(x).into()
+    }
+
+    /// The angle in `[-90°, 90°]` whose tangent is the value.
+    #[inline]
+    pub fn atan(x: T) -> Self {
+        Rad::atan(x).into()
+    }
+
+    /// The angle in `[-180°, 180°]` of the point `(x, y)` counterclockwise
+    /// from the x-axis.
+    #[inline]
+    pub fn atan2(y: T, x: T) -> Self {
+        Rad::atan2(y, x).into()
+    }
+}
+
+impl<T: Float> From<Deg<T>> for Rad<T> {
+    #[inline]
+    fn from(angle: Deg<T>) -> Self {
+        Self(angle.0.to_radians())
+    }
+}
+
+impl<T: Float> From<Rad<T>> for Deg<T> {
+    #[inline]
+    fn from(angle: Rad<T>) -> Self {
+        Self(angle.0.to_degrees())
+    }
+}
+
+macro_rules! impl_angle {
+    ($A:ident, $unit:literal) => {
+        impl<T: Float> $A<T> {
+            /// The angle wrapped into `[0, full turn)`.
+            pub fn normalize(self) -> Self {
+                let full = Self::full_turn().0;
+                let r = self.0 % full;
+                if r < T::zero() {
+                    // a tiny negative remainder rounds up to the full turn
+                    let r = r + full;
+                    Self(if r < full { r } else { T::zero() })
+                } else {
+                    Self(r)
+                }
+            }
+
+            /// The angle wrapped into `[-half turn, half turn)`, unchanged
+            /// if already inside.
+            pub fn normalize_signed(self) -> Self {
+                let (full, half) = (Self::full_turn().0, Self::half_turn().0);
+                let r = self.0 % full;
+                Self(if r >= half {
+                    r - full
+                } else if r < -half {
+                    r + full
+                } else {
+                    r
+                })
+            }
+
+            /// The angle wrapped into `[start, start + full turn)`.
+            pub fn wrap_from(self, start: Self) -> Self {
+                let a = (self - start).normalize() + start;
+                // rounding may land just past the end
+                if a < start + Self::full_turn() {
+                    a
+                } else {
+                    start
+                }
+            }
+
+            /// The difference `self - other` along the shorter arc, in
+            /// `[-half turn, half turn)`.
+            pub fn delta(self, other: Self) -> Self {
+                (self - other).normalize_signed()
+            }
+
+            /// The angle linearly interpolated by the fraction `t` along the
+            /// shorter arc.
+            pub fn lerp(self, other: Self, t: T) -> Self {
+                self + other.delta(self) * t
+            }
+
+            /// The angle of the absolute value, i.e. without the direction.
+            #[inline]
+            pub fn abs(self) -> Self {
+                Self(self.0.abs())
+            }
+
+            /// Whether the angle is neither infinite nor NaN.
+            #[inline]
+            pub fn is_finite(self) -> bool {
+                self.0.is_finite()
+            }
+        }
+
+        impl<T: Display> Display for $A<T> {
+            fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+                self.0.fmt(f)?;
+                f.write_str($unit)
+            }
+        }
+
+        impl<T: Zero> Zero for $A<T> {
+            #[inline]
+            fn zero() -> Self {
+                Self(T::zero())
+            }
+
+            #[inline]
+            fn is_zero(&self) -> bool {
+                self.0.is_zero()
+            }
+        }
+
+        impl<T: Neg<Output = T>> Neg for $A<T> {
+            type Output = Self;
+
+            #[inline]
+            fn neg(self) -> Self {
+                Self(-self.0)
+            }
+        }
+
+        impl<T: Add<Output = T>> Add for $A<T> {
+            type Output = Self;
+
+            #[inline]
+            fn add(self, rhs: Self) -> Self {
+                Self(self.0 + rhs.0)
+            }
+        }
+
+        impl<T: Sub<Output = T>> Sub for $A<T> {
+            type Output = Self;
+
+            #[inline]
+            fn sub(self, rhs: Self) -> Self {
+                Self(self.0 - rhs.0)
+            }
+        }
+
+        impl<T: Mul<Output = T>> Mul<T> for $A<T> {
+            type Output = Self;
+
+            #[inline]
+            fn mul(self, rhs: T) -> Self {
+                Self(self.0 * rhs)
+            }
+        }
+
+        impl<T: Div<Output = T>> Div<T> for $A<T> {
+            type Output = Self;
+
+            #[inline]
+            fn div(self, rhs: T) -> Self {
+                Self(self.0 / rhs)
+            }
+        }
+
+        /// The ratio of the angles.
+        impl<T: Div<Output = T>> Div for $A<T> {
+            type Output = T;
+
+            #[inline]
+            fn div(self, rhs: Self) -> T {
+                self.0 / rhs.0
+            }
+        }
+
+        impl<T: Rem<Output = T>> Rem for $A<T> {
+            type Output = Self;
+
+            #[inline]
+            fn rem(self, rhs: Self) -> Self {
+                Self(self.0 % rhs.0)
+            }
+        }
+
+        impl<T: AddAssign> AddAssign for $A<T> {
+            #[inline]
+            fn add_assign(&mut self, rhs: Self) {
+                self.0 += rhs.0;
+            }
+        }
+
+        impl<T: SubAssign> SubAssign for $A<T> {
+            #[inline]
+            fn sub_assign(&mut self, rhs: Self) {
+                self.0 -= rhs.0;
+            }
+        }
+
+        impl<T: MulAssign> MulAssign<T> for $A<T> {
+            #[inline]
+            fn mul_assign(&mut self, rhs: T) {
+                self.0 *= rhs;
+            }
+        }
+
+        impl<T: DivAssign> DivAssign<T> for $A<T> {
+            #[inline]
+            fn div_assign(&mut self, rhs: T) {
+                self.0 /= rhs;
+            }
+        }
+
+        impl<T: Zero> Sum for $A<T> {
+            #[inline]
+            fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+                Self(iter.fold(T::zero(), |a, b| a + b.0))
+            }
+        }
+    };
+}
+
+impl_angle!(Rad, " rad");
+impl_angle!(Deg, "°");
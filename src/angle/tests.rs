@@ -0,0 +1,85 @@
+use std::f64::consts::FRAC_PI_2;
+use std::f64::consts::PI;
+use std::f64::consts::TAU;
+
+use num_traits::Zero;
+
+use crate::*;
+
+#[test]
+fn conversion() {
+    assert_eq!(Rad::from(Deg(180.0)), Rad(PI));
+    assert_eq!(Deg::from(Rad(FRAC_PI_2)), Deg(90.0));
+    assert_eq!(Rad::<f64>::right(), Rad(FRAC_PI_2));
+    assert_eq!(Rad::from(Deg::<f64>::full_turn()), Rad::full_turn());
+    assert_eq!(Deg::from(Rad::<f32>::half_turn()), Deg::half_turn());
+    for x in [-720.0, -33.3, 0.0, 1.0, 45.0, 400.0] {
+        assert!((Deg::from(Rad::from(Deg(x))).0 - x).abs() < 1e-12);
+    }
+}
+
+#[test]
+fn arithmetic() {
+    let (a, b) = (Deg(30.0), Deg(45.0));
+    assert_eq!(a + b, Deg(75.0));
+    assert_eq!(a - b, Deg(-15.0));
+    assert_eq!(-a, Deg(-30.0));
+    assert_eq!(a * 3.0, Deg(90.0));
+    assert_eq!(b / 3.0, Deg(15.0));
+    assert_eq!(b / a, 1.5);
+    assert_eq!(Deg(100.0) % Deg(45.0), Deg(10.0));
+    assert_eq!([a, b, a].into_iter().sum::<Deg<f64>>(), Deg(105.0));
+    let mut c = a;
+    c += b;
+    c -= a;
+    c *= 2.0;
+    c /= 3.0;
+    assert_eq!(c, Deg(30.0));
+    assert!(Rad(1.0) < Rad(2.0));
+    assert!(Rad::<f64>::zero().is_zero());
+    assert_eq!(Deg(1.5).to_string(), "1.5°");
+    assert_eq!(Rad(1.5).to_string(), "1.5 rad");
+}
+
+#[test]
+fn wrapping() {
+    // unchanged inside of the ranges
+    for x in [0.0, 1.0, PI - 1e-15, 3.0] {
+        assert_eq!(Rad(x).normalize(), Rad(x));
+        assert_eq!(Rad(x).normalize_signed(), Rad(x));
+        assert_eq!(Rad(-x).normalize_signed(), Rad(-x));
+    }
+    assert_eq!(Deg(-90.0).normalize(), Deg(270.0));
+    assert_eq!(Deg(720.0).normalize(), Deg(0.0));
+    assert_eq!(Deg(-360.0).normalize(), Deg(0.0));
+    assert_eq!(Deg(180.0).normalize_signed(), Deg(-180.0));
+    assert_eq!(Deg(-180.0).normalize_signed(), Deg(-180.0));
+    assert_eq!(Deg(270.0).normalize_signed(), Deg(-90.0));
+    assert_eq!(Deg(-450.0).normalize_signed(), Deg(-90.0));
+    assert_eq!(Deg(10.0).wrap_from(Deg(90.0)), Deg(370.0));
+    assert_eq!(Deg(-100.0).wrap_from(Deg(-90.0)), Deg(260.0));
+    // a tiny negative angle does not round up to a full turn
+    let tiny = Rad(-1e-300).normalize();
+    assert!(tiny.0 >= 0.0 && tiny.0 < TAU);
+    assert!(Rad(f64::NAN).normalize().0.is_nan());
+
+    // the shorter arc across the cut
+    assert_eq!(Deg(170.0).delta(Deg(-170.0)), Deg(-20.0));
+    assert_eq!(Deg(-170.0).delta(Deg(170.0)), Deg(20.0));
+    assert_eq!(Deg(170.0).lerp(Deg(-170.0), 0.5).normalize(), Deg(180.0));
+    assert_eq!(Deg(10.0).lerp(Deg(30.0), 0.25), Deg(15.0));
+}
+
+#[test]
+fn trigonometry() {
+    let a = Deg(30.0);
+    assert!((a.sin() - 0.5).abs() < 1e-15);
+    assert!((Deg(60.0).cos() - 0.5).abs() < 1e-15);
+    assert!((Deg(45.0).tan() - 1.0).abs() < 1e-15);
+    assert_eq!(Rad(1.0).sin_cos(), 1f64.sin_cos());
+    assert!((Deg::asin(0.5).0 - 30.0).abs() < 1e-13);
+    assert!((Deg::acos(0.5).0 - 60.0).abs() < 1e-13);
+    assert!((Deg::atan(1.0).0 - 45.0).abs() < 1e-13);
+    assert_eq!(Rad::atan2(1.0, -1.0), Rad(3.0 * PI / 4.0));
+    assert_eq!(Deg::atan2(-1.0, 0.0), Deg(-90.0));
+}
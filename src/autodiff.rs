@@ -0,0 +1,80 @@
+//! Forward-mode automatic differentiation with [`Dual`] and [`DualN`].
+//!
+//! Unlike [`calculus`](crate::calculus), the derivatives are exact up to rounding,
+//! but the function must be generic enough to be evaluated on dual numbers.
+//!
+//! # Examples
+//!
+//! ```
+//! use ::ndvec::*;
+//! use ::ndvec::autodiff;
+//!
+//! let x = VecND::from([1.0, 2.0, 2.0]);
+//! let g = autodiff::gradient(|x| x.norm(), x);
+//! assert_eq!(g, x / 3.0);
+//! ```
+
+#[cfg(test)]
+mod tests;
+
+use num_traits::Float;
+
+use crate::Component;
+use crate::Dual;
+use crate::DualN;
+use crate::MatrixND;
+use crate::VecND;
+
+/// Lifts a point to the independent variables of [`DualN`].
+#[inline]
+fn variables<T: Float, const N: usize>(x: VecND<T, N>) -> VecND<DualN<T, N>, N> {
+    VecND::from(std::array::from_fn(|i| DualN::variable(x[i], i)))
+}
+
+/// The derivative of a scalar function `f(t)` at `t`.
+pub fn derivative<T: Float>(f: impl Fn(Dual<T>) -> Dual<T>, t: T) -> T {
+    f(Dual::variable(t, 0)).eps()
+}
+
+/// The gradient of a scalar field, evaluating `f` once per dimension.
+pub fn gradient<T: Float, const N: usize>(
+    f: impl Fn(VecND<Dual<T>, N>) -> Dual<T>,
+    x: VecND<T, N>,
+) -> VecND<T, N> {
+    VecND::from(std::array::from_fn(|j| {
+        let x = VecND::from(std::array::from_fn(|i| {
+            let d = if i == j { T::one() } else { T::zero() };
+            Dual::new(x[i], [d])
+        }));
+        f(x).eps()
+    }))
+}
+
+/// The gradient of a scalar field, evaluating `f` once on [`DualN`].
+pub fn gradient_n<T: Float, const N: usize>(
+    f: impl Fn(VecND<DualN<T, N>, N>) -> DualN<T, N>,
+    x: VecND<T, N>,
+) -> VecND<T, N> {
+    VecND::from(f(variables(x)).du)
+}
+
+/// The value and the gradient of a scalar field, evaluating `f` once on [`DualN`].
+pub fn value_and_gradient<T: Float, const N: usize>(
+    f: impl Fn(VecND<DualN<T, N>, N>) -> DualN<T, N>,
+    x: VecND<T, N>,
+) -> (T, VecND<T, N>) {
+    let y = f(variables(x));
+    (y.re, VecND::from(y.du))
+}
+
+/// The Jacobian matrix of a map `f: R^N → R^M`, whose element `(i, j)` is `∂f_i/∂x_j`.
+///
+/// The same matrix is approximated by finite differences in
+/// [`calculus::jacobian`](crate::calculus::jacobian).
+pub fn jacobian<T: Component, const N: usize, const M: usize>(
+    f: impl Fn(VecND<DualN<T, N>, N>) -> VecND<DualN<T, N>, M>,
+    x: VecND<T, N>,
+) -> MatrixND<T, M, N> {
+    let y = f(variables(x));
+    MatrixND::from_fn(|i, j| y[i].du[j])
+}
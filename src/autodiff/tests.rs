@@ -0,0 +1,82 @@
+use crate::autodiff::*;
+use crate::*;
+
+fn close(a: VecND<f64, 3>, b: VecND<f64, 3>) -> bool {
+    a.distance(b) <= 1e-12 * (1.0 + b.norm())
+}
+
+#[test]
+fn derivative_of_function() {
+    let d = derivative(|t| (t * t).sin() / t.exp(), 0.8f64);
+    let expected = (2.0 * 0.8 * 0.64f64.cos() - 0.64f64.sin()) / 0.8f64.exp();
+    assert!((d - expected).abs() < 1e-14);
+}
+
+#[test]
+fn gradient_of_norm() {
+    let x = VecND::from([1.5, -2.0, 0.5]);
+    let expected = x / x.norm();
+    assert!(close(gradient(|x| x.norm(), x), expected));
+    assert!(close(gradient_n(|x| x.norm(), x), expected));
+    let (value, g) = value_and_gradient(|x| x.norm(), x);
+    assert_eq!(value, x.norm());
+    assert!(close(g, expected));
+}
+
+#[test]
+fn gradient_of_dot_and_distance() {
+    let x = VecND::from([1.5, -2.0, 0.5]);
+    let c = VecND::from([0.25, 1.0, -3.0]);
+
+    let cd = VecND::from([0.25, 1.0, -3.0].map(Dual::constant));
+    assert_eq!(gradient(|x| x.dot(cd), x), c);
+    let cn = VecND::from([0.25, 1.0, -3.0].map(DualN::constant));
+    assert_eq!(gradient_n(|x| x.dot(cn), x), c);
+
+    let expected = (x - c) / x.distance(c);
+    assert!(close(gradient(|x| x.distance(cd), x), expected));
+    assert!(close(gradient_n(|x| x.distance(cn), x), expected));
+    assert!(close(gradient_n(|x| x.distance_sqr(cn), x), (x - c) * 2.0));
+}
+
+#[test]
+fn jacobian_of_normalization() {
+    let x = VecND::from([1.5, -2.0, 0.5]);
+    let r = x.norm();
+    let j = jacobian(|x| x / x.norm(), x);
+    for i in 0..3 {
+        for k in 0..3 {
+            let delta = if i == k { 1.0 } else { 0.0 };
+            let expected = delta / r - x[i] * x[k] / (r * r * r);
+            assert!((j[(i, k)] - expected).abs() < 1e-14);
+        }
+    }
+}
+
+#[test]
+fn jacobian_of_nonsquare_map() {
+    let x = VecND::from([0.3, 1.2]);
+    let j = jacobian(
+        |x: VecND<DualN<f64, 2>, 2>| VecND::from([x[0] * x[1], x[0].sin(), x.norm_sqr()]),
+        x,
+    );
+    assert_eq!(j.row(0), VecND::from([1.2, 0.3]));
+    assert_eq!(j.row(1), VecND::from([0.3f64.cos(), 0.0]));
+    assert_eq!(j.row(2), VecND::from([0.6, 2.4]));
+    assert_eq!(j.col(1), VecND::from([0.3, 0.0, 2.4]));
+}
+
+#[test]
+fn jacobian_agrees_with_finite_differences() {
+    let x = VecND::from([0.3, 1.2]);
+    let exact = jacobian(
+        |x: VecND<DualN<f64, 2>, 2>| VecND::from([x[0] * x[1], x[0].sin(), x.norm_sqr()]),
+        x,
+    );
+    let approx = crate::calculus::jacobian(
+        |x: VecND<f64, 2>| VecND::from([x[0] * x[1], x[0].sin(), x.norm_sqr()]),
+        x,
+        1e-2,
+    );
+    assert!((approx - exact).frobenius_norm() < 1e-12);
+}
@@ -0,0 +1,297 @@
+#[cfg(test)]
+mod tests;
+
+use num_traits::Float;
+use num_traits::One;
+use num_traits::Zero;
+
+use crate::components::Components;
+use crate::Aabb;
+use crate::Aggregate;
+use crate::ItemId;
+use crate::Orthtree;
+use crate::Vector;
+use crate::VectorRefOps;
+
+/// Monopole summary of point sources, used as the [`Aggregate`] of [`BarnesHut`].
+///
+/// Sources may have negative strength, e.g. electric charges.
+/// The centre is weighted by the absolute strength so that it stays inside
+/// of the node even when the total strength cancels out.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct MassMoment<V: Vector>
+where
+    for<'l> &'l V: VectorRefOps<V::Cmp, V>,
+{
+    /// The total strength.
+    pub mass: V::Cmp,
+    /// The total absolute strength.
+    pub abs_mass: V::Cmp,
+    /// The sum of positions weighted by the absolute strength.
+    pub moment: V,
+}
+
+impl<V: Vector> MassMoment<V>
+where
+    for<'l> &'l V: VectorRefOps<V::Cmp, V>,
+{
+    /// The centre of the sources, or `None` if there are no sources with strength.
+    #[inline]
+    pub fn center(&self) -> Option<V> {
+        (!self.abs_mass.is_zero()).then(|| self.moment / self.abs_mass)
+    }
+}
+
+impl<V: Vector> Aggregate<V, V::Cmp> for MassMoment<V>
+where
+    for<'l> &'l V: VectorRefOps<V::Cmp, V>,
+{
+    #[inline]
+    fn empty() -> Self {
+        Self {
+            mass: V::Cmp::zero(),
+            abs_mass: V::Cmp::zero(),
+            moment: V::zero(),
+        }
+    }
+
+    #[inline]
+    fn add(&mut self, position: &V, mass: &V::Cmp) {
+        self.mass += mass;
+        self.abs_mass += mass.abs();
+        self.moment += position * mass.abs();
+    }
+
+    #[inline]
+    fn merge(&mut self, other: &Self) {
+        self.mass += other.mass;
+        self.abs_mass += other.abs_mass;
+        self.moment += other.moment;
+    }
+}
+
+/// Barnes-Hut tree code evaluating inverse-square fields of point sources
+/// in `O(N log N)`.
+///
+/// With sources `m_j` at `r_j`, the field at `r` and the potential are
+///
+/// + `a(r) = Σ m_j (r_j - r) / (|r_j - r|² + ε²)^(3/2)`
+/// + `φ(r) = -Σ m_j / (|r_j - r|² + ε²)^(1/2)`
+///
+/// where `ε` is the softening length.
+/// Gravitational accelerations are `G a`; Coulomb fields of charges `q_j`
+/// are `-k a`, giving accelerations `-k q_i a / m_i`.
+///
+/// A node is approximated by its monopole when its edge length divided by
+/// the distance to its centre is less than the opening angle `θ`;
+/// `θ = 0` reproduces the direct summation.
+///
+/// # Examples
+///
+/// ```
+/// use ::ndvec::*;
+///
+/// let positions = [Vec3D::new(0.0, 0.0, 0.0), Vec3D::new(2.0, 0.0, 0.0)];
+/// let masses = [1.0, 4.0];
+/// let bh = BarnesHut::new(&positions, &masses, 0.5, 0.0);
+/// assert_eq!(bh.accelerations(), vec![Vec3D::new(1.0, 0.0, 0.0), Vec3D::new(-0.25, 0.0, 0.0)]);
+/// assert_eq!(bh.potential_at(Vec3D::new(1.0, 0.0, 0.0)), -5.0);
+/// ```
+pub struct BarnesHut<V: Vector>
+where
+    for<'l> &'l V: VectorRefOps<V::Cmp, V>,
+{
+    tree: Orthtree<V, V::Cmp, MassMoment<V>>,
+    ids: Vec<ItemId>,
+    theta: V::Cmp,
+    softening_sqr: V::Cmp,
+}
+
+impl<V: Components> BarnesHut<V>
+where
+    for<'l> &'l V: VectorRefOps<V::Cmp, V>,
+{
+    /// Builds the tree of the sources with the opening angle `theta`
+    /// and the softening length.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the lengths of `positions` and `masses` differ,
+    /// or if any position is not finite.
+    pub fn new(positions: &[V], masses: &[V::Cmp], theta: V::Cmp, softening: V::Cmp) -> Self {
+        assert_eq!(
+            positions.len(),
+            masses.len(),
+            "each position must have its mass"
+        );
+        let one = V::Cmp::one();
+        let bounds = match Aabb::from_points(positions.iter().copied()) {
+            Some(b) => {
+                let size = b.size();
+                let half = (0..V::DIM)
+                    .map(|i| size[i])
+                    .fold(V::Cmp::zero(), V::Cmp::max)
+                    / (one + one);
+                Aabb::from_center(b.center(), if half > V::Cmp::zero() { half } else { one })
+            }
+            None => Aabb::from_center(V::zero(), one),
+        };
+        let mut tree = Orthtree::new(bounds);
+        let ids = positions
+            .iter()
+            .zip(masses)
+            .map(|(&p, &m)| tree.insert(p, m))
+            .collect();
+        Self {
+            tree,
+            ids,
+            theta,
+            softening_sqr: softening * softening,
+        }
+    }
+
+    /// The underlying tree.
+    #[inline]
+    pub fn tree(&self) -> &Orthtree<V, V::Cmp, MassMoment<V>> {
+        &self.tree
+    }
+
+    /// The field at an arbitrary point.
+    pub fn acceleration_at(&self, point: V) -> V {
+        self.field(point, None)
+    }
+
+    /// The potential at an arbitrary point.
+    pub fn potential_at(&self, point: V) -> V::Cmp {
+        self.potential(point, None)
+    }
+
+    /// The field at each source caused by all the other sources.
+    pub fn accelerations(&self) -> Vec<V> {
+        self.ids
+            .iter()
+            .map(|&id| self.field(self.position(id), Some(id)))
+            .collect()
+    }
+
+    /// The potential at each source caused by all the other sources.
+    pub fn potentials(&self) -> Vec<V::Cmp> {
+        self.ids
+            .iter()
+            .map(|&id| self.potential(self.position(id), Some(id)))
+            .collect()
+    }
+
+    fn position(&self, id: ItemId) -> V {
+        self.tree.get(id).expect("sources are never removed").0
+    }
+
+    fn field(&self, point: V, skip: Option<ItemId>) -> V {
+        let mut field = V::zero();
+        self.interact(point, skip, |d, r2, m| {
+            field += d * (m / (r2 * r2.sqrt()));
+        });
+        field
+    }
+
+    fn potential(&self, point: V, skip: Option<ItemId>) -> V::Cmp {
+        let mut potential = V::Cmp::zero();
+        self.interact(point, skip, |_, r2, m| {
+            potential -= m / r2.sqrt();
+        });
+        potential
+    }
+
+    /// Calls `f(r_j - r, |r_j - r|² + ε², m_j)` for every accepted source or node.
+    fn interact(&self, point: V, skip: Option<ItemId>, mut f: impl FnMut(V, V::Cmp, V::Cmp)) {
+        let mut source = |position: V, mass: V::Cmp| {
+            let d = position - point;
+            let r2 = d.norm_sqr() + self.softening_sqr;
+            if r2 > V::Cmp::zero() {
+                f(d, r2, mass);
+            }
+        };
+        let mut stack = vec![self.tree.root()];
+        while let Some(node) = stack.pop() {
+            if node.is_leaf() {
+                for (id, position, &mass) in node.items() {
+                    if Some(id) != skip {
+                        source(position, mass);
+                    }
+                }
+                continue;
+            }
+            let aggregate = node.aggregate();
+            let Some(center) = aggregate.center() else {
+                continue;
+            };
+            let size = node.bounds().size();
+            let edge = (0..V::DIM)
+                .map(|i| size[i])
+                .fold(V::Cmp::zero(), V::Cmp::max);
+            if !node.bounds().contains(point) && edge < self.theta * center.distance(point) {
+                source(center, aggregate.mass);
+            } else {
+                stack.extend(node.children().filter(|c| !c.is_empty()));
+            }
+        }
+    }
+}
+
+/// The field at each source by direct `O(N²)` summation.
+///
+/// See [`BarnesHut`] for the definition.
+pub fn direct_accelerations<V: Vector>(
+    positions: &[V],
+    masses: &[V::Cmp],
+    softening: V::Cmp,
+) -> Vec<V>
+where
+    for<'l> &'l V: VectorRefOps<V::Cmp, V>,
+{
+    let eps2 = softening * softening;
+    positions
+        .iter()
+        .enumerate()
+        .map(|(i, &p)| {
+            positions
+                .iter()
+                .zip(masses)
+                .enumerate()
+                .filter(|&(j, (&q, _))| j != i && (q != p || eps2 > V::Cmp::zero()))
+                .map(|(_, (&q, &m))| {
+                    let d = q - p;
+                    let r2 = d.norm_sqr() + eps2;
+                    d * (m / (r2 * r2.sqrt()))
+                })
+                .sum()
+        })
+        .collect()
+}
+
+/// The potential at each source by direct `O(N²)` summation.
+///
+/// See [`BarnesHut`] for the definition.
+pub fn direct_potentials<V: Vector>(
+    positions: &[V],
+    masses: &[V::Cmp],
+    softening: V::Cmp,
+) -> Vec<V::Cmp>
+where
+    for<'l> &'l V: VectorRefOps<V::Cmp, V>,
+{
+    let eps2 = softening * softening;
+    positions
+        .iter()
+        .enumerate()
+        .map(|(i, &p)| {
+            positions
+                .iter()
+                .zip(masses)
+                .enumerate()
+                .filter(|&(j, (&q, _))| j != i && (q != p || eps2 > V::Cmp::zero()))
+                .map(|(_, (&q, &m))| -m / ((q - p).norm_sqr() + eps2).sqrt())
+                .sum()
+        })
+        .collect()
+}
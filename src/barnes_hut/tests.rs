@@ -0,0 +1,120 @@
+use crate::testing::Lcg;
+use crate::*;
+
+/// The maximum error relative to the RMS of the expected values.
+fn max_error<V: Vector<Cmp = f64>>(a: &[V], b: &[V]) -> f64
+where
+    for<'l> &'l V: VectorRefOps<V::Cmp, V>,
+{
+    let rms = (b.iter().map(|b| b.norm_sqr()).sum::<f64>() / b.len() as f64).sqrt();
+    a.iter()
+        .zip(b)
+        .map(|(a, b)| a.distance(*b))
+        .fold(0.0, f64::max)
+        / rms
+}
+
+fn plummer_like(rng: &mut Lcg, n: usize) -> (Vec<V3D64>, Vec<f64>) {
+    let positions = (0..n)
+        .map(|_| {
+            let v = Vec3D::new(rng.unit(), rng.unit(), rng.unit()) - Vec3D::new(0.5, 0.5, 0.5);
+            v * (1.0 / (0.1 + v.norm()))
+        })
+        .collect();
+    let masses = (0..n).map(|_| 0.5 + rng.unit()).collect();
+    (positions, masses)
+}
+
+#[test]
+fn exact_without_opening() {
+    let mut rng = Lcg(11);
+    let (positions, masses) = plummer_like(&mut rng, 200);
+    let bh = BarnesHut::new(&positions, &masses, 0.0, 0.01);
+    let direct = direct_accelerations(&positions, &masses, 0.01);
+    assert!(max_error(&bh.accelerations(), &direct) < 1e-12);
+
+    let direct = direct_potentials(&positions, &masses, 0.01);
+    for (a, b) in bh.potentials().iter().zip(direct) {
+        assert!((a - b).abs() < 1e-12 * b.abs());
+    }
+}
+
+#[test]
+fn accelerations_3d() {
+    let mut rng = Lcg(12);
+    let (positions, masses) = plummer_like(&mut rng, 1000);
+    let direct = direct_accelerations(&positions, &masses, 0.001);
+    let coarse = BarnesHut::new(&positions, &masses, 0.8, 0.001).accelerations();
+    let fine = BarnesHut::new(&positions, &masses, 0.3, 0.001).accelerations();
+    let coarse = max_error(&coarse, &direct);
+    let fine = max_error(&fine, &direct);
+    assert!(coarse < 2e-2, "{coarse}");
+    assert!(fine < 1e-3, "{fine}");
+    assert!(fine < coarse);
+}
+
+#[test]
+fn potentials_3d() {
+    let mut rng = Lcg(13);
+    let (positions, masses) = plummer_like(&mut rng, 500);
+    let direct = direct_potentials(&positions, &masses, 0.01);
+    let bh = BarnesHut::new(&positions, &masses, 0.5, 0.01).potentials();
+    for (a, b) in bh.iter().zip(direct) {
+        assert!((a - b).abs() < 5e-3 * b.abs(), "{a} != {b}");
+    }
+}
+
+#[test]
+fn accelerations_2d() {
+    let mut rng = Lcg(14);
+    let positions: Vec<_> = (0..800)
+        .map(|_| Vec2D::new(rng.unit(), rng.unit() * 3.0))
+        .collect();
+    let masses: Vec<_> = (0..800).map(|_| rng.unit()).collect();
+    let direct = direct_accelerations(&positions, &masses, 0.01);
+    let bh = BarnesHut::new(&positions, &masses, 0.4, 0.01).accelerations();
+    let error = max_error(&bh, &direct);
+    assert!(error < 5e-3, "{error}");
+}
+
+#[test]
+fn mixed_sign_charges() {
+    let mut rng = Lcg(15);
+    let positions: Vec<_> = (0..400)
+        .map(|_| Vec3D::new(rng.unit(), rng.unit(), rng.unit()))
+        .collect();
+    let charges: Vec<_> = (0..400)
+        .map(|i| if i % 2 == 0 { 1.0 } else { -1.0 } * (0.5 + rng.unit()))
+        .collect();
+    let probe = Vec3D::new(1.5, -0.5, 0.5);
+    let bh = BarnesHut::new(&positions, &charges, 0.15, 0.0);
+    let expected: V3D64 = positions
+        .iter()
+        .zip(&charges)
+        .map(|(p, q)| {
+            let d = p - probe;
+            d * (q / d.norm().powi(3))
+        })
+        .sum();
+    let error = bh.acceleration_at(probe).distance(expected) / expected.norm();
+    assert!(error < 3e-2, "{error}");
+}
+
+#[test]
+fn planar_bodies_in_3d() {
+    let positions: Vec<_> = (0..64)
+        .map(|i| Vec3D::new((i % 8) as f64, (i / 8) as f64, 0.0))
+        .collect();
+    let masses = vec![1.0; 64];
+    let bh = BarnesHut::new(&positions, &masses, 0.5, 0.0);
+    let direct = direct_accelerations(&positions, &masses, 0.0);
+    assert!(max_error(&bh.accelerations(), &direct) < 1e-2);
+}
+
+#[test]
+fn empty() {
+    let bh = BarnesHut::<V3D64>::new(&[], &[], 0.5, 0.1);
+    assert!(bh.accelerations().is_empty());
+    assert_eq!(bh.acceleration_at(Vec3D::new(1.0, 2.0, 3.0)), Vec3D::zero());
+    assert_eq!(bh.potential_at(Vec3D::new(1.0, 2.0, 3.0)), 0.0);
+}
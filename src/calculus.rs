@@ -0,0 +1,248 @@
+//! Numerical differentiation of scalar and vector fields.
+//!
+//! Every derivative is a central difference refined by Richardson extrapolation
+//! over [`LEVELS`] halvings of the initial step `h`, so the truncation error is
+//! `O(h^(2 LEVELS))` for smooth functions.
+//! The step should be large enough for the rounding error to stay small,
+//! e.g. `1e-2` relative to the scale of the variation of the field.
+//!
+//! # Examples
+//!
+//! ```
+//! use ::ndvec::*;
+//! use ::ndvec::calculus;
+//!
+//! let f = |p: V3D64| p.x * p.x * p.y + p.z.sin();
+//! let g = calculus::gradient(f, Vec3D::new(1.0, 2.0, 0.0), 1e-2);
+//! assert!(g.distance(Vec3D::new(4.0, 1.0, 1.0)) < 1e-12);
+//!
+//! let v = |p: V3D64| Vec3D::new(-p.y, p.x, 0.0);
+//! let c = calculus::curl(v, Vec3D::new(0.3, -0.2, 0.5), 1e-2);
+//! assert!(c.distance(Vec3D::new(0.0, 0.0, 2.0)) < 1e-12);
+//! ```
+
+#[cfg(test)]
+mod tests;
+
+use std::iter::Sum;
+use std::ops::Add;
+use std::ops::Div;
+use std::ops::Mul;
+use std::ops::Sub;
+
+use num_traits::Float;
+use num_traits::One;
+use num_traits::Zero;
+
+use crate::components::Components;
+use crate::Component;
+use crate::MatrixND;
+use crate::RefComponent;
+use crate::Vec2D;
+use crate::Vec3D;
+use crate::VecND;
+use crate::Vector;
+use crate::VectorRefOps;
+
+/// The number of steps used in Richardson extrapolation.
+pub const LEVELS: usize = 4;
+
+/// Extrapolates estimates `estimate(h)` whose error is a series of even powers of `h`
+/// to `h → 0`, halving the step `levels` times.
+///
+/// # Panics
+///
+/// Panics if `levels` is zero.
+pub fn richardson<T: Float, W>(h: T, levels: usize, estimate: impl Fn(T) -> W) -> W
+where
+    W: Copy + Add<Output = W> + Sub<Output = W> + Mul<T, Output = W>,
+{
+    assert!(levels > 0, "at least one level is needed");
+    let two = T::one() + T::one();
+    let four = two * two;
+    let mut row: Vec<W> = Vec::with_capacity(levels);
+    let mut h = h;
+    for _ in 0..levels {
+        let mut d = estimate(h);
+        let mut factor = four;
+        for previous in row.iter_mut() {
+            let lower = std::mem::replace(previous, d);
+            d = d + (d - lower) * (T::one() / (factor - T::one()));
+            factor = factor * four;
+        }
+        row.push(d);
+        h = h / two;
+    }
+    row[levels - 1]
+}
+
+/// The unit vector along the `i`-th axis.
+#[inline]
+fn axis<V: Components>(i: usize) -> V
+where
+    for<'l> &'l V: VectorRefOps<V::Cmp, V>,
+{
+    let mut e = V::zero();
+    e[i] = V::Cmp::one();
+    e
+}
+
+/// The derivative of a curve `f(t)` at `t`.
+pub fn derivative<T: Float, W>(f: impl Fn(T) -> W, t: T, h: T) -> W
+where
+    W: Copy + Add<Output = W> + Sub<Output = W> + Mul<T, Output = W>,
+{
+    let two = T::one() + T::one();
+    richardson(h, LEVELS, |h| {
+        (f(t + h) - f(t - h)) * (T::one() / (two * h))
+    })
+}
+
+/// The derivative of a field at `x` along the direction `d`, scaled by the length of `d`.
+pub fn directional_derivative<V: Vector, W>(f: impl Fn(V) -> W, x: V, d: V, h: V::Cmp) -> W
+where
+    for<'l> &'l V: VectorRefOps<V::Cmp, V>,
+    W: Copy + Add<Output = W> + Sub<Output = W> + Mul<V::Cmp, Output = W>,
+{
+    derivative(|t| f(x + d * t), V::Cmp::zero(), h)
+}
+
+/// The gradient of a scalar field.
+pub fn gradient<V: Components>(f: impl Fn(V) -> V::Cmp, x: V, h: V::Cmp) -> V
+where
+    for<'l> &'l V: VectorRefOps<V::Cmp, V>,
+{
+    let mut g = V::zero();
+    for i in 0..V::DIM {
+        g[i] = directional_derivative(&f, x, axis(i), h);
+    }
+    g
+}
+
+/// The Jacobian matrix of a map `f: V → W`, whose element `(i, j)` is `∂f_i/∂x_j`.
+///
+/// The columns `∂f/∂x_j` are laid out as in [`autodiff::jacobian`](crate::autodiff::jacobian).
+pub fn jacobian<V, W, const N: usize, const M: usize>(
+    f: impl Fn(V) -> W,
+    x: V,
+    h: V::Cmp,
+) -> MatrixND<V::Cmp, M, N>
+where
+    V: Components + Into<VecND<V::Cmp, N>>,
+    W: Vector<Cmp = V::Cmp> + Into<VecND<V::Cmp, M>>,
+    for<'l> &'l V: VectorRefOps<V::Cmp, V>,
+    for<'l> &'l W: VectorRefOps<W::Cmp, W>,
+{
+    MatrixND::from_cols(std::array::from_fn(|j| {
+        directional_derivative(&f, x, axis(j), h).into()
+    }))
+}
+
+/// The divergence of a vector field.
+pub fn divergence<V: Components>(f: impl Fn(V) -> V, x: V, h: V::Cmp) -> V::Cmp
+where
+    for<'l> &'l V: VectorRefOps<V::Cmp, V>,
+{
+    let two = V::Cmp::one() + V::Cmp::one();
+    richardson(h, LEVELS, |h| {
+        (0..V::DIM)
+            .map(|i| {
+                let e = axis::<V>(i) * h;
+                (f(x + e)[i] - f(x - e)[i]) / (two * h)
+            })
+            .sum()
+    })
+}
+
+/// The curl of a 3-dimensional vector field.
+pub fn curl<T: Component>(f: impl Fn(Vec3D<T>) -> Vec3D<T>, x: Vec3D<T>, h: T) -> Vec3D<T>
+where
+    for<'a> &'a T: RefComponent<T>,
+{
+    let [dx, dy, dz]: [Vec3D<T>; 3] = jacobian::<Vec3D<T>, Vec3D<T>, 3, 3>(f, x, h).into();
+    Vec3D::new(dy.z - dz.y, dz.x - dx.z, dx.y - dy.x)
+}
+
+/// The scalar curl `∂f_y/∂x - ∂f_x/∂y` of a 2-dimensional vector field.
+pub fn curl_2d<T: Component>(f: impl Fn(Vec2D<T>) -> Vec2D<T>, x: Vec2D<T>, h: T) -> T
+where
+    for<'a> &'a T: RefComponent<T>,
+{
+    let dx = directional_derivative::<Vec2D<T>, _>(&f, x, Vec2D::new(T::one(), T::zero()), h);
+    let dy = directional_derivative::<Vec2D<T>, _>(&f, x, Vec2D::new(T::zero(), T::one()), h);
+    dx.y - dy.x
+}
+
+/// The Hessian matrix of a scalar field, whose element `(i, j)` is `∂²f/∂x_i∂x_j`.
+pub fn hessian<V, const N: usize>(
+    f: impl Fn(V) -> V::Cmp,
+    x: V,
+    h: V::Cmp,
+) -> MatrixND<V::Cmp, N, N>
+where
+    V: Components + Into<VecND<V::Cmp, N>>,
+    for<'l> &'l V: VectorRefOps<V::Cmp, V>,
+{
+    let two = V::Cmp::one() + V::Cmp::one();
+    let four = two * two;
+    let second = |i: usize, j: usize| {
+        let a = axis::<V>(i);
+        let b = axis::<V>(j);
+        if i == j {
+            richardson(h, LEVELS, |h| {
+                (f(x + a * h) - f(x) * two + f(x - a * h)) / (h * h)
+            })
+        } else {
+            richardson(h, LEVELS, |h| {
+                let (a, b) = (a * h, b * h);
+                (f(x + a + b) - f(x + a - b) - f(x - a + b) + f(x - a - b)) / (four * h * h)
+            })
+        }
+    };
+    let mut m = MatrixND::zero();
+    for (i, j) in (0..N).flat_map(|i| (0..=i).map(move |j| (i, j))) {
+        let hij = second(i, j);
+        m[(i, j)] = hij;
+        m[(j, i)] = hij;
+    }
+    m
+}
+
+/// The Laplacian of a scalar field.
+pub fn laplacian<V: Components>(f: impl Fn(V) -> V::Cmp, x: V, h: V::Cmp) -> V::Cmp
+where
+    for<'l> &'l V: VectorRefOps<V::Cmp, V>,
+{
+    sum_of_second_derivatives(f, x, h)
+}
+
+/// The vector Laplacian of a vector field, i.e. the Laplacian of each component.
+pub fn vector_laplacian<V: Components>(f: impl Fn(V) -> V, x: V, h: V::Cmp) -> V
+where
+    for<'l> &'l V: VectorRefOps<V::Cmp, V>,
+{
+    sum_of_second_derivatives(f, x, h)
+}
+
+/// `Σ ∂²f/∂x_i²` for a field with any output, shared by the Laplacians.
+fn sum_of_second_derivatives<V: Components, W>(f: impl Fn(V) -> W, x: V, h: V::Cmp) -> W
+where
+    for<'l> &'l V: VectorRefOps<V::Cmp, V>,
+    W: Copy
+        + Sum
+        + Add<Output = W>
+        + Sub<Output = W>
+        + Mul<V::Cmp, Output = W>
+        + Div<V::Cmp, Output = W>,
+{
+    let two = V::Cmp::one() + V::Cmp::one();
+    richardson(h, LEVELS, |h| {
+        let center = f(x) * two;
+        (0..V::DIM)
+            .map(|i| {
+                let e = axis::<V>(i) * h;
+                (f(x + e) - center + f(x - e)) / (h * h)
+            })
+            .sum()
+    })
+}
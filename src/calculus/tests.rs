@@ -0,0 +1,114 @@
+use crate::calculus::*;
+use crate::*;
+
+#[test]
+fn richardson_extrapolation() {
+    // (e^h - e^-h) / 2h = 1 + h^2/6 + ...
+    let d = richardson(0.5f64, 5, |h| (h.exp() - (-h).exp()) / (2.0 * h));
+    assert!((d - 1.0).abs() < 1e-14);
+    let d = richardson(0.5f64, 1, |h| (h.exp() - (-h).exp()) / (2.0 * h));
+    assert!((d - 1.0).abs() > 1e-2);
+}
+
+#[test]
+#[should_panic]
+fn richardson_without_levels() {
+    richardson(0.1f64, 0, |h| h);
+}
+
+#[test]
+fn derivative_of_curve() {
+    let d = derivative(|t: f64| t.sin(), 1.0, 0.1);
+    assert!((d - 1f64.cos()).abs() < 1e-13);
+    let v = derivative(|t: f64| Vec2D::new(t.cos(), t * t), 2.0, 0.1);
+    assert!(v.distance(Vec2D::new(-(2f64.sin()), 4.0)) < 1e-13);
+}
+
+#[test]
+fn gradient_of_scalar_field() {
+    let f = |p: V3D64| p.x * p.y.exp() + p.z * p.z * p.x;
+    let x = Vec3D::new(1.5, -0.5, 2.0);
+    let expected = Vec3D::new((-0.5f64).exp() + 4.0, 1.5 * (-0.5f64).exp(), 6.0);
+    assert!(gradient(f, x, 1e-2).distance(expected) < 1e-11);
+
+    let f = |p: VecND<f64, 4>| p.norm_sqr();
+    let x = VecND::from([1.0, 2.0, 3.0, 4.0]);
+    assert!(gradient(f, x, 1e-1).distance(x * 2.0) < 1e-12);
+}
+
+#[test]
+fn gradient_f32() {
+    let f = |p: V2D32| p.x * p.x + 3.0 * p.y;
+    let g = gradient(f, Vec2D::new(2f32, 1f32), 0.25);
+    assert!(g.distance(Vec2D::new(4f32, 3f32)) < 1e-4);
+}
+
+#[test]
+fn directional() {
+    let f = |p: V2D64| p.x * p.y;
+    let d = directional_derivative(f, Vec2D::new(2.0, 3.0), Vec2D::new(1.0, 1.0), 1e-2);
+    assert!((d - 5.0).abs() < 1e-12);
+}
+
+#[test]
+fn jacobian_of_map() {
+    let f = |p: V2D64| Vec3D::new(p.x * p.y, p.x.sin(), p.y * p.y);
+    let j = jacobian(f, Vec2D::new(0.5, 2.0), 1e-2);
+    assert!(j.col(0).distance(VecND::from([2.0, 0.5f64.cos(), 0.0])) < 1e-12);
+    assert!(j.col(1).distance(VecND::from([0.5, 0.0, 4.0])) < 1e-12);
+    assert!(j.row(1).distance(VecND::from([0.5f64.cos(), 0.0])) < 1e-12);
+}
+
+#[test]
+fn divergence_of_field() {
+    let f = |p: V3D64| Vec3D::new(p.x * p.x, p.x * p.y, p.z.sin());
+    let x = Vec3D::new(1.0, 2.0, 0.5);
+    assert!((divergence(f, x, 1e-2) - (2.0 + 1.0 + 0.5f64.cos())).abs() < 1e-12);
+
+    let radial = |p: VecND<f64, 5>| p;
+    assert!(
+        (divergence(radial, VecND::from([1.0, -1.0, 2.0, 0.0, 3.0]), 1e-2) - 5.0).abs() < 1e-12
+    );
+}
+
+#[test]
+fn curl_of_field() {
+    let f = |p: V3D64| Vec3D::new(p.y * p.z, -p.x * p.z, p.x * p.x * p.y);
+    let x = Vec3D::new(1.0, 2.0, 3.0);
+    // (x^2 + x, y - 2xy, -z - z)
+    let expected = Vec3D::new(2.0, 2.0 - 4.0, -6.0);
+    assert!(curl(f, x, 1e-2).distance(expected) < 1e-11);
+
+    let gradient_field = |p: V3D64| Vec3D::new(2.0 * p.x * p.y, p.x * p.x, 1.0);
+    assert!(curl(gradient_field, x, 1e-2).norm() < 1e-11);
+
+    let rotation = |p: V2D64| Vec2D::new(-p.y, p.x);
+    assert!((curl_2d(rotation, Vec2D::new(0.3, 0.7), 1e-2) - 2.0).abs() < 1e-12);
+}
+
+#[test]
+fn hessian_of_scalar_field() {
+    let f = |p: V3D64| p.x * p.x * p.y + p.y * p.z.exp();
+    let x = Vec3D::new(1.0, 2.0, 0.5);
+    let e = 0.5f64.exp();
+    let expected = MatrixND::from_rows([[4.0, 2.0, 0.0], [2.0, 0.0, e], [0.0, e, 2.0 * e]]);
+    let h = hessian(f, x, 1e-1);
+    assert!(
+        (h - expected).frobenius_norm() < 1e-9,
+        "{h:?} != {expected:?}"
+    );
+    assert!(h.is_symmetric());
+}
+
+#[test]
+fn laplacian_of_scalar_field() {
+    let f = |p: V3D64| p.x * p.x + 2.0 * p.y * p.y - p.z * p.z * p.z;
+    assert!((laplacian(f, Vec3D::new(1.0, 1.0, 2.0), 1e-1) - (2.0 + 4.0 - 12.0)).abs() < 1e-9);
+
+    let harmonic = |p: V3D64| 1.0 / p.norm();
+    assert!(laplacian(harmonic, Vec3D::new(1.0, -2.0, 0.5), 1e-2).abs() < 1e-8);
+
+    let f = |p: V2D64| Vec2D::new(p.x * p.x * p.y, p.y.sin());
+    let l = vector_laplacian(f, Vec2D::new(1.0, 0.5), 1e-1);
+    assert!(l.distance(Vec2D::new(1.0, -(0.5f64.sin()))) < 1e-9);
+}
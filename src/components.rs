@@ -0,0 +1,24 @@
+use std::ops::Index;
+use std::ops::IndexMut;
+
+use crate::Vector;
+use crate::VectorRefOps;
+
+/// Vector whose components are accessible by index in `0..DIM`.
+///
+/// Implemented for every [`Vector`] that also implements [`Index`] and
+/// [`IndexMut`] over its components, including all vectors of this crate.
+/// Kept out of the public API so that [`Vector`] does not require indexing
+/// from its implementors.
+pub trait Components: Vector + Index<usize, Output = Self::Cmp> + IndexMut<usize>
+where
+    for<'l> &'l Self: VectorRefOps<Self::Cmp, Self>,
+{
+}
+
+impl<V> Components for V
+where
+    V: Vector + Index<usize, Output = V::Cmp> + IndexMut<usize>,
+    for<'l> &'l V: VectorRefOps<V::Cmp, V>,
+{
+}
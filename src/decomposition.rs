@@ -0,0 +1,593 @@
+#[cfg(test)]
+mod tests;
+
+use std::array;
+use std::cmp::Ordering;
+use std::fmt::Debug;
+use std::fmt::Formatter;
+
+use num_traits::Zero;
+
+use crate::Component;
+use crate::InnerProductSpace;
+use crate::MatrixND;
+use crate::NormedSpace;
+use crate::VecND;
+
+/// The maximum number of sweeps of the Jacobi methods, which converge
+/// quadratically within a handful of sweeps.
+const MAX_SWEEPS: usize = 64;
+
+/// LU decomposition with partial pivoting `P A = L U` of a square matrix.
+///
+/// `L` is unit lower triangular and `U` upper triangular.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Lu<T, const N: usize> {
+    /// `L` below the diagonal and `U` on and above it, as rows.
+    lu: [[T; N]; N],
+    /// The row of `A` at each row of `P A`.
+    perm: [usize; N],
+    /// The determinant of `P`.
+    sign: T,
+    /// The 1-norm of `A` for the condition number.
+    norm1: T,
+}
+
+/// Cholesky decomposition `A = L Lᵀ` of a symmetric positive definite
+/// matrix, where `L` is lower triangular with the positive diagonal.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Cholesky<T, const N: usize> {
+    /// `L` as rows.
+    l: [[T; N]; N],
+}
+
+/// QR decomposition `A = Q R` by Householder reflections.
+#[derive(Clone, Copy, PartialEq)]
+pub struct Qr<T, const R: usize, const C: usize> {
+    /// The orthogonal matrix.
+    pub q: MatrixND<T, R, R>,
+    /// The upper triangular (trapezoidal unless square) matrix.
+    pub r: MatrixND<T, R, C>,
+}
+
+/// Singular value decomposition `A = U Σ Vᵀ` by the one-sided Jacobi
+/// method.
+///
+/// The singular values are in the descending order. The columns of `U` of
+/// the zero singular values are zero, e.g. the last `C - R` of a matrix of
+/// more columns than rows.
+#[derive(Clone, Copy, PartialEq)]
+pub struct Svd<T, const R: usize, const C: usize> {
+    /// The left singular vectors as columns.
+    pub u: MatrixND<T, R, C>,
+    /// The singular values `Σ` in the descending order.
+    pub singular_values: VecND<T, C>,
+    /// The right singular vectors as columns, an orthogonal matrix.
+    pub v: MatrixND<T, C, C>,
+}
+
+/// Eigendecomposition `A = V Λ Vᵀ` of a symmetric matrix by the cyclic
+/// Jacobi method.
+#[derive(Clone, Copy, PartialEq)]
+pub struct SymmetricEigen<T, const N: usize> {
+    /// The eigenvalues `Λ` in the ascending order.
+    pub values: VecND<T, N>,
+    /// The unit eigenvectors as columns, an orthogonal matrix.
+    pub vectors: MatrixND<T, N, N>,
+}
+
+impl<T: Copy + Debug, const R: usize, const C: usize> Debug for Qr<T, R, C> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Qr")
+            .field("q", &self.q)
+            .field("r", &self.r)
+            .finish()
+    }
+}
+
+impl<T: Copy + Debug, const R: usize, const C: usize> Debug for Svd<T, R, C> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Svd")
+            .field("u", &self.u)
+            .field("singular_values", &self.singular_values)
+            .field("v", &self.v)
+            .finish()
+    }
+}
+
+impl<T: Copy + Debug, const N: usize> Debug for SymmetricEigen<T, N> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SymmetricEigen")
+            .field("values", &self.values)
+            .field("vectors", &self.vectors)
+            .finish()
+    }
+}
+
+/// `(c, s)` of the Jacobi rotation which zeroes the off-diagonal element
+/// `a_pq` of `[[a_pp, a_pq], [a_pq, a_qq]]`.
+pub(crate) fn jacobi_rotation<T: Component>(a_pp: T, a_qq: T, a_pq: T) -> (T, T) {
+    let two = T::one() + T::one();
+    let theta = (a_qq - a_pp) / (two * a_pq);
+    let t = theta.signum() / (theta.abs() + theta.hypot(T::one()));
+    let c = t.hypot(T::one()).recip();
+    (c, t * c)
+}
+
+/// The indices of the values in the order, NaN last.
+fn sorted_order<T: Component, const N: usize>(values: &[T; N], descending: bool) -> [usize; N] {
+    let mut order: [usize; N] = array::from_fn(|i| i);
+    order.sort_by(|&i, &j| {
+        let (a, b) = if descending {
+            (values[j], values[i])
+        } else {
+            (values[i], values[j])
+        };
+        a.partial_cmp(&b).unwrap_or(Ordering::Equal)
+    });
+    order
+}
+
+/// The index of the maximum absolute value in the range.
+fn argmax_abs<T: Component>(range: std::ops::Range<usize>, f: impl Fn(usize) -> T) -> usize {
+    let start = range.start;
+    range
+        .max_by(|&i, &j| {
+            f(i).abs()
+                .partial_cmp(&f(j).abs())
+                .unwrap_or(Ordering::Equal)
+        })
+        .unwrap_or(start)
+}
+
+impl<T: Component, const R: usize, const C: usize> MatrixND<T, R, C> {
+    /// The QR decomposition by Householder reflections.
+    pub fn qr(self) -> Qr<T, R, C> {
+        let mut a = self.rows();
+        let mut q: [[T; R]; R] = MatrixND::<T, R, R>::identity().rows();
+        let two = T::one() + T::one();
+        for k in 0..R.min(C) {
+            let norm = (k..R).map(|i| a[i][k] * a[i][k]).sum::<T>().sqrt();
+            // reflect onto the opposite sign to avoid cancellation
+            let alpha = if a[k][k] > T::zero() { -norm } else { norm };
+            let mut v = [T::zero(); R];
+            for i in k..R {
+                v[i] = a[i][k];
+            }
+            v[k] -= alpha;
+            let vv = (k..R).map(|i| v[i] * v[i]).sum::<T>();
+            if vv.is_zero() {
+                continue;
+            }
+            let f: [T; C] = array::from_fn(|j| {
+                if j < k {
+                    T::zero()
+                } else {
+                    two * (k..R).map(|i| v[i] * a[i][j]).sum::<T>() / vv
+                }
+            });
+            for (row, vi) in a.iter_mut().zip(v).skip(k) {
+                for (x, fj) in row.iter_mut().zip(f).skip(k) {
+                    *x -= fj * vi;
+                }
+            }
+            for row in q.iter_mut() {
+                let f = two * (k..R).map(|i| row[i] * v[i]).sum::<T>() / vv;
+                for i in k..R {
+                    row[i] -= f * v[i];
+                }
+            }
+            a[k][k] = alpha;
+            for row in a.iter_mut().skip(k + 1) {
+                row[k] = T::zero();
+            }
+        }
+        Qr {
+            q: MatrixND::from_rows(q),
+            r: MatrixND::from_rows(a),
+        }
+    }
+
+    /// The singular value decomposition by the one-sided Jacobi method,
+    /// accurate even for the small singular values.
+    ///
+    /// The matrix is scaled by its largest absolute element in advance, so
+    /// that the squared norms of the columns neither overflow nor underflow.
+    pub fn svd(self) -> Svd<T, R, C> {
+        let scale = (0..R)
+            .flat_map(|i| (0..C).map(move |j| (i, j)))
+            .fold(T::zero(), |m, (i, j)| m.max(self[(i, j)].abs()));
+        let scale = if scale.is_zero() || !scale.is_finite() {
+            T::one()
+        } else {
+            scale
+        };
+        let mut u = self.cols().map(|c| c / scale);
+        let mut v = MatrixND::<T, C, C>::identity().cols();
+        for _ in 0..MAX_SWEEPS {
+            let mut rotated = false;
+            for p in 0..C {
+                for q in p + 1..C {
+                    let (alpha, beta) = (u[p].norm_sqr(), u[q].norm_sqr());
+                    let gamma = u[p].dot(u[q]);
+                    if gamma.abs() <= T::epsilon() * alpha.sqrt() * beta.sqrt() {
+                        continue;
+                    }
+                    rotated = true;
+                    // the rotation which diagonalizes the Gram matrix
+                    let (c, s) = jacobi_rotation(alpha, beta, gamma);
+                    let (up, uq) = (u[p], u[q]);
+                    u[p] = up * c - uq * s;
+                    u[q] = up * s + uq * c;
+                    let (vp, vq) = (v[p], v[q]);
+                    v[p] = vp * c - vq * s;
+                    v[q] = vp * s + vq * c;
+                }
+            }
+            if !rotated {
+                break;
+            }
+        }
+        let sigma = u.map(|c| c.norm());
+        let u: [VecND<T, R>; C] = array::from_fn(|j| {
+            if sigma[j].is_zero() {
+                VecND::zero()
+            } else {
+                u[j] / sigma[j]
+            }
+        });
+        let order = sorted_order(&sigma, true);
+        Svd {
+            u: MatrixND::from_cols(order.map(|j| u[j])),
+            singular_values: VecND::from(order.map(|j| sigma[j] * scale)),
+            v: MatrixND::from_cols(order.map(|j| v[j])),
+        }
+    }
+
+    /// The condition number in the 2-norm, the ratio of the largest to the
+    /// smallest singular value.
+    ///
+    /// Infinite if the matrix is singular or has more columns than rows.
+    pub fn condition_number(self) -> T {
+        self.svd().condition_number()
+    }
+}
+
+impl<T: Component, const N: usize> MatrixND<T, N, N> {
+    /// The LU decomposition with partial pivoting, or `None` if the matrix
+    /// is singular, i.e. a pivot is zero.
+    pub fn lu(self) -> Option<Lu<T, N>> {
+        let mut a = self.rows();
+        let mut perm: [usize; N] = array::from_fn(|i| i);
+        let mut sign = T::one();
+        for k in 0..N {
+            let p = argmax_abs(k..N, |i| a[i][k]);
+            if a[p][k].is_zero() || a[p][k].is_nan() {
+                return None;
+            }
+            if p != k {
+                a.swap(p, k);
+                perm.swap(p, k);
+                sign = -sign;
+            }
+            let pivot = a[k];
+            for row in a.iter_mut().skip(k + 1) {
+                let f = row[k] / pivot[k];
+                row[k] = f;
+                for (x, u) in row.iter_mut().zip(pivot).skip(k + 1) {
+                    *x -= f * u;
+                }
+            }
+        }
+        Some(Lu {
+            lu: a,
+            perm,
+            sign,
+            norm1: self.norm1(),
+        })
+    }
+
+    /// The Cholesky decomposition of the symmetric matrix given by the lower
+    /// triangle, or `None` unless that matrix is positive definite.
+    ///
+    /// The upper triangle is not read, so a non-symmetric matrix is not
+    /// rejected; check [`is_symmetric`](MatrixND::is_symmetric) if needed.
+    /// About twice as fast as [`lu`](MatrixND::lu) and stable without pivoting.
+    pub fn cholesky(self) -> Option<Cholesky<T, N>> {
+        let a = self.rows();
+        let mut l = [[T::zero(); N]; N];
+        for j in 0..N {
+            let d = a[j][j] - (0..j).map(|k| l[j][k] * l[j][k]).sum::<T>();
+            if d.is_nan() || d <= T::zero() {
+                return None;
+            }
+            l[j][j] = d.sqrt();
+            for i in j + 1..N {
+                let s = a[i][j] - (0..j).map(|k| l[i][k] * l[j][k]).sum::<T>();
+                l[i][j] = s / l[j][j];
+            }
+        }
+        Some(Cholesky { l })
+    }
+
+    /// The eigendecomposition of the symmetric matrix by the cyclic Jacobi
+    /// method, accurate to the rounding of the elements.
+    ///
+    /// The matrix is symmetrized as `(A + Aᵀ) / 2`.
+    pub fn symmetric_eigen(self) -> SymmetricEigen<T, N> {
+        let half = (T::one() + T::one()).recip();
+        let mut a = ((self + self.transpose()) * half).rows();
+        let mut v = MatrixND::<T, N, N>::identity().rows();
+        for _ in 0..MAX_SWEEPS {
+            let mut rotated = false;
+            for p in 0..N {
+                for q in p + 1..N {
+                    // negligible relative to the diagonal, without overflow of the product
+                    let diagonal = a[p][p].abs().sqrt() * a[q][q].abs().sqrt();
+                    if a[p][q].abs() <= T::epsilon() * half * diagonal {
+                        continue;
+                    }
+                    rotated = true;
+                    let (c, s) = jacobi_rotation(a[p][p], a[q][q], a[p][q]);
+                    for row in a.iter_mut().chain(v.iter_mut()) {
+                        let (kp, kq) = (row[p], row[q]);
+                        row[p] = c * kp - s * kq;
+                        row[q] = s * kp + c * kq;
+                    }
+                    let (ap, aq) = (a[p], a[q]);
+                    a[p] = array::from_fn(|k| c * ap[k] - s * aq[k]);
+                    a[q] = array::from_fn(|k| s * ap[k] + c * aq[k]);
+                    a[p][q] = T::zero();
+                    a[q][p] = T::zero();
+                }
+            }
+            if !rotated {
+                break;
+            }
+        }
+        let values: [T; N] = array::from_fn(|i| a[i][i]);
+        let vectors = MatrixND::from_rows(v);
+        let order = sorted_order(&values, false);
+        SymmetricEigen {
+            values: VecND::from(order.map(|i| values[i])),
+            vectors: MatrixND::from_cols(order.map(|j| vectors.col(j))),
+        }
+    }
+}
+
+impl<T: Component, const N: usize> Lu<T, N> {
+    /// The unit lower triangular `L`.
+    pub fn l(&self) -> MatrixND<T, N, N> {
+        MatrixND::from_fn(|i, j| match i.cmp(&j) {
+            Ordering::Greater => self.lu[i][j],
+            Ordering::Equal => T::one(),
+            Ordering::Less => T::zero(),
+        })
+    }
+
+    /// The upper triangular `U`.
+    pub fn u(&self) -> MatrixND<T, N, N> {
+        MatrixND::from_fn(|i, j| if i <= j { self.lu[i][j] } else { T::zero() })
+    }
+
+    /// The permutation `P`: the row of `A` at each row of `P A`.
+    pub fn permutation(&self) -> [usize; N] {
+        self.perm
+    }
+
+    /// The solution `x` of `A x = b`.
+    pub fn solve(&self, b: &VecND<T, N>) -> VecND<T, N> {
+        let mut x = self.perm.map(|p| b[p]);
+        for i in 0..N {
+            for k in 0..i {
+                let xk = x[k];
+                x[i] -= self.lu[i][k] * xk;
+            }
+        }
+        for i in (0..N).rev() {
+            for k in i + 1..N {
+                let xk = x[k];
+                x[i] -= self.lu[i][k] * xk;
+            }
+            x[i] /= self.lu[i][i];
+        }
+        VecND::from(x)
+    }
+
+    /// The solution `x` of `Aᵀ x = b`.
+    fn solve_transpose(&self, b: &VecND<T, N>) -> VecND<T, N> {
+        // Aᵀ = Uᵀ Lᵀ P
+        let mut w: [T; N] = (*b).into();
+        for i in 0..N {
+            for k in 0..i {
+                let wk = w[k];
+                w[i] -= self.lu[k][i] * wk;
+            }
+            w[i] /= self.lu[i][i];
+        }
+        for i in (0..N).rev() {
+            for k in i + 1..N {
+                let wk = w[k];
+                w[i] -= self.lu[k][i] * wk;
+            }
+        }
+        let mut x = [T::zero(); N];
+        for (i, &p) in self.perm.iter().enumerate() {
+            x[p] = w[i];
+        }
+        VecND::from(x)
+    }
+
+    /// The determinant of `A`.
+    pub fn determinant(&self) -> T {
+        (0..N).fold(self.sign, |d, i| d * self.lu[i][i])
+    }
+
+    /// The inverse of `A`.
+    pub fn inverse(&self) -> MatrixND<T, N, N> {
+        MatrixND::from_cols(
+            MatrixND::<T, N, N>::identity()
+                .cols()
+                .map(|e| self.solve(&e)),
+        )
+    }
+
+    /// Estimate of the condition number `‖A‖₁ ‖A⁻¹‖₁` in the 1-norm by the
+    /// method of Hager and Higham, without computing the inverse.
+    ///
+    /// Usually exact and never more than the condition number.
+    pub fn condition_estimate(&self) -> T {
+        let n = T::from(N).expect("the dimension must be representable");
+        let norm1 = |v: VecND<T, N>| (0..N).map(|i| v[i].abs()).sum::<T>();
+        let mut x = VecND::from([n.recip(); N]);
+        let mut estimate = T::zero();
+        for _ in 0..5 {
+            let y = self.solve(&x);
+            estimate = estimate.max(norm1(y));
+            let xi = VecND::from(array::from_fn(|i| {
+                if y[i] < T::zero() {
+                    -T::one()
+                } else {
+                    T::one()
+                }
+            }));
+            let z = self.solve_transpose(&xi);
+            let j = argmax_abs(0..N, |i| z[i]);
+            if z[j].abs() <= z.dot(x) {
+                break;
+            }
+            x = VecND::zero();
+            x[j] = T::one();
+        }
+        // the alternating vector catches what the iteration misses
+        if N > 1 {
+            let alt = VecND::from(array::from_fn(|i| {
+                let s = if i % 2 == 0 { T::one() } else { -T::one() };
+                let f = T::from(i).expect("the index must be representable");
+                s * (T::one() + f / (n - T::one()))
+            }));
+            let three = T::one() + T::one() + T::one();
+            let alt = (T::one() + T::one()) * norm1(self.solve(&alt)) / (three * n);
+            estimate = estimate.max(alt);
+        }
+        estimate * self.norm1
+    }
+}
+
+impl<T: Component, const N: usize> Cholesky<T, N> {
+    /// The lower triangular `L`.
+    pub fn l(&self) -> MatrixND<T, N, N> {
+        MatrixND::from_rows(self.l)
+    }
+
+    /// The solution `x` of `A x = b`.
+    pub fn solve(&self, b: &VecND<T, N>) -> VecND<T, N> {
+        let mut x: [T; N] = (*b).into();
+        for i in 0..N {
+            for k in 0..i {
+                let xk = x[k];
+                x[i] -= self.l[i][k] * xk;
+            }
+            x[i] /= self.l[i][i];
+        }
+        for i in (0..N).rev() {
+            for k in i + 1..N {
+                let xk = x[k];
+                x[i] -= self.l[k][i] * xk;
+            }
+            x[i] /= self.l[i][i];
+        }
+        VecND::from(x)
+    }
+
+    /// The determinant of `A`, the squared product of the diagonal of `L`.
+    pub fn determinant(&self) -> T {
+        let d = (0..N).fold(T::one(), |d, i| d * self.l[i][i]);
+        d * d
+    }
+
+    /// The inverse of `A`.
+    pub fn inverse(&self) -> MatrixND<T, N, N> {
+        MatrixND::from_cols(
+            MatrixND::<T, N, N>::identity()
+                .cols()
+                .map(|e| self.solve(&e)),
+        )
+    }
+}
+
+impl<T: Component, const R: usize, const C: usize> Qr<T, R, C> {
+    /// The least squares solution `x` minimizing `‖A x - b‖`, or `None` if
+    /// the matrix has more columns than rows or is rank deficient.
+    ///
+    /// The rank is decided by the diagonal of `R` relative to its largest
+    /// element, so use [`Svd::solve`] for a matrix of the rank in doubt.
+    pub fn solve(&self, b: &VecND<T, R>) -> Option<VecND<T, C>> {
+        if R < C {
+            return None;
+        }
+        let size = T::from(R.max(C)).expect("the dimension must be representable");
+        let largest = (0..C).fold(T::zero(), |m, i| m.max(self.r[(i, i)].abs()));
+        let tolerance = T::epsilon() * size * largest;
+        let qtb = self.q.transpose() * *b;
+        let mut x = [T::zero(); C];
+        for i in (0..C).rev() {
+            let s = qtb[i] - (i + 1..C).map(|k| self.r[(i, k)] * x[k]).sum::<T>();
+            if self.r[(i, i)].abs() <= tolerance {
+                return None;
+            }
+            x[i] = s / self.r[(i, i)];
+        }
+        Some(VecND::from(x))
+    }
+}
+
+impl<T: Component, const R: usize, const C: usize> Svd<T, R, C> {
+    /// The tolerance below which singular values are treated as zero.
+    fn tolerance(&self) -> T {
+        if C == 0 {
+            return T::zero();
+        }
+        let size = T::from(R.max(C)).expect("the dimension must be representable");
+        T::epsilon() * size * self.singular_values[0]
+    }
+
+    /// The numerical rank, the number of the singular values above the
+    /// rounding of the largest one.
+    pub fn rank(&self) -> usize {
+        let tol = self.tolerance();
+        (0..C).filter(|&i| self.singular_values[i] > tol).count()
+    }
+
+    /// The condition number in the 2-norm, infinite if singular.
+    pub fn condition_number(&self) -> T {
+        if C == 0 {
+            return T::one();
+        }
+        if R < C {
+            return T::infinity();
+        }
+        self.singular_values[0] / self.singular_values[C - 1]
+    }
+
+    /// The Moore-Penrose pseudo-inverse `V Σ⁺ Uᵀ`, treating the singular
+    /// values below the rounding of the largest one as zero.
+    pub fn pseudo_inverse(&self) -> MatrixND<T, C, R> {
+        let tol = self.tolerance();
+        let sigma_inv = MatrixND::from_diagonal(VecND::from(array::from_fn(|i| {
+            let s = self.singular_values[i];
+            if s > tol {
+                s.recip()
+            } else {
+                T::zero()
+            }
+        })));
+        self.v * sigma_inv * self.u.transpose()
+    }
+
+    /// The least squares solution `x` of the minimum norm minimizing
+    /// `‖A x - b‖`, even if rank deficient.
+    pub fn solve(&self, b: &VecND<T, R>) -> VecND<T, C> {
+        self.pseudo_inverse() * *b
+    }
+}
@@ -0,0 +1,253 @@
+use crate::testing::Lcg;
+use crate::*;
+
+fn distance<const R: usize, const C: usize>(a: MatrixND<f64, R, C>, b: MatrixND<f64, R, C>) -> f64 {
+    (a - b).frobenius_norm()
+}
+
+fn is_orthogonal<const N: usize>(q: MatrixND<f64, N, N>) -> bool {
+    distance(q.transpose() * q, MatrixND::identity()) < 1e-13
+}
+
+#[test]
+fn lu() {
+    let mut rng = Lcg(1);
+    for _ in 0..100 {
+        let a: MatrixND<f64, 4, 4> = rng.matrix();
+        let lu = a.lu().unwrap();
+        let (l, u, p) = (lu.l(), lu.u(), lu.permutation());
+        let pa = MatrixND::from_fn(|i, j| a[(p[i], j)]);
+        assert!(distance(pa, l * u) < 1e-14);
+        for i in 0..4 {
+            assert_eq!(l[(i, i)], 1.0);
+            for j in i + 1..4 {
+                assert_eq!(l[(i, j)], 0.0);
+                assert_eq!(u[(j, i)], 0.0);
+                // partial pivoting bounds the multipliers
+                assert!(l[(j, i)].abs() <= 1.0);
+            }
+        }
+        let b = rng.vector();
+        assert!((a * lu.solve(&b)).distance(b) < 1e-12);
+        assert!(distance(a * lu.inverse(), MatrixND::identity()) < 1e-11);
+        // the determinant is multiplicative
+        let c: MatrixND<f64, 4, 4> = rng.matrix();
+        let det = (a * c).determinant();
+        assert!((det - a.determinant() * c.determinant()).abs() < 1e-13 * (1.0 + det.abs()));
+    }
+    let a = MatrixND::from_rows([[0.0, 1.0], [1.0, 0.0]]);
+    assert_eq!(a.lu().unwrap().permutation(), [1, 0]);
+    assert_eq!(a.determinant(), -1.0);
+    assert!(MatrixND::from_rows([[1.0, 2.0], [2.0, 4.0]]).lu().is_none());
+    assert!(MatrixND::<f64, 2, 2>::zero().lu().is_none());
+    assert!(MatrixND::from_rows([[f64::NAN, 0.0], [0.0, 1.0]])
+        .lu()
+        .is_none());
+}
+
+#[test]
+fn condition_estimate() {
+    let mut rng = Lcg(2);
+    for _ in 0..100 {
+        let a: MatrixND<f64, 5, 5> = rng.matrix();
+        let lu = a.lu().unwrap();
+        let exact = a.norm1() * lu.inverse().norm1();
+        let estimate = lu.condition_estimate();
+        // a lower bound, which is almost always sharp
+        assert!(estimate <= exact * (1.0 + 1e-12));
+        assert!(estimate >= exact / 3.0, "{estimate} vs {exact}");
+    }
+    let ill = MatrixND::from_rows([[1.0, 1.0], [1.0, 1.0 + 1e-10]]);
+    assert!(ill.lu().unwrap().condition_estimate() > 1e10);
+    assert_eq!(
+        MatrixND::<f64, 3, 3>::identity()
+            .lu()
+            .unwrap()
+            .condition_estimate(),
+        1.0
+    );
+}
+
+#[test]
+fn cholesky() {
+    let mut rng = Lcg(3);
+    for _ in 0..100 {
+        let m: MatrixND<f64, 4, 4> = rng.matrix();
+        let a = m.transpose() * m + MatrixND::identity();
+        let ch = a.cholesky().unwrap();
+        let l = ch.l();
+        assert!(distance(l * l.transpose(), a) < 1e-14);
+        for i in 0..4 {
+            assert!(l[(i, i)] > 0.0);
+            for j in i + 1..4 {
+                assert_eq!(l[(i, j)], 0.0);
+            }
+        }
+        let b = rng.vector();
+        assert!((a * ch.solve(&b)).distance(b) < 1e-14);
+        assert!(distance(ch.inverse(), a.inverse().unwrap()) < 1e-13);
+        assert!((ch.determinant() - a.determinant()).abs() < 1e-13 * a.determinant());
+    }
+    // indefinite
+    assert!(MatrixND::from_rows([[1.0, 2.0], [2.0, 1.0]])
+        .cholesky()
+        .is_none());
+    // semidefinite
+    assert!(MatrixND::from_rows([[1.0, 1.0], [1.0, 1.0]])
+        .cholesky()
+        .is_none());
+}
+
+#[test]
+fn qr() {
+    let mut rng = Lcg(4);
+    for _ in 0..100 {
+        let a: MatrixND<f64, 4, 3> = rng.matrix();
+        let Qr { q, r } = a.qr();
+        assert!(is_orthogonal(q));
+        assert!(distance(q * r, a) < 1e-14);
+        for j in 0..3 {
+            for i in j + 1..4 {
+                assert_eq!(r[(i, j)], 0.0);
+            }
+        }
+        // the least squares residual is orthogonal to the columns
+        let b = rng.vector();
+        let x = a.qr().solve(&b).unwrap();
+        let residual = a * x - b;
+        assert!((a.transpose() * residual).norm() < 1e-14);
+        assert!(x.distance(a.svd().solve(&b)) < 1e-12);
+
+        let s: MatrixND<f64, 3, 3> = rng.matrix();
+        let b = rng.vector();
+        assert!((s * s.qr().solve(&b).unwrap()).distance(b) < 1e-12);
+    }
+    // an exact fit of the line through the points
+    let a = MatrixND::from_rows([[1.0, 0.0], [1.0, 1.0], [1.0, 2.0], [1.0, 3.0]]);
+    let x = a.qr().solve(&VecND::from([1.0, 3.0, 5.0, 7.0])).unwrap();
+    assert!(x.distance(VecND::from([1.0, 2.0])) < 1e-14);
+    // wide or rank deficient
+    assert!(MatrixND::from_rows([[1.0, 2.0, 3.0]])
+        .qr()
+        .solve(&VecND::from([1.0]))
+        .is_none());
+    let a = MatrixND::from_rows([[1.0, 2.0], [2.0, 4.0], [0.0, 0.0]]);
+    assert!(a.qr().solve(&VecND::from([1.0, 1.0, 1.0])).is_none());
+    // rank deficient to the rounding
+    let a = MatrixND::from_rows([[1.0, 2.0], [2.0, 4.0], [3.0, 6.0]]);
+    assert_ne!(a.qr().r[(1, 1)], 0.0);
+    assert!(a.qr().solve(&VecND::from([1.0, 1.0, 1.0])).is_none());
+}
+
+#[test]
+fn svd() {
+    let mut rng = Lcg(5);
+    for _ in 0..100 {
+        let a: MatrixND<f64, 4, 3> = rng.matrix();
+        let Svd {
+            u,
+            singular_values: s,
+            v,
+        } = a.svd();
+        assert!(distance(u * MatrixND::from_diagonal(s) * v.transpose(), a) < 1e-14);
+        assert!(is_orthogonal(v));
+        assert!(distance(u.transpose() * u, MatrixND::identity()) < 1e-13);
+        for i in 1..3 {
+            assert!(s[i - 1] >= s[i]);
+        }
+        assert!(s[2] >= 0.0);
+        assert!((s.norm() - a.frobenius_norm()).abs() < 1e-14);
+        // the pseudo-inverse of the full column rank
+        let p = a.svd().pseudo_inverse();
+        assert!(distance(p * a, MatrixND::identity()) < 1e-12);
+        assert_eq!(a.svd().rank(), 3);
+
+        let sq: MatrixND<f64, 3, 3> = rng.matrix();
+        let exact = sq.frobenius_norm() * sq.inverse().unwrap().frobenius_norm();
+        // the 2-norm condition number is within the factor n of the
+        // Frobenius one
+        let k = sq.condition_number();
+        assert!(k >= 1.0);
+        assert!(k <= exact * (1.0 + 1e-12) && exact <= 3.0 * k * (1.0 + 1e-12));
+    }
+    let a = MatrixND::from_rows([[3.0, 0.0], [0.0, -4.0]]);
+    let svd = a.svd();
+    assert_eq!(svd.singular_values, VecND::from([4.0, 3.0]));
+    assert!((svd.condition_number() - 4.0 / 3.0).abs() < 1e-15);
+
+    // rank deficient
+    let a = MatrixND::from_rows([[1.0, 2.0, 3.0], [2.0, 4.0, 6.0], [1.0, 0.0, 1.0]]);
+    let svd = a.svd();
+    assert_eq!(svd.rank(), 2);
+    assert!(svd.singular_values[2] < 1e-15);
+    assert!(a.condition_number() > 1e15);
+    let p = svd.pseudo_inverse();
+    // the Moore-Penrose conditions
+    assert!(distance(a * p * a, a) < 1e-13);
+    assert!(distance(p * a * p, p) < 1e-13);
+    assert!(distance(a * p, (a * p).transpose()) < 1e-14);
+    // the minimum norm solution lies in the row space
+    let x = svd.solve(&VecND::from([1.0, 2.0, 0.0]));
+    assert!((a * x).distance(VecND::from([1.0, 2.0, 0.0])) < 1e-14);
+    assert!(x.dot(VecND::from([1.0, 1.0, -1.0])).abs() < 1e-14);
+
+    // more columns than rows
+    let a = MatrixND::from_rows([[1.0, 0.0, 1.0], [0.0, 1.0, 0.0]]);
+    let svd = a.svd();
+    assert_eq!(svd.rank(), 2);
+    assert_eq!(svd.condition_number(), f64::INFINITY);
+    assert_eq!(svd.u.col(2), VecND::zero());
+    assert!(distance(a * svd.pseudo_inverse(), MatrixND::identity()) < 1e-15);
+}
+
+#[test]
+fn symmetric_eigen() {
+    let mut rng = Lcg(6);
+    for _ in 0..100 {
+        let m: MatrixND<f64, 5, 5> = rng.matrix();
+        let a = m + m.transpose();
+        let SymmetricEigen { values, vectors } = a.symmetric_eigen();
+        assert!(is_orthogonal(vectors));
+        assert!(distance(a * vectors, vectors * MatrixND::from_diagonal(values)) < 1e-13);
+        for i in 1..5 {
+            assert!(values[i - 1] <= values[i]);
+        }
+        assert!(((0..5).map(|i| values[i]).sum::<f64>() - a.trace()).abs() < 1e-13);
+    }
+    let a = MatrixND::from_rows([[2.0, 1.0], [1.0, 2.0]]);
+    let e = a.symmetric_eigen();
+    assert!(e.values.distance(VecND::from([1.0, 3.0])) < 1e-15);
+    let v = e.vectors.col(1);
+    assert!((v[0] - v[1]).abs() < 1e-15);
+    // repeated eigenvalues
+    let e = MatrixND::<f64, 3, 3>::identity().symmetric_eigen();
+    assert_eq!(e.values, VecND::from([1.0; 3]));
+    assert!(is_orthogonal(e.vectors));
+}
+
+#[test]
+fn large_and_small_scales() {
+    let a = MatrixND::from_rows([[2.0, 1.0], [1.0, 2.0]]);
+    for scale in [1e160, 1e-170, 1e300, 1e-300] {
+        let b = a * scale;
+        let e = b.symmetric_eigen();
+        let values = e.values / scale;
+        assert!(
+            values.distance(VecND::from([1.0, 3.0])) < 1e-15,
+            "{scale:e}"
+        );
+        assert!(is_orthogonal(e.vectors));
+        assert!((e.vectors.col(1)[0] - e.vectors.col(1)[1]).abs() < 1e-15);
+
+        let svd = b.svd();
+        let values = svd.singular_values / scale;
+        assert!(
+            values.distance(VecND::from([3.0, 1.0])) < 1e-15,
+            "{scale:e}"
+        );
+        assert!(is_orthogonal(svd.u));
+        assert!(is_orthogonal(svd.v));
+        assert!((svd.condition_number() - 3.0).abs() < 1e-14);
+        assert_eq!(svd.rank(), 2);
+    }
+}
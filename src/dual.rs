@@ -0,0 +1,720 @@
+#[cfg(test)]
+mod tests;
+
+use std::cmp::Ordering;
+use std::fmt::Display;
+use std::iter::Sum;
+use std::num::FpCategory;
+use std::ops::Add;
+use std::ops::AddAssign;
+use std::ops::Div;
+use std::ops::DivAssign;
+use std::ops::Mul;
+use std::ops::MulAssign;
+use std::ops::Neg;
+use std::ops::Rem;
+use std::ops::RemAssign;
+use std::ops::Sub;
+use std::ops::SubAssign;
+
+use num_traits::Float;
+use num_traits::Num;
+use num_traits::NumCast;
+use num_traits::One;
+use num_traits::ToPrimitive;
+use num_traits::Zero;
+#[cfg(feature = "serde_arrays")]
+use serde::Deserialize;
+#[cfg(feature = "serde_arrays")]
+use serde::Serialize;
+
+/// Dual number `re + Σ du_i ε_i` with `ε_i ε_j = 0`, for forward-mode automatic
+/// differentiation along `N` directions at once.
+///
+/// It implements [`Float`] and is therefore a [`Component`](crate::Component),
+/// so every method of [`Vector`](crate::Vector) propagates derivatives.
+/// Comparisons and classifications only look at the real part.
+///
+/// # Examples
+///
+/// ```
+/// use ::ndvec::*;
+///
+/// let x: Vec2D<DualN<f64, 2>> = Vec2D::new(DualN::variable(3.0, 0), DualN::variable(4.0, 1));
+/// let r = x.norm();
+/// assert_eq!(r.re, 5.0);
+/// assert!((r.du[0] - 0.6).abs() < 1e-15 && (r.du[1] - 0.8).abs() < 1e-15);
+/// ```
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde_arrays", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serde_arrays",
+    serde(bound(serialize = "T: Serialize", deserialize = "T: Deserialize<'de>"))
+)]
+pub struct DualN<T, const N: usize> {
+    /// The real part.
+    pub re: T,
+    /// The infinitesimal parts, i.e. the derivatives along each direction.
+    #[cfg_attr(feature = "serde_arrays", serde(with = "serde_arrays"))]
+    pub du: [T; N],
+}
+
+/// Dual number `re + du ε` with `ε² = 0`, differentiating along a single direction.
+///
+/// # Examples
+///
+/// ```
+/// use ::ndvec::*;
+/// use num_traits::Float;
+///
+/// let t = Dual::variable(0.5f64, 0);
+/// let y = (t * t).sin();
+/// assert_eq!(y.re, 0.25f64.sin());
+/// assert_eq!(y.eps(), 2.0 * 0.5 * 0.25f64.cos());
+/// ```
+pub type Dual<T> = DualN<T, 1>;
+
+impl<T, const N: usize> DualN<T, N> {
+    /// Creates a dual number from its parts.
+    #[inline]
+    pub const fn new(re: T, du: [T; N]) -> Self {
+        Self { re, du }
+    }
+}
+
+impl<T: Zero + Copy, const N: usize> DualN<T, N> {
+    /// Creates a constant, whose derivatives are all zero.
+    #[inline]
+    pub fn constant(re: T) -> Self {
+        Self {
+            re,
+            du: [T::zero(); N],
+        }
+    }
+}
+
+impl<T: Zero + One + Copy, const N: usize> DualN<T, N> {
+    /// Creates the `i`-th independent variable, whose derivative is one along
+    /// the `i`-th direction and zero along the others.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `i` is not less than `N`.
+    #[inline]
+    pub fn variable(re: T, i: usize) -> Self {
+        let mut du = [T::zero(); N];
+        du[i] = T::one();
+        Self { re, du }
+    }
+}
+
+impl<T: Copy> DualN<T, 1> {
+    /// The derivative along the single direction.
+    #[inline]
+    pub fn eps(&self) -> T {
+        self.du[0]
+    }
+}
+
+impl<T: Float, const N: usize> DualN<T, N> {
+    /// Applies a function with the value `f` and the derivative `df` at the real part.
+    #[inline]
+    fn chain(self, f: T, df: T) -> Self {
+        Self {
+            re: f,
+            du: self.du.map(|d| d * df),
+        }
+    }
+}
+
+impl<T: PartialEq, const N: usize> PartialEq for DualN<T, N> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.re == other.re
+    }
+}
+
+impl<T: PartialOrd, const N: usize> PartialOrd for DualN<T, N> {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.re.partial_cmp(&other.re)
+    }
+}
+
+impl<T: Display, const N: usize> Display for DualN<T, N> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.re.fmt(f)?;
+        for (i, d) in self.du.iter().enumerate() {
+            write!(f, " + ")?;
+            d.fmt(f)?;
+            if N == 1 {
+                write!(f, "ε")?;
+            } else {
+                write!(f, "ε{i}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<T: Float, const N: usize> Neg for DualN<T, N> {
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self {
+        Self {
+            re: -self.re,
+            du: self.du.map(|d| -d),
+        }
+    }
+}
+
+impl<T: Float, const N: usize> Neg for &DualN<T, N> {
+    type Output = DualN<T, N>;
+
+    #[inline]
+    fn neg(self) -> DualN<T, N> {
+        -*self
+    }
+}
+
+impl<T: Float, const N: usize> Add for DualN<T, N> {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, rhs: Self) -> Self {
+        Self {
+            re: self.re + rhs.re,
+            du: std::array::from_fn(|i| self.du[i] + rhs.du[i]),
+        }
+    }
+}
+
+impl<T: Float, const N: usize> Sub for DualN<T, N> {
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, rhs: Self) -> Self {
+        Self {
+            re: self.re - rhs.re,
+            du: std::array::from_fn(|i| self.du[i] - rhs.du[i]),
+        }
+    }
+}
+
+impl<T: Float, const N: usize> Mul for DualN<T, N> {
+    type Output = Self;
+
+    #[inline]
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn mul(self, rhs: Self) -> Self {
+        Self {
+            re: self.re * rhs.re,
+            du: std::array::from_fn(|i| self.du[i] * rhs.re + self.re * rhs.du[i]),
+        }
+    }
+}
+
+impl<T: Float, const N: usize> Div for DualN<T, N> {
+    type Output = Self;
+
+    #[inline]
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn div(self, rhs: Self) -> Self {
+        let re = self.re / rhs.re;
+        Self {
+            re,
+            du: std::array::from_fn(|i| (self.du[i] - re * rhs.du[i]) / rhs.re),
+        }
+    }
+}
+
+impl<T: Float, const N: usize> Rem for DualN<T, N> {
+    type Output = Self;
+
+    #[inline]
+    fn rem(self, rhs: Self) -> Self {
+        let q = (self.re / rhs.re).trunc();
+        Self {
+            re: self.re % rhs.re,
+            du: std::array::from_fn(|i| self.du[i] - q * rhs.du[i]),
+        }
+    }
+}
+
+macro_rules! impl_ref_ops {
+    ($($Op:ident, $op:ident, $OpAssign:ident, $op_assign:ident);+) => {
+        $(
+            impl<T: Float, const N: usize> $Op<&DualN<T, N>> for DualN<T, N> {
+                type Output = DualN<T, N>;
+
+                #[inline]
+                fn $op(self, rhs: &DualN<T, N>) -> DualN<T, N> {
+                    self.$op(*rhs)
+                }
+            }
+
+            impl<T: Float, const N: usize> $Op<DualN<T, N>> for &DualN<T, N> {
+                type Output = DualN<T, N>;
+
+                #[inline]
+                fn $op(self, rhs: DualN<T, N>) -> DualN<T, N> {
+                    (*self).$op(rhs)
+                }
+            }
+
+            impl<T: Float, const N: usize> $Op<&DualN<T, N>> for &DualN<T, N> {
+                type Output = DualN<T, N>;
+
+                #[inline]
+                fn $op(self, rhs: &DualN<T, N>) -> DualN<T, N> {
+                    (*self).$op(*rhs)
+                }
+            }
+
+            impl<T: Float, const N: usize> $OpAssign for DualN<T, N> {
+                #[inline]
+                fn $op_assign(&mut self, rhs: DualN<T, N>) {
+                    *self = (*self).$op(rhs);
+                }
+            }
+
+            impl<T: Float, const N: usize> $OpAssign<&DualN<T, N>> for DualN<T, N> {
+                #[inline]
+                fn $op_assign(&mut self, rhs: &DualN<T, N>) {
+                    *self = (*self).$op(*rhs);
+                }
+            }
+        )+
+    };
+}
+
+impl_ref_ops!(
+    Add, add, AddAssign, add_assign;
+    Sub, sub, SubAssign, sub_assign;
+    Mul, mul, MulAssign, mul_assign;
+    Div, div, DivAssign, div_assign;
+    Rem, rem, RemAssign, rem_assign
+);
+
+impl<T: Float, const N: usize> Sum for DualN<T, N> {
+    #[inline]
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::zero(), |a, b| a + b)
+    }
+}
+
+impl<T: Float, const N: usize> Zero for DualN<T, N> {
+    #[inline]
+    fn zero() -> Self {
+        Self::constant(T::zero())
+    }
+
+    #[inline]
+    fn is_zero(&self) -> bool {
+        self.re.is_zero()
+    }
+}
+
+impl<T: Float, const N: usize> One for DualN<T, N> {
+    #[inline]
+    fn one() -> Self {
+        Self::constant(T::one())
+    }
+}
+
+impl<T: Float, const N: usize> Num for DualN<T, N> {
+    type FromStrRadixErr = T::FromStrRadixErr;
+
+    #[inline]
+    fn from_str_radix(str: &str, radix: u32) -> Result<Self, Self::FromStrRadixErr> {
+        T::from_str_radix(str, radix).map(Self::constant)
+    }
+}
+
+impl<T: Float, const N: usize> ToPrimitive for DualN<T, N> {
+    #[inline]
+    fn to_i64(&self) -> Option<i64> {
+        self.re.to_i64()
+    }
+
+    #[inline]
+    fn to_u64(&self) -> Option<u64> {
+        self.re.to_u64()
+    }
+
+    #[inline]
+    fn to_f64(&self) -> Option<f64> {
+        self.re.to_f64()
+    }
+}
+
+impl<T: Float, const N: usize> NumCast for DualN<T, N> {
+    #[inline]
+    fn from<P: ToPrimitive>(n: P) -> Option<Self> {
+        T::from(n).map(Self::constant)
+    }
+}
+
+impl<T: Float, const N: usize> Float for DualN<T, N> {
+    #[inline]
+    fn nan() -> Self {
+        Self::constant(T::nan())
+    }
+
+    #[inline]
+    fn infinity() -> Self {
+        Self::constant(T::infinity())
+    }
+
+    #[inline]
+    fn neg_infinity() -> Self {
+        Self::constant(T::neg_infinity())
+    }
+
+    #[inline]
+    fn neg_zero() -> Self {
+        Self::constant(T::neg_zero())
+    }
+
+    #[inline]
+    fn min_value() -> Self {
+        Self::constant(T::min_value())
+    }
+
+    #[inline]
+    fn min_positive_value() -> Self {
+        Self::constant(T::min_positive_value())
+    }
+
+    #[inline]
+    fn epsilon() -> Self {
+        Self::constant(T::epsilon())
+    }
+
+    #[inline]
+    fn max_value() -> Self {
+        Self::constant(T::max_value())
+    }
+
+    #[inline]
+    fn is_nan(self) -> bool {
+        self.re.is_nan()
+    }
+
+    #[inline]
+    fn is_infinite(self) -> bool {
+        self.re.is_infinite()
+    }
+
+    #[inline]
+    fn is_finite(self) -> bool {
+        self.re.is_finite()
+    }
+
+    #[inline]
+    fn is_normal(self) -> bool {
+        self.re.is_normal()
+    }
+
+    #[inline]
+    fn classify(self) -> FpCategory {
+        self.re.classify()
+    }
+
+    #[inline]
+    fn floor(self) -> Self {
+        Self::constant(self.re.floor())
+    }
+
+    #[inline]
+    fn ceil(self) -> Self {
+        Self::constant(self.re.ceil())
+    }
+
+    #[inline]
+    fn round(self) -> Self {
+        Self::constant(self.re.round())
+    }
+
+    #[inline]
+    fn trunc(self) -> Self {
+        Self::constant(self.re.trunc())
+    }
+
+    #[inline]
+    fn fract(self) -> Self {
+        self.chain(self.re.fract(), T::one())
+    }
+
+    #[inline]
+    fn abs(self) -> Self {
+        if self.re.is_sign_negative() {
+            -self
+        } else {
+            self
+        }
+    }
+
+    #[inline]
+    fn signum(self) -> Self {
+        Self::constant(self.re.signum())
+    }
+
+    #[inline]
+    fn is_sign_positive(self) -> bool {
+        self.re.is_sign_positive()
+    }
+
+    #[inline]
+    fn is_sign_negative(self) -> bool {
+        self.re.is_sign_negative()
+    }
+
+    #[inline]
+    fn mul_add(self, a: Self, b: Self) -> Self {
+        self * a + b
+    }
+
+    #[inline]
+    fn recip(self) -> Self {
+        let r = self.re.recip();
+        self.chain(r, -r * r)
+    }
+
+    #[inline]
+    fn powi(self, n: i32) -> Self {
+        if n == 0 {
+            return Self::one();
+        }
+        let n_t = T::from(n).expect("i32 must be representable");
+        let d = match n.checked_sub(1) {
+            Some(m) => self.re.powi(m),
+            None => self.re.powi(n) / self.re,
+        };
+        self.chain(self.re.powi(n), n_t * d)
+    }
+
+    fn powf(self, n: Self) -> Self {
+        let re = self.re.powf(n.re);
+        let base = if n.re.is_zero() {
+            T::zero()
+        } else {
+            n.re * self.re.powf(n.re - T::one())
+        };
+        let exponent = if n.du.iter().all(|d| d.is_zero()) {
+            T::zero()
+        } else {
+            re * self.re.ln()
+        };
+        Self {
+            re,
+            du: std::array::from_fn(|i| self.du[i] * base + n.du[i] * exponent),
+        }
+    }
+
+    #[inline]
+    fn sqrt(self) -> Self {
+        let s = self.re.sqrt();
+        self.chain(s, (s + s).recip())
+    }
+
+    #[inline]
+    fn exp(self) -> Self {
+        let e = self.re.exp();
+        self.chain(e, e)
+    }
+
+    #[inline]
+    fn exp2(self) -> Self {
+        let e = self.re.exp2();
+        self.chain(e, e * (T::one() + T::one()).ln())
+    }
+
+    #[inline]
+    fn ln(self) -> Self {
+        self.chain(self.re.ln(), self.re.recip())
+    }
+
+    #[inline]
+    fn log(self, base: Self) -> Self {
+        self.ln() / base.ln()
+    }
+
+    #[inline]
+    fn log2(self) -> Self {
+        self.chain(
+            self.re.log2(),
+            (self.re * (T::one() + T::one()).ln()).recip(),
+        )
+    }
+
+    #[inline]
+    fn log10(self) -> Self {
+        self.chain(
+            self.re.log10(),
+            (self.re * T::from(10).expect("10 must be representable").ln()).recip(),
+        )
+    }
+
+    #[inline]
+    fn to_degrees(self) -> Self {
+        self.chain(self.re.to_degrees(), T::one().to_degrees())
+    }
+
+    #[inline]
+    fn to_radians(self) -> Self {
+        self.chain(self.re.to_radians(), T::one().to_radians())
+    }
+
+    #[inline]
+    fn max(self, other: Self) -> Self {
+        if self.re.is_nan() || other.re > self.re {
+            other
+        } else {
+            self
+        }
+    }
+
+    #[inline]
+    fn min(self, other: Self) -> Self {
+        if self.re.is_nan() || other.re < self.re {
+            other
+        } else {
+            self
+        }
+    }
+
+    #[inline]
+    fn abs_sub(self, other: Self) -> Self {
+        if self.re > other.re {
+            self - other
+        } else {
+            Self::zero()
+        }
+    }
+
+    #[inline]
+    fn cbrt(self) -> Self {
+        let c = self.re.cbrt();
+        self.chain(
+            c,
+            (c * c * T::from(3).expect("3 must be representable")).recip(),
+        )
+    }
+
+    #[inline]
+    fn hypot(self, other: Self) -> Self {
+        let h = self.re.hypot(other.re);
+        Self {
+            re: h,
+            du: std::array::from_fn(|i| (self.re * self.du[i] + other.re * other.du[i]) / h),
+        }
+    }
+
+    #[inline]
+    fn sin(self) -> Self {
+        let (s, c) = self.re.sin_cos();
+        self.chain(s, c)
+    }
+
+    #[inline]
+    fn cos(self) -> Self {
+        let (s, c) = self.re.sin_cos();
+        self.chain(c, -s)
+    }
+
+    #[inline]
+    fn tan(self) -> Self {
+        let t = self.re.tan();
+        self.chain(t, T::one() + t * t)
+    }
+
+    #[inline]
+    fn asin(self) -> Self {
+        self.chain(
+            self.re.asin(),
+            (T::one() - self.re * self.re).sqrt().recip(),
+        )
+    }
+
+    #[inline]
+    fn acos(self) -> Self {
+        self.chain(
+            self.re.acos(),
+            -(T::one() - self.re * self.re).sqrt().recip(),
+        )
+    }
+
+    #[inline]
+    fn atan(self) -> Self {
+        self.chain(self.re.atan(), (T::one() + self.re * self.re).recip())
+    }
+
+    #[inline]
+    fn atan2(self, other: Self) -> Self {
+        let r2 = self.re * self.re + other.re * other.re;
+        Self {
+            re: self.re.atan2(other.re),
+            du: std::array::from_fn(|i| (other.re * self.du[i] - self.re * other.du[i]) / r2),
+        }
+    }
+
+    #[inline]
+    fn sin_cos(self) -> (Self, Self) {
+        let (s, c) = self.re.sin_cos();
+        (self.chain(s, c), self.chain(c, -s))
+    }
+
+    #[inline]
+    fn exp_m1(self) -> Self {
+        self.chain(self.re.exp_m1(), self.re.exp())
+    }
+
+    #[inline]
+    fn ln_1p(self) -> Self {
+        self.chain(self.re.ln_1p(), (T::one() + self.re).recip())
+    }
+
+    #[inline]
+    fn sinh(self) -> Self {
+        self.chain(self.re.sinh(), self.re.cosh())
+    }
+
+    #[inline]
+    fn cosh(self) -> Self {
+        self.chain(self.re.cosh(), self.re.sinh())
+    }
+
+    #[inline]
+    fn tanh(self) -> Self {
+        let t = self.re.tanh();
+        self.chain(t, T::one() - t * t)
+    }
+
+    #[inline]
+    fn asinh(self) -> Self {
+        self.chain(
+            self.re.asinh(),
+            (self.re * self.re + T::one()).sqrt().recip(),
+        )
+    }
+
+    #[inline]
+    fn acosh(self) -> Self {
+        self.chain(
+            self.re.acosh(),
+            (self.re * self.re - T::one()).sqrt().recip(),
+        )
+    }
+
+    #[inline]
+    fn atanh(self) -> Self {
+        self.chain(self.re.atanh(), (T::one() - self.re * self.re).recip())
+    }
+
+    #[inline]
+    fn integer_decode(self) -> (u64, i16, i8) {
+        self.re.integer_decode()
+    }
+}
@@ -0,0 +1,167 @@
+#![allow(clippy::op_ref)]
+
+use num_traits::Float;
+
+use crate::*;
+
+fn close(a: f64, b: f64) -> bool {
+    (a - b).abs() <= 1e-12 * (1.0 + b.abs())
+}
+
+/// Checks `f'(x)` against a central difference of the real parts.
+fn check(f: impl Fn(Dual<f64>) -> Dual<f64>, x: f64) {
+    let h = 1e-6;
+    let y = f(Dual::variable(x, 0));
+    let fd = (f(Dual::constant(x + h)).re - f(Dual::constant(x - h)).re) / (2.0 * h);
+    assert!(
+        (y.eps() - fd).abs() < 1e-6 * (1.0 + fd.abs()),
+        "{} vs {}",
+        y.eps(),
+        fd
+    );
+}
+
+#[test]
+fn arithmetic() {
+    let a = Dual::new(3.0, [2.0]);
+    let b = Dual::new(-2.0, [0.5]);
+    assert_eq!((a + b).du, [2.5]);
+    assert_eq!((a - &b).du, [1.5]);
+    assert_eq!((&a * b).du, [2.0 * -2.0 + 3.0 * 0.5]);
+    assert_eq!((&a / &b).re, -1.5);
+    assert_eq!((a / b).du, [(2.0 * -2.0 - 3.0 * 0.5) / 4.0]);
+    assert_eq!((-a).du, [-2.0]);
+    let mut c = a;
+    c *= b;
+    c += &a;
+    assert_eq!(c, a * b + a);
+    assert_eq!(c.du, (a * b + a).du);
+    assert_eq!(
+        Dual::new(7.5, [1.0]) % Dual::constant(2.0),
+        Dual::new(1.5, [1.0])
+    );
+    assert_eq!((Dual::new(7.5, [1.0]) % Dual::constant(2.0)).du, [1.0]);
+}
+
+#[test]
+fn elementary_functions() {
+    let x = 0.7;
+    check(|x| x.sqrt(), x);
+    check(|x| x.cbrt(), x);
+    check(|x| x.exp(), x);
+    check(|x| x.exp2(), x);
+    check(|x| x.exp_m1(), x);
+    check(|x| x.ln(), x);
+    check(|x| x.ln_1p(), x);
+    check(|x| x.log2(), x);
+    check(|x| x.log10(), x);
+    check(|x| x.log(Dual::constant(3.0)), x);
+    check(|x| x.recip(), x);
+    check(|x| x.powi(3), x);
+    check(|x| x.powi(-2), x);
+    check(|x| x.powf(Dual::constant(2.5)), x);
+    check(|x| x.powf(x), x);
+    check(|x| Dual::constant(2.0).powf(x), x);
+    check(|x| x.sin(), x);
+    check(|x| x.cos(), x);
+    check(|x| x.tan(), x);
+    check(|x| x.asin(), x);
+    check(|x| x.acos(), x);
+    check(|x| x.atan(), x);
+    check(|x| x.atan2(Dual::constant(-0.3)), x);
+    check(|x| Dual::constant(-0.3).atan2(x), x);
+    check(|x| x.sinh(), x);
+    check(|x| x.cosh(), x);
+    check(|x| x.tanh(), x);
+    check(|x| x.asinh(), x);
+    check(|x| (x + Dual::constant(1.0)).acosh(), x);
+    check(|x| x.atanh(), x);
+    check(|x| x.hypot(Dual::constant(0.4)), x);
+    check(|x| (-x).abs(), x);
+    check(|x| x.to_degrees(), x);
+    check(|x| x.mul_add(x, x), x);
+    check(|x| x.sin_cos().0 * x.sin_cos().1, x);
+}
+
+#[test]
+fn powers_of_zero() {
+    let y = Dual::variable(0.0f64, 0).powf(Dual::constant(2.0));
+    assert_eq!((y.re, y.eps()), (0.0, 0.0));
+    let y = Dual::variable(0.0f64, 0).powi(1);
+    assert_eq!((y.re, y.eps()), (0.0, 1.0));
+    let y = Dual::variable(5.0f64, 0).powi(0);
+    assert_eq!((y.re, y.eps()), (1.0, 0.0));
+}
+
+#[test]
+fn powi_of_smallest_exponent() {
+    let y = Dual::variable(1.0f64, 0).powi(i32::MIN);
+    assert_eq!((y.re, y.eps()), (1.0, i32::MIN as f64));
+    let y = Dual::variable(-1.0f64, 0).powi(i32::MIN);
+    assert_eq!((y.re, y.eps()), (1.0, -(i32::MIN as f64)));
+}
+
+#[test]
+fn comparisons_use_real_part() {
+    let a = Dual::new(1.0, [5.0]);
+    let b = Dual::new(2.0, [-5.0]);
+    assert!(a < b);
+    assert_eq!(a, Dual::new(1.0, [0.0]));
+    assert_eq!(a.max(b).du, [-5.0]);
+    assert_eq!(a.min(b).du, [5.0]);
+    assert!(Dual::<f64>::nan().is_nan());
+    assert!(!Dual::<f64>::infinity().is_finite());
+}
+
+#[test]
+fn casts_and_display() {
+    let a: Dual<f64> = num_traits::cast(3).unwrap();
+    assert_eq!((a.re, a.eps()), (3.0, 0.0));
+    assert_eq!(
+        num_traits::ToPrimitive::to_i32(&Dual::new(2.5, [1.0])),
+        Some(2)
+    );
+    assert_eq!(Dual::new(1.5, [2.0]).to_string(), "1.5 + 2ε");
+    assert_eq!(DualN::new(1.0, [2.0, 3.0]).to_string(), "1 + 2ε0 + 3ε1");
+    let s: Dual<f64> = [Dual::new(1.0, [1.0]), Dual::new(2.0, [3.0])]
+        .into_iter()
+        .sum();
+    assert_eq!(s.du, [4.0]);
+}
+
+#[test]
+fn vector_norm_dot_distance() {
+    let p = [1.5, -2.0, 0.5];
+    let q = [0.25, 1.0, -3.0];
+    let x = Vec3D::new(
+        DualN::variable(p[0], 0),
+        DualN::variable(p[1], 1),
+        DualN::variable(p[2], 2),
+    );
+    let c = Vec3D::new(
+        DualN::constant(q[0]),
+        DualN::constant(q[1]),
+        DualN::constant(q[2]),
+    );
+    let r = (p[0] * p[0] + p[1] * p[1] + p[2] * p[2]).sqrt();
+
+    let n = x.norm();
+    assert!(close(n.re, r));
+    for (d, p) in n.du.into_iter().zip(p) {
+        assert!(close(d, p / r));
+    }
+
+    let d = x.dot(c);
+    assert_eq!(d.du, q);
+
+    let s = x.norm_sqr();
+    assert_eq!(s.du, [2.0 * p[0], 2.0 * p[1], 2.0 * p[2]]);
+
+    let dist = x.distance(c);
+    let diff = [p[0] - q[0], p[1] - q[1], p[2] - q[2]];
+    let l = (diff[0] * diff[0] + diff[1] * diff[1] + diff[2] * diff[2]).sqrt();
+    assert!(close(dist.re, l));
+    for (d, diff) in dist.du.into_iter().zip(diff) {
+        assert!(close(d, diff / l));
+    }
+}
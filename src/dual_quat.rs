@@ -0,0 +1,250 @@
+#[cfg(test)]
+mod tests;
+
+use std::ops::Mul;
+use std::ops::MulAssign;
+
+use num_traits::Zero;
+#[cfg(feature = "serde")]
+use serde::Deserialize;
+#[cfg(feature = "serde")]
+use serde::Serialize;
+
+use crate::Component;
+use crate::InnerProductSpace;
+use crate::Isometry3;
+use crate::RefComponent;
+use crate::Rotor3;
+use crate::Se3;
+use crate::Vec3D;
+
+/// Unit dual quaternion `r + ε d` of a rigid motion: the rotation `r` and
+/// the dual part `d = t r / 2` of the translation `t` applied after it.
+///
+/// Unlike matrices, dual quaternions can be blended linearly into a rigid
+/// motion, e.g. for skinning, see [`blend`](DualQuat::blend).
+///
+/// # Examples
+///
+/// ```
+/// use std::f64::consts::FRAC_PI_2;
+/// use ::ndvec::*;
+///
+/// let r = Rotor3::from_axis_angle(Vec3D::new(0.0, 0.0, 1.0), Rad(FRAC_PI_2));
+/// let a = DualQuat::from_translation(Vec3D::new(1.0, 0.0, 0.0));
+/// let b = DualQuat::new(r, Vec3D::new(0.0, 0.0, 2.0));
+/// // the screw motion halfway: a quarter turn and a half of the lift
+/// let m = a.sclerp(b, 0.5);
+/// let p = m.transform_point(Vec3D::new(0.0, 0.0, 0.0));
+/// assert!((p.z - 1.0).abs() < 1e-15);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct DualQuat<T> {
+    real: Quat<T>,
+    dual: Quat<T>,
+}
+
+/// Quaternion `w + v` of the scalar and the vector part.
+///
+/// The rotor `(s, b)` is the quaternion `(s, -b)`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+struct Quat<T> {
+    w: T,
+    v: Vec3D<T>,
+}
+
+impl<T: Component> Quat<T>
+where
+    for<'a> &'a T: RefComponent<T>,
+{
+    #[inline]
+    fn new(w: T, v: Vec3D<T>) -> Self {
+        Self { w, v }
+    }
+
+    #[inline]
+    fn mul(self, rhs: Self) -> Self {
+        Self::new(
+            self.w * rhs.w - self.v.dot(rhs.v),
+            rhs.v * self.w + self.v * rhs.w + self.v.cross(rhs.v),
+        )
+    }
+
+    #[inline]
+    fn conjugate(self) -> Self {
+        Self::new(self.w, -self.v)
+    }
+
+    #[inline]
+    fn dot(self, rhs: Self) -> T {
+        self.w * rhs.w + self.v.dot(rhs.v)
+    }
+
+    #[inline]
+    fn scale(self, k: T) -> Self {
+        Self::new(self.w * k, self.v * k)
+    }
+
+    #[inline]
+    fn add(self, rhs: Self) -> Self {
+        Self::new(self.w + rhs.w, self.v + rhs.v)
+    }
+}
+
+impl<T: Component> DualQuat<T>
+where
+    for<'a> &'a T: RefComponent<T>,
+{
+    /// The rotation by the rotor, then the translation.
+    pub fn new(rotation: Rotor3<T>, translation: Vec3D<T>) -> Self {
+        let real = Quat::new(rotation.s, -rotation.b);
+        let half = (T::one() + T::one()).recip();
+        let dual = Quat::new(T::zero(), translation * half).mul(real);
+        Self { real, dual }
+    }
+
+    /// The motion of no rotation or translation.
+    pub fn identity() -> Self {
+        Self::new(Rotor3::identity(), Vec3D::zero())
+    }
+
+    /// The rotation around the origin only.
+    pub fn from_rotation(rotation: Rotor3<T>) -> Self {
+        Self::new(rotation, Vec3D::zero())
+    }
+
+    /// The translation only.
+    pub fn from_translation(translation: Vec3D<T>) -> Self {
+        Self::new(Rotor3::identity(), translation)
+    }
+
+    /// The rotation, applied first.
+    pub fn rotation(self) -> Rotor3<T> {
+        Rotor3::new(self.real.w, -self.real.v)
+    }
+
+    /// The translation `2 d r*`, applied after the rotation.
+    pub fn translation(self) -> Vec3D<T> {
+        let t = self.dual.mul(self.real.conjugate()).v;
+        t + t
+    }
+
+    /// Rotates and translates the point.
+    pub fn transform_point(self, p: Vec3D<T>) -> Vec3D<T> {
+        self.transform_vector(p) + self.translation()
+    }
+
+    /// Rotates the direction, ignoring the translation.
+    pub fn transform_vector(self, v: Vec3D<T>) -> Vec3D<T> {
+        self.rotation().rotate(v)
+    }
+
+    /// The inverse motion, the conjugate of both parts for a unit dual
+    /// quaternion.
+    pub fn inverse(self) -> Self {
+        Self {
+            real: self.real.conjugate(),
+            dual: self.dual.conjugate(),
+        }
+    }
+
+    /// Scales to the unit dual quaternion, whose real part is of the unit
+    /// norm and orthogonal to the dual part, to remove accumulated rounding.
+    ///
+    /// Components are NaN if the real part is zero.
+    pub fn normalize(self) -> Self {
+        let n = self.real.dot(self.real).sqrt().recip();
+        let (real, dual) = (self.real.scale(n), self.dual.scale(n));
+        let dual = dual.add(real.scale(-real.dot(dual)));
+        Self { real, dual }
+    }
+
+    /// The screw motion by the fraction `t` of this one, with the angle in
+    /// `[0, π]`.
+    pub fn pow(self, t: T) -> Self {
+        let tau = Se3::from(Isometry3::from(self)).log();
+        Isometry3::from(Se3::exp(tau * t)).into()
+    }
+
+    /// Screw linear interpolation (ScLERP): the motion by the fraction `t`
+    /// of the way from `self` to `other` with the constant velocity of a
+    /// screw motion along the shorter path.
+    pub fn sclerp(self, other: Self, t: T) -> Self {
+        self * (self.inverse() * other).pow(t)
+    }
+
+    /// Dual quaternion linear blending (DLB) of the weighted motions, e.g.
+    /// the bones which influence a vertex in skinning.
+    ///
+    /// Much cheaper than iterated [`sclerp`](DualQuat::sclerp) and always a
+    /// rigid motion. Each dual quaternion is flipped into the hemisphere of
+    /// the first, since `q` and `-q` are the same motion.
+    /// Components are NaN if there are no motions or the weights cancel out.
+    pub fn blend<I: IntoIterator<Item = (Self, T)>>(motions: I) -> Self {
+        let mut iter = motions.into_iter();
+        let Some((first, w)) = iter.next() else {
+            return Self {
+                real: Quat::new(T::nan(), Vec3D::zero()),
+                dual: Quat::new(T::nan(), Vec3D::zero()),
+            };
+        };
+        let (mut real, mut dual) = (first.real.scale(w), first.dual.scale(w));
+        for (q, w) in iter {
+            let w = if q.real.dot(first.real) < T::zero() {
+                -w
+            } else {
+                w
+            };
+            real = real.add(q.real.scale(w));
+            dual = dual.add(q.dual.scale(w));
+        }
+        Self { real, dual }.normalize()
+    }
+}
+
+impl<T: Component> Mul for DualQuat<T>
+where
+    for<'a> &'a T: RefComponent<T>,
+{
+    type Output = Self;
+
+    /// The composition: `a * b` moves by `b` and then by `a`.
+    fn mul(self, rhs: Self) -> Self {
+        Self {
+            real: self.real.mul(rhs.real),
+            dual: self.real.mul(rhs.dual).add(self.dual.mul(rhs.real)),
+        }
+    }
+}
+
+impl<T: Component> MulAssign for DualQuat<T>
+where
+    for<'a> &'a T: RefComponent<T>,
+{
+    #[inline]
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+impl<T: Component> From<Isometry3<T>> for DualQuat<T>
+where
+    for<'a> &'a T: RefComponent<T>,
+{
+    #[inline]
+    fn from(m: Isometry3<T>) -> Self {
+        Self::new(m.rotation, m.translation)
+    }
+}
+
+impl<T: Component> From<DualQuat<T>> for Isometry3<T>
+where
+    for<'a> &'a T: RefComponent<T>,
+{
+    #[inline]
+    fn from(q: DualQuat<T>) -> Self {
+        Self::new(q.rotation(), q.translation())
+    }
+}
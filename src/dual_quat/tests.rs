@@ -0,0 +1,142 @@
+use std::f64::consts::PI;
+
+use crate::testing::Lcg;
+use crate::*;
+
+impl Lcg {
+    fn iso(&mut self) -> Isometry3<f64> {
+        let r = Rotor3::from_axis_angle(self.vec3(), Rad(2.0 * PI * self.next()));
+        Isometry3::new(r, self.vec3())
+    }
+}
+
+#[test]
+fn rigid_motions() {
+    let mut rng = Lcg(1);
+    for _ in 0..100 {
+        let (a, b, p, v) = (rng.iso(), rng.iso(), rng.vec3(), rng.vec3());
+        let (qa, qb) = (DualQuat::from(a), DualQuat::from(b));
+        assert!(qa.transform_point(p).distance(a.transform_point(p)) < 1e-15);
+        assert!(qa.transform_vector(v).distance(a.transform_vector(v)) < 1e-15);
+        assert!(qa.translation().distance(a.translation) < 1e-15);
+        assert!(
+            (qa * qb)
+                .transform_point(p)
+                .distance((a * b).transform_point(p))
+                < 1e-15
+        );
+        let d = qa
+            .inverse()
+            .transform_point(qa.transform_point(p))
+            .distance(p);
+        assert!(d < 1e-14);
+        let m = Isometry3::from(qa);
+        assert!(m.transform_point(p).distance(a.transform_point(p)) < 1e-15);
+
+        // rounding accumulated over a long chain is removed
+        let mut q = DualQuat::identity();
+        for _ in 0..1000 {
+            q *= qb;
+        }
+        let n = q.normalize();
+        assert!((n.rotation().norm() - 1.0).abs() < 1e-15);
+        assert!(n.transform_point(p).distance(q.transform_point(p)) < 1e-10);
+    }
+    let r = Rotor3::from_axis_angle(Vec3D::new(1.0, 0.0, 0.0), Rad(1.0));
+    let q = DualQuat::from_rotation(r) * DualQuat::from_translation(Vec3D::new(0.0, 1.0, 0.0));
+    let p = q.transform_point(Vec3D::new(0.0, 0.0, 0.0));
+    assert!(p.distance(r.rotate(Vec3D::new(0.0, 1.0, 0.0))) < 1e-16);
+}
+
+#[test]
+fn screw_linear_interpolation() {
+    let mut rng = Lcg(2);
+    for _ in 0..100 {
+        let (a, b, p) = (
+            DualQuat::from(rng.iso()),
+            DualQuat::from(rng.iso()),
+            rng.vec3(),
+        );
+        assert!(
+            a.sclerp(b, 0.0)
+                .transform_point(p)
+                .distance(a.transform_point(p))
+                < 1e-14
+        );
+        assert!(
+            a.sclerp(b, 1.0)
+                .transform_point(p)
+                .distance(b.transform_point(p))
+                < 1e-14
+        );
+        // the constant velocity: halves compose into the whole
+        let m = a.sclerp(b, 0.5);
+        let half = a.inverse() * m;
+        assert!(
+            (a * half * half)
+                .transform_point(p)
+                .distance(b.transform_point(p))
+                < 1e-14
+        );
+        // the same motion represented by the negated dual quaternion
+        let neg = DualQuat::blend([(b, -1.0)]);
+        assert!(
+            a.sclerp(neg, 0.5)
+                .transform_point(p)
+                .distance(m.transform_point(p))
+                < 1e-14
+        );
+        // the points move along helices: the distance from the axis is kept
+        let t = a.sclerp(b, 0.3).transform_point(p);
+        let u = a.sclerp(b, 0.7).transform_point(p);
+        let a2 = a.transform_point(p);
+        let b2 = b.transform_point(p);
+        assert!(((t - a2).norm() - (b2 - u).norm()).abs() < 1e-13);
+    }
+    // pure translations are interpolated linearly
+    let a = DualQuat::from_translation(Vec3D::new(0.0, 0.0, 0.0));
+    let b = DualQuat::from_translation(Vec3D::new(2.0, 4.0, 6.0));
+    assert!(
+        a.sclerp(b, 0.25)
+            .translation()
+            .distance(Vec3D::new(0.5, 1.0, 1.5))
+            < 1e-15
+    );
+}
+
+#[test]
+fn linear_blending() {
+    let mut rng = Lcg(3);
+    for _ in 0..100 {
+        let (a, b, p) = (
+            DualQuat::from(rng.iso()),
+            DualQuat::from(rng.iso()),
+            rng.vec3(),
+        );
+        let q = DualQuat::blend([(a, 0.3), (b, 0.5), (a * b, 0.2)]);
+        // a rigid motion
+        assert!((q.rotation().norm() - 1.0).abs() < 1e-15);
+        let v = rng.vec3();
+        assert!((q.transform_vector(v).norm() - v.norm()).abs() < 1e-15);
+        // the weights need not be normalized
+        let r = DualQuat::blend([(a, 3.0), (b, 5.0), (a * b, 2.0)]);
+        assert!(r.transform_point(p).distance(q.transform_point(p)) < 1e-15);
+        // the same motion blended with itself, even if negated
+        let s = DualQuat::blend([(a, 0.5), (DualQuat::blend([(a, -1.0)]), 0.5)]);
+        assert!(s.transform_point(p).distance(a.transform_point(p)) < 1e-15);
+        // close to ScLERP for two motions
+        let c = a.sclerp(b, 0.5).transform_point(p);
+        assert!(
+            DualQuat::blend([(a, 0.5), (b, 0.5)])
+                .transform_point(p)
+                .distance(c)
+                < 0.5
+        );
+    }
+    let r = Rotor3::from_axis_angle(Vec3D::new(0.0, 0.0, 1.0), Rad(PI / 2.0));
+    let a = DualQuat::new(r, Vec3D::new(1.0, 0.0, 0.0));
+    let m = DualQuat::blend([(DualQuat::identity(), 1.0), (a, 1.0)]);
+    // the rotations of two equal weights blend into the halfway rotation
+    assert!((m.rotation() - a.pow(0.5).rotation()).norm() < 1e-15);
+    assert!(DualQuat::<f64>::blend([]).translation().has_nan());
+}
@@ -0,0 +1,196 @@
+#[cfg(test)]
+mod tests;
+
+use std::cmp::Ordering;
+
+use crate::decomposition::jacobi_rotation;
+use crate::lie::mat_vec;
+use crate::Component;
+use crate::InnerProductSpace;
+use crate::MatrixND;
+use crate::NormedSpace;
+use crate::Rad;
+use crate::RefComponent;
+use crate::Vec3D;
+
+/// Eigendecomposition `A = V Λ Vᵀ` of a symmetric 3×3 matrix in the closed
+/// form, e.g. the principal axes of an inertia or a stress tensor.
+///
+/// The best separated eigenvalue is of the trigonometric solution of the
+/// characteristic cubic and its eigenvector of the cross products of the
+/// rows, following Eberly's robust construction. The other two are of the
+/// rotation in the orthogonal plane, accurate even if nearly repeated.
+/// Falls back to the Jacobi iteration of [`MatrixND::symmetric_eigen`] if
+/// the residual of the closed form still exceeds the rounding.
+///
+/// # Examples
+///
+/// ```
+/// use ::ndvec::*;
+///
+/// let inertia = [
+///     Vec3D::new(2f64, 1.0, 0.0),
+///     Vec3D::new(1.0, 2.0, 0.0),
+///     Vec3D::new(0.0, 0.0, 5.0),
+/// ];
+/// let e = SymmetricEigen3::new(inertia);
+/// assert!(e.values.distance(Vec3D::new(1.0, 3.0, 5.0)) < 1e-14);
+/// let axis = e.vectors[0];
+/// assert!((axis.x + axis.y).abs() < 1e-15 && axis.z.abs() < 1e-15);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SymmetricEigen3<T> {
+    /// The eigenvalues `Λ` in the ascending order.
+    pub values: Vec3D<T>,
+    /// The unit eigenvectors, a right-handed orthonormal basis, i.e. the
+    /// columns of a rotation matrix.
+    pub vectors: [Vec3D<T>; 3],
+}
+
+/// The unit eigenvector of the eigenvalue of the multiplicity one, the
+/// longest cross product of the rows of `A - λ I`.
+fn simple_eigenvector<T: Component>(a: &[Vec3D<T>; 3], lambda: T) -> Vec3D<T>
+where
+    for<'a> &'a T: RefComponent<T>,
+{
+    let r0 = Vec3D::new(a[0].x - lambda, a[0].y, a[0].z);
+    let r1 = Vec3D::new(a[1].x, a[1].y - lambda, a[1].z);
+    let r2 = Vec3D::new(a[2].x, a[2].y, a[2].z - lambda);
+    let v = [r0.cross(r1), r0.cross(r2), r1.cross(r2)]
+        .into_iter()
+        .max_by(|a, b| {
+            a.norm_sqr()
+                .partial_cmp(&b.norm_sqr())
+                .unwrap_or(Ordering::Equal)
+        })
+        .expect("three candidates");
+    v / v.norm()
+}
+
+impl<T: Component> SymmetricEigen3<T>
+where
+    for<'a> &'a T: RefComponent<T>,
+{
+    /// The eigendecomposition of the symmetric matrix of the columns.
+    ///
+    /// The matrix is symmetrized as `(A + Aᵀ) / 2`.
+    pub fn new(m: [Vec3D<T>; 3]) -> Self {
+        let half = (T::one() + T::one()).recip();
+        let (a01, a02, a12) = (
+            (m[0].y + m[1].x) * half,
+            (m[0].z + m[2].x) * half,
+            (m[1].z + m[2].y) * half,
+        );
+        let a = [
+            Vec3D::new(m[0].x, a01, a02),
+            Vec3D::new(a01, m[1].y, a12),
+            Vec3D::new(a02, a12, m[2].z),
+        ];
+        let scale = a
+            .iter()
+            .flat_map(|c| [c.x.abs(), c.y.abs(), c.z.abs()])
+            .fold(T::zero(), T::max);
+        if scale.is_zero() {
+            return Self::sorted([T::zero(); 3], MatrixND::<T, 3, 3>::identity().into());
+        }
+        let e = Self::closed_form(&a.map(|c| c / scale));
+        let e = Self::sorted(e.0.map(|l| l * scale), e.1);
+        // the rounding of the Jacobi iteration
+        let tolerance = T::epsilon() * T::from(64.0).expect("64 must be representable") * scale;
+        if e.residual(&a) <= tolerance {
+            e
+        } else {
+            let j = MatrixND::from(a.map(|c| c / scale)).symmetric_eigen();
+            Self::sorted(
+                [j.values[0], j.values[1], j.values[2]].map(|l| l * scale),
+                <[Vec3D<T>; 3]>::from(j.vectors),
+            )
+        }
+    }
+
+    /// The eigenvalues and the orthonormal eigenvectors in no order of the
+    /// matrix scaled to the unit maximum element.
+    fn closed_form(a: &[Vec3D<T>; 3]) -> ([T; 3], [Vec3D<T>; 3]) {
+        let (o, l) = (T::zero(), T::one());
+        let (two, three) = (l + l, l + l + l);
+        let (a00, a11, a22) = (a[0].x, a[1].y, a[2].z);
+        let (a01, a02, a12) = (a[1].x, a[2].x, a[2].y);
+        let off = a01 * a01 + a02 * a02 + a12 * a12;
+        if off.is_zero() {
+            let axes = [
+                Vec3D::new(l, o, o),
+                Vec3D::new(o, l, o),
+                Vec3D::new(o, o, l),
+            ];
+            return ([a00, a11, a22], axes);
+        }
+        // the roots q + 2 p cos(φ + 2πk/3) of the cubic of B = (A - q I) / p
+        let q = (a00 + a11 + a22) / three;
+        let (b00, b11, b22) = (a00 - q, a11 - q, a22 - q);
+        let p = ((b00 * b00 + b11 * b11 + b22 * b22 + two * off) / (two * three)).sqrt();
+        let det = b00 * (b11 * b22 - a12 * a12) - a01 * (a01 * b22 - a12 * a02)
+            + a02 * (a01 * a12 - b11 * a02);
+        let r = (det / (two * p * p * p)).max(-l).min(l);
+        let phi = r.acos() / three;
+        let max = q + two * p * phi.cos();
+        let min = q + two * p * (phi + Rad::<T>::full_turn().0 / three).cos();
+        // the eigenvalue farther from the middle one is the better separated,
+        // while the other two may be accurate only to the square root of the
+        // rounding and are solved in the orthogonal plane instead
+        let first = if r >= o { max } else { min };
+        let w = simple_eigenvector(a, first);
+        let u = if w.x.abs() > w.y.abs() {
+            Vec3D::new(-w.z, o, w.x)
+        } else {
+            Vec3D::new(o, w.z, -w.y)
+        };
+        let u = u / u.norm();
+        let v = w.cross(u);
+        let (au, av) = (mat_vec(a, u), mat_vec(a, v));
+        let (m00, m01, m11) = (u.dot(au), u.dot(av), v.dot(av));
+        if m01.is_zero() {
+            return ([first, m00, m11], [w, u, v]);
+        }
+        let (c, s) = jacobi_rotation(m00, m11, m01);
+        let t = s / c;
+        (
+            [first, m00 - t * m01, m11 + t * m01],
+            [w, u * c - v * s, u * s + v * c],
+        )
+    }
+
+    /// The decomposition of the eigenvalues in the ascending order, with the
+    /// basis flipped to the right-handed one.
+    fn sorted(values: [T; 3], vectors: [Vec3D<T>; 3]) -> Self {
+        let mut order = [0, 1, 2];
+        order.sort_by(|&i, &j| values[i].partial_cmp(&values[j]).unwrap_or(Ordering::Equal));
+        let mut vectors = order.map(|i| vectors[i]);
+        if vectors[0].cross(vectors[1]).dot(vectors[2]) < T::zero() {
+            vectors[2] = -vectors[2];
+        }
+        let [i, j, k] = order;
+        Self {
+            values: Vec3D::new(values[i], values[j], values[k]),
+            vectors,
+        }
+    }
+
+    /// The sum of the norms of `A v - λ v` of the eigenpairs, NaN if any is.
+    fn residual(&self, a: &[Vec3D<T>; 3]) -> T {
+        let values = [self.values.x, self.values.y, self.values.z];
+        (0..3)
+            .map(|i| (mat_vec(a, self.vectors[i]) - self.vectors[i] * values[i]).norm())
+            .sum()
+    }
+
+    /// The matrix `V Λ Vᵀ` of the columns.
+    pub fn matrix(self) -> [Vec3D<T>; 3] {
+        let [v0, v1, v2] = self.vectors;
+        let (l0, l1, l2) = (self.values.x, self.values.y, self.values.z);
+        [
+            v0 * (l0 * v0.x) + v1 * (l1 * v1.x) + v2 * (l2 * v2.x),
+            v0 * (l0 * v0.y) + v1 * (l1 * v1.y) + v2 * (l2 * v2.y),
+            v0 * (l0 * v0.z) + v1 * (l1 * v1.z) + v2 * (l2 * v2.z),
+        ]
+    }
+}
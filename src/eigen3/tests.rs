@@ -0,0 +1,166 @@
+use crate::lie::mat_vec;
+use crate::testing::Lcg;
+use crate::*;
+
+impl Lcg {
+    fn rotation(&mut self) -> [V3D64; 3] {
+        So3::exp(Vec3D::new(self.next(), self.next(), self.next()) * 8.0).matrix()
+    }
+}
+
+/// The matrix `R diag(d) Rᵀ` of the columns.
+fn rotated(r: [V3D64; 3], d: V3D64) -> [V3D64; 3] {
+    SymmetricEigen3 {
+        values: d,
+        vectors: r,
+    }
+    .matrix()
+}
+
+/// Checks the eigenpairs, the ascending order and the right-handed
+/// orthonormal basis to the rounding of the scale.
+fn check(m: [V3D64; 3], e: SymmetricEigen3<f64>, scale: f64) {
+    let [v0, v1, v2] = e.vectors;
+    let values = [e.values.x, e.values.y, e.values.z];
+    assert!(values[0] <= values[1] && values[1] <= values[2], "{e:?}");
+    for (i, v) in e.vectors.into_iter().enumerate() {
+        assert!((v.norm() - 1.0).abs() < 1e-14, "{e:?}");
+        let r = (mat_vec(&m, v) - v * values[i]).norm();
+        assert!(r < 1e-14 * scale, "residual {r} of {m:?}: {e:?}");
+    }
+    assert!(v0.dot(v1).abs() < 1e-14 && v0.dot(v2).abs() < 1e-14 && v1.dot(v2).abs() < 1e-14);
+    let handedness = Vec3D::new(
+        v0.y * v1.z - v0.z * v1.y,
+        v0.z * v1.x - v0.x * v1.z,
+        v0.x * v1.y - v0.y * v1.x,
+    )
+    .dot(v2);
+    assert!((handedness - 1.0).abs() < 1e-14, "{e:?}");
+}
+
+#[test]
+fn rotated_diagonals() {
+    let mut rng = Lcg(1);
+    for _ in 0..1000 {
+        let r = rng.rotation();
+        let d = Vec3D::new(rng.next(), rng.next(), rng.next()) * 10.0;
+        let m = rotated(r, d);
+        let e = SymmetricEigen3::new(m);
+        check(m, e, 10.0);
+        let mut sorted = [d.x, d.y, d.z];
+        sorted.sort_by(f64::total_cmp);
+        assert!(
+            e.values.distance(Vec3D::from(sorted)) < 1e-13,
+            "{e:?} vs {d:?}"
+        );
+        // the eigenvectors are the columns of the rotation up to the sign
+        for (i, v) in e.vectors.into_iter().enumerate() {
+            let j = [d.x, d.y, d.z]
+                .into_iter()
+                .position(|x| x == sorted[i])
+                .unwrap();
+            assert!((v.dot(r[j]).abs() - 1.0).abs() < 1e-12);
+        }
+        assert!(
+            Vec3D::from(e.matrix().map(|c| c.norm_sqr()))
+                .distance(Vec3D::from(m.map(|c| c.norm_sqr())))
+                < 1e-12
+        );
+    }
+}
+
+#[test]
+fn repeated_eigenvalues() {
+    let mut rng = Lcg(2);
+    for k in 0..1000 {
+        let r = rng.rotation();
+        let (a, b) = (rng.next(), rng.next());
+        // exactly and nearly repeated, down to the rounding
+        let gap = 10f64.powi(-(k % 16));
+        let d = match k % 3 {
+            0 => Vec3D::new(a, a + gap, b),
+            1 => Vec3D::new(a, a, a + gap),
+            _ => Vec3D::new(a, a * (1.0 + gap), a),
+        };
+        let m = rotated(r, d);
+        check(m, SymmetricEigen3::new(m), 1.0);
+        let m = rotated(r, Vec3D::new(a, a, b));
+        check(m, SymmetricEigen3::new(m), 1.0);
+    }
+    let m = rotated(rng.rotation(), Vec3D::new(2.0, 2.0, 2.0));
+    let e = SymmetricEigen3::new(m);
+    check(m, e, 2.0);
+    assert!(e.values.distance(Vec3D::new(2.0, 2.0, 2.0)) < 1e-14);
+}
+
+#[test]
+fn special_matrices() {
+    let zero = [Vec3D::new(0.0, 0.0, 0.0); 3];
+    let e = SymmetricEigen3::new(zero);
+    assert_eq!(e.values, Vec3D::new(0.0, 0.0, 0.0));
+    check(zero, e, 1.0);
+
+    // already diagonal, sorted by the eigenvalues
+    let m = [
+        Vec3D::new(3.0, 0.0, 0.0),
+        Vec3D::new(0.0, -1.0, 0.0),
+        Vec3D::new(0.0, 0.0, 2.0),
+    ];
+    let e = SymmetricEigen3::new(m);
+    assert_eq!(e.values, Vec3D::new(-1.0, 2.0, 3.0));
+    check(m, e, 3.0);
+    assert_eq!(e.vectors[0].y.abs(), 1.0);
+
+    // the scale of the elements does not matter
+    let mut rng = Lcg(3);
+    for scale in [1e-150, 1e-20, 1e20, 1e150] {
+        let r = rng.rotation();
+        let m = rotated(r, Vec3D::new(1.0, 2.0, 3.0) * scale);
+        let e = SymmetricEigen3::new(m);
+        check(m, e, 3.0 * scale);
+        assert!(e.values.distance(Vec3D::new(1.0, 2.0, 3.0) * scale) < 1e-14 * scale);
+    }
+
+    // the asymmetric part is ignored
+    let m = [
+        Vec3D::new(2.0, 1.0, 0.0),
+        Vec3D::new(3.0, 2.0, 0.0),
+        Vec3D::new(0.0, 0.0, 1.0),
+    ];
+    let e = SymmetricEigen3::new(m);
+    assert!(e.values.distance(Vec3D::new(0.0, 1.0, 4.0)) < 1e-14);
+}
+
+#[test]
+fn inertia_tensor() {
+    // the principal axes of the points of the unit masses
+    let mut rng = Lcg(4);
+    let r = rng.rotation();
+    let points = [(1.0, 0.0, 0.0), (0.0, 2.0, 0.0), (0.0, 0.0, 3.0)]
+        .into_iter()
+        .flat_map(|(x, y, z)| [Vec3D::new(x, y, z), Vec3D::new(-x, -y, -z)])
+        .map(|p| mat_vec(&r, p));
+    let mut inertia = [Vec3D::new(0.0, 0.0, 0.0); 3];
+    for p in points {
+        let axes = [
+            Vec3D::new(1.0, 0.0, 0.0),
+            Vec3D::new(0.0, 1.0, 0.0),
+            Vec3D::new(0.0, 0.0, 1.0),
+        ];
+        for (c, e) in inertia.iter_mut().zip(axes) {
+            *c += e * p.norm_sqr() - p * p.dot(e);
+        }
+    }
+    let e = SymmetricEigen3::new(inertia);
+    check(inertia, e, 26.0);
+    assert!(e.values.distance(Vec3D::new(10.0, 20.0, 26.0)) < 1e-13);
+    // the smallest moment about the farthest axis
+    assert!((e.vectors[0].dot(r[2]).abs() - 1.0).abs() < 1e-14);
+    let rotor = Rotor3::from_matrix(e.vectors);
+    assert!(
+        rotor
+            .rotate(Vec3D::new(1.0, 0.0, 0.0))
+            .distance(e.vectors[0])
+            < 1e-14
+    );
+}
@@ -0,0 +1,278 @@
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "serde")]
+use serde::Deserialize;
+#[cfg(feature = "serde")]
+use serde::Serialize;
+
+use crate::Component;
+use crate::Rad;
+use crate::RefComponent;
+use crate::Rotor3;
+use crate::Vec3D;
+
+/// Order of the axes of Euler angles.
+///
+/// The six Tait-Bryan orders rotate about all three axes, e.g. yaw, pitch
+/// and roll. The six proper Euler orders rotate about the first axis again.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum EulerOrder {
+    XYZ,
+    XZY,
+    YXZ,
+    YZX,
+    ZXY,
+    ZYX,
+    XYX,
+    XZX,
+    YXY,
+    YZY,
+    ZXZ,
+    ZYZ,
+}
+
+/// Whether Euler angles rotate about the axes of the rotating body or of
+/// the fixed frame.
+///
+/// Intrinsic rotations in an order are the same as extrinsic rotations in
+/// the reverse order by the reversed angles.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum EulerMode {
+    /// About the axes of the body, which move with each rotation.
+    Intrinsic,
+    /// About the axes of the fixed frame.
+    Extrinsic,
+}
+
+impl EulerOrder {
+    /// All the orders, the Tait-Bryan orders first.
+    pub const ALL: [Self; 12] = [
+        Self::XYZ,
+        Self::XZY,
+        Self::YXZ,
+        Self::YZX,
+        Self::ZXY,
+        Self::ZYX,
+        Self::XYX,
+        Self::XZX,
+        Self::YXY,
+        Self::YZY,
+        Self::ZXZ,
+        Self::ZYZ,
+    ];
+
+    /// The indices of the axes, `0` for x, `1` for y and `2` for z.
+    pub const fn axes(self) -> [usize; 3] {
+        match self {
+            Self::XYZ => [0, 1, 2],
+            Self::XZY => [0, 2, 1],
+            Self::YXZ => [1, 0, 2],
+            Self::YZX => [1, 2, 0],
+            Self::ZXY => [2, 0, 1],
+            Self::ZYX => [2, 1, 0],
+            Self::XYX => [0, 1, 0],
+            Self::XZX => [0, 2, 0],
+            Self::YXY => [1, 0, 1],
+            Self::YZY => [1, 2, 1],
+            Self::ZXZ => [2, 0, 2],
+            Self::ZYZ => [2, 1, 2],
+        }
+    }
+
+    /// Checks if the order is a proper Euler order, whose first and last
+    /// axes are the same.
+    pub const fn is_proper(self) -> bool {
+        let [i, _, k] = self.axes();
+        i == k
+    }
+
+    /// The order of the axes reversed.
+    pub const fn reverse(self) -> Self {
+        match self {
+            Self::XYZ => Self::ZYX,
+            Self::XZY => Self::YZX,
+            Self::YXZ => Self::ZXY,
+            Self::YZX => Self::XZY,
+            Self::ZXY => Self::YXZ,
+            Self::ZYX => Self::XYZ,
+            proper => proper,
+        }
+    }
+}
+
+/// Orientation as three successive rotations about coordinate axes.
+///
+/// The angles are counterclockwise and applied in the order of the axes,
+/// e.g. the intrinsic [`ZYX`](EulerOrder::ZYX) angles are the yaw, the
+/// pitch and the roll of aerospace.
+///
+/// Conversions back to the angles return the first and the third angle in
+/// `[-π, π)`, and the second in `[0, π]` for proper Euler orders or in
+/// `[-π/2, π/2]` for Tait-Bryan orders.
+/// At the gimbal lock, where the second angle is such that the first and
+/// the third axis coincide, only the sum or the difference of the first and
+/// the third angle is determined, and the one applied last is set to zero.
+///
+/// # Examples
+///
+/// ```
+/// use ::ndvec::*;
+///
+/// let (yaw, pitch, roll) = (Deg(90.0).into(), Rad(0.0), Rad(0.0));
+/// let e = EulerAngles::new([yaw, pitch, roll], EulerOrder::ZYX, EulerMode::Intrinsic);
+/// let v = e.rotate(Vec3D::new(1.0, 0.0, 0.0));
+/// assert!(v.distance(Vec3D::new(0.0, 1.0, 0.0)) < 1e-15);
+///
+/// // the same orientation in another convention
+/// let f = e.convert(EulerOrder::XYZ, EulerMode::Extrinsic);
+/// assert!((f.angles[2] - yaw).abs() < Rad(1e-15));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct EulerAngles<T> {
+    /// The angles of the rotations about the axes of the order.
+    pub angles: [Rad<T>; 3],
+    /// The order of the axes.
+    pub order: EulerOrder,
+    /// Whether the axes are of the body or of the fixed frame.
+    pub mode: EulerMode,
+}
+
+impl<T> EulerAngles<T> {
+    #[inline]
+    pub const fn new(angles: [Rad<T>; 3], order: EulerOrder, mode: EulerMode) -> Self {
+        Self {
+            angles,
+            order,
+            mode,
+        }
+    }
+}
+
+impl<T: Component> EulerAngles<T>
+where
+    for<'a> &'a T: RefComponent<T>,
+{
+    /// The rotor of the orientation.
+    pub fn to_rotor(self) -> Rotor3<T> {
+        let [r1, r2, r3] = [0, 1, 2].map(|i| {
+            let mut axis = Vec3D::new(T::zero(), T::zero(), T::zero());
+            axis[self.order.axes()[i]] = T::one();
+            Rotor3::from_axis_angle(axis, self.angles[i])
+        });
+        match self.mode {
+            // each rotation is about the axis already rotated by the preceding ones
+            EulerMode::Intrinsic => r1 * r2 * r3,
+            EulerMode::Extrinsic => r3 * r2 * r1,
+        }
+    }
+
+    /// The angles of the rotor in the order and the mode.
+    ///
+    /// Uses the direct method of Bernardes and Viollet on the quaternion.
+    pub fn from_rotor(rotor: Rotor3<T>, order: EulerOrder, mode: EulerMode) -> Self {
+        // the method is for extrinsic rotations
+        let extrinsic = mode == EulerMode::Extrinsic;
+        let seq = if extrinsic { order } else { order.reverse() };
+        let [i, j, k] = seq.axes();
+        let proper = seq.is_proper();
+        let k = if proper { 3 - i - j } else { k };
+        // the parity of the permutation (i, j, k)
+        let sign = if (j + 3 - i) % 3 == 1 {
+            T::one()
+        } else {
+            -T::one()
+        };
+        // the quaternion (w, x, y, z) is the rotor (w, -(x, y, z))
+        let w = rotor.s;
+        let v = -rotor.b;
+        let (a, b, c, d) = if proper {
+            (w, v[i], v[j], v[k] * sign)
+        } else {
+            (w - v[j], v[i] + v[k] * sign, v[j] + w, v[k] * sign - v[i])
+        };
+
+        let two = T::one() + T::one();
+        let pi = T::from(std::f64::consts::PI).expect("π must be representable");
+        let mut second = two * c.hypot(d).atan2(a.hypot(b));
+        let half_sum = b.atan2(a);
+        let half_diff = d.atan2(c);
+        // within the rounding from the gimbal lock
+        let lock = T::epsilon() * T::from(16.0).expect("16 must be representable");
+        // at the gimbal lock the angle applied last is zero, which is the
+        // first one of the reversed sequence of intrinsic rotations
+        let locked = |angle: T| {
+            if extrinsic {
+                (angle, T::zero())
+            } else {
+                (T::zero(), angle)
+            }
+        };
+        let (mut first, mut third) = if second <= lock {
+            locked(two * half_sum)
+        } else if second >= pi - lock {
+            locked(two * if extrinsic { -half_diff } else { half_diff })
+        } else {
+            (half_sum - half_diff, half_sum + half_diff)
+        };
+        if !proper {
+            third *= sign;
+            second -= pi / two;
+        }
+        if !extrinsic {
+            std::mem::swap(&mut first, &mut third);
+        }
+        // the second angle is already in its range
+        let (first, third) = (Rad(first), Rad(third));
+        Self::new(
+            [
+                first.normalize_signed(),
+                Rad(second),
+                third.normalize_signed(),
+            ],
+            order,
+            mode,
+        )
+    }
+
+    /// The rotation matrix of the orientation, as columns.
+    pub fn to_matrix(self) -> [Vec3D<T>; 3] {
+        let r = self.to_rotor();
+        let (o, l) = (T::zero(), T::one());
+        [
+            r.rotate(Vec3D::new(l, o, o)),
+            r.rotate(Vec3D::new(o, l, o)),
+            r.rotate(Vec3D::new(o, o, l)),
+        ]
+    }
+
+    /// The angles of the rotation matrix given as columns.
+    pub fn from_matrix(m: [Vec3D<T>; 3], order: EulerOrder, mode: EulerMode) -> Self {
+        Self::from_rotor(Rotor3::from_matrix(m), order, mode)
+    }
+
+    /// The unit quaternion `[w, x, y, z]` of the orientation.
+    pub fn to_quaternion(self) -> [T; 4] {
+        let r = self.to_rotor();
+        [r.s, -r.b.x, -r.b.y, -r.b.z]
+    }
+
+    /// The angles of the unit quaternion `[w, x, y, z]`.
+    pub fn from_quaternion(q: [T; 4], order: EulerOrder, mode: EulerMode) -> Self {
+        let [w, x, y, z] = q;
+        Self::from_rotor(Rotor3::new(w, -Vec3D::new(x, y, z)), order, mode)
+    }
+
+    /// The same orientation in another order or mode.
+    pub fn convert(self, order: EulerOrder, mode: EulerMode) -> Self {
+        Self::from_rotor(self.to_rotor(), order, mode)
+    }
+
+    /// Rotates the vector by the orientation.
+    pub fn rotate(self, v: Vec3D<T>) -> Vec3D<T> {
+        self.to_rotor().rotate(v)
+    }
+}
@@ -0,0 +1,131 @@
+use std::f64::consts::FRAC_PI_2;
+use std::f64::consts::PI;
+
+use crate::testing::Lcg;
+use crate::*;
+
+const MODES: [EulerMode; 2] = [EulerMode::Intrinsic, EulerMode::Extrinsic];
+
+fn apply(m: [V3D64; 3], v: V3D64) -> V3D64 {
+    m[0] * v.x + m[1] * v.y + m[2] * v.z
+}
+
+/// Checks that both rotate the vectors alike.
+fn assert_same(a: EulerAngles<f64>, b: EulerAngles<f64>, vs: &[V3D64], tol: f64) {
+    for &v in vs {
+        let d = a.rotate(v).distance(b.rotate(v));
+        assert!(d < tol, "{a:?} {b:?} {d}");
+    }
+}
+
+fn assert_ranges(e: EulerAngles<f64>) {
+    let [first, second, third] = e.angles.map(|a| a.0);
+    assert!(first.abs() <= PI && third.abs() <= PI, "{e:?}");
+    if e.order.is_proper() {
+        assert!((0.0..=PI).contains(&second), "{e:?}");
+    } else {
+        assert!(second.abs() <= FRAC_PI_2, "{e:?}");
+    }
+}
+
+#[test]
+fn round_trips() {
+    let mut rng = Lcg(1);
+    let vs: Vec<_> = (0..3).map(|_| rng.vec3()).collect();
+    for order in EulerOrder::ALL {
+        for mode in MODES {
+            for _ in 0..50 {
+                let angles = [0, 1, 2].map(|_| Rad(2.0 * PI * rng.next()));
+                let e = EulerAngles::new(angles, order, mode);
+                let r = EulerAngles::from_rotor(e.to_rotor(), order, mode);
+                assert_ranges(r);
+                assert_same(e, r, &vs, 1e-14);
+                // the same angles unless outside the ranges or near the lock
+                let r2 = EulerAngles::from_rotor(r.to_rotor(), order, mode);
+                let away = if order.is_proper() {
+                    r.angles[1].sin()
+                } else {
+                    r.angles[1].cos()
+                };
+                for i in 0..3 {
+                    let d = (r.angles[i] - r2.angles[i]).abs().0;
+                    assert!(d * away < 1e-15 || d < 1e-13, "{r:?} {r2:?}");
+                }
+                let m = EulerAngles::from_matrix(e.to_matrix(), order, mode);
+                assert_same(e, m, &vs, 1e-14);
+                let q = EulerAngles::from_quaternion(e.to_quaternion(), order, mode);
+                assert_same(e, q, &vs, 1e-14);
+                for &v in &vs {
+                    assert!(apply(e.to_matrix(), v).distance(e.rotate(v)) < 1e-15);
+                }
+                // into every other convention and back
+                for other in EulerOrder::ALL {
+                    for other_mode in MODES {
+                        let c = e.convert(other, other_mode);
+                        assert_eq!((c.order, c.mode), (other, other_mode));
+                        assert_same(e, c, &vs, 1e-14);
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn gimbal_lock() {
+    let mut rng = Lcg(2);
+    let vs: Vec<_> = (0..3).map(|_| rng.vec3()).collect();
+    for order in EulerOrder::ALL {
+        let locks: &[f64] = if order.is_proper() {
+            &[0.0, PI]
+        } else {
+            &[FRAC_PI_2, -FRAC_PI_2]
+        };
+        for mode in MODES {
+            for &lock in locks {
+                for _ in 0..20 {
+                    let angles = [rng.next(), lock, rng.next()].map(Rad);
+                    let e = EulerAngles::new(angles, order, mode);
+                    let r = EulerAngles::from_rotor(e.to_rotor(), order, mode);
+                    assert_ranges(r);
+                    assert_same(e, r, &vs, 1e-14);
+                    assert!((r.angles[1].0 - lock).abs() < 1e-7, "{r:?}");
+                    // the last applied angle is zero
+                    assert_eq!(r.angles[2], Rad(0.0), "{r:?}");
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn aerospace_sequence() {
+    let x = Vec3D::new(1.0, 0.0, 0.0);
+    let y = Vec3D::new(0.0, 1.0, 0.0);
+    let z = Vec3D::new(0.0, 0.0, 1.0);
+    let ypr = |yaw: f64, pitch: f64, roll: f64| {
+        let angles = [yaw, pitch, roll].map(|a| Deg(a).into());
+        EulerAngles::new(angles, EulerOrder::ZYX, EulerMode::Intrinsic)
+    };
+    // the nose turns left by the yaw
+    assert!(ypr(90.0, 0.0, 0.0).rotate(x).distance(y) < 1e-15);
+    // and up by the negative pitch, with z up
+    assert!(ypr(0.0, -90.0, 0.0).rotate(x).distance(z) < 1e-15);
+    // the roll is about the nose after the yaw and the pitch
+    let e = ypr(90.0, 0.0, 90.0);
+    assert!(e.rotate(x).distance(y) < 1e-15);
+    assert!(e.rotate(y).distance(z) < 1e-15);
+
+    // intrinsic rotations are extrinsic ones in the reverse order
+    let mut rng = Lcg(3);
+    let vs: Vec<_> = (0..3).map(|_| rng.vec3()).collect();
+    for order in EulerOrder::ALL {
+        let [a, b, c] = [0, 1, 2].map(|_| Rad(2.0 * PI * rng.next()));
+        let i = EulerAngles::new([a, b, c], order, EulerMode::Intrinsic);
+        let e = EulerAngles::new([c, b, a], order.reverse(), EulerMode::Extrinsic);
+        assert_same(i, e, &vs, 1e-15);
+        let q = i.to_quaternion();
+        let n = q.iter().map(|c| c * c).sum::<f64>();
+        assert!((n - 1.0).abs() < 1e-15);
+    }
+}
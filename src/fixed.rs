@@ -7,6 +7,8 @@ use std::ops::Add;
 use std::ops::AddAssign;
 use std::ops::Div;
 use std::ops::DivAssign;
+use std::ops::Index;
+use std::ops::IndexMut;
 use std::ops::Mul;
 use std::ops::MulAssign;
 use std::ops::Neg;
@@ -21,9 +23,13 @@ use serde::Deserialize;
 use serde::Serialize;
 
 use crate::Component;
+use crate::InnerProductSpace;
+use crate::NormedSpace;
+use crate::Rad;
 use crate::RefComponent;
 use crate::VecND;
 use crate::Vector;
+use crate::VectorSpace;
 
 macro_rules! declare_vector {
     ($V:ident{$first:ident $(, $rest:ident )*}; $N:literal) => {
@@ -48,23 +54,45 @@ declare_vector!(Vec1D { x }; 1);
 declare_vector!(Vec2D { x, y }; 2);
 declare_vector!(Vec3D { x, y, z }; 3);
 
-impl<T: Component> Vector for Vec1D<T>
+impl<T: Component> VectorSpace for Vec1D<T>
 where
     for<'a> &'a T: RefComponent<T>,
 {
-    type Cmp = T;
+    type Scalar = T;
+}
 
-    const DIM: usize = 1;
+impl<T: Component> InnerProductSpace for Vec1D<T>
+where
+    for<'a> &'a T: RefComponent<T>,
+{
+    #[inline]
+    fn dot(self, rhs: Self) -> Self::Scalar {
+        self.x * rhs.x
+    }
 
     #[inline]
-    fn norm_sqr(self) -> Self::Cmp {
+    fn norm_sqr(self) -> Self::Scalar {
         self.x * self.x
     }
+}
 
+impl<T: Component> NormedSpace for Vec1D<T>
+where
+    for<'a> &'a T: RefComponent<T>,
+{
     #[inline]
-    fn dot(self, rhs: Self) -> Self::Cmp {
-        self.x * rhs.x
+    fn norm(self) -> Self::Scalar {
+        self.x.abs()
     }
+}
+
+impl<T: Component> Vector for Vec1D<T>
+where
+    for<'a> &'a T: RefComponent<T>,
+{
+    type Cmp = T;
+
+    const DIM: usize = 1;
 
     #[inline]
     fn is_finite(self) -> bool {
@@ -75,33 +103,49 @@ where
     fn has_nan(self) -> bool {
         self.x.is_nan()
     }
-
-    #[inline]
-    fn norm(self) -> Self::Cmp {
-        self.x.abs()
-    }
 }
 
 macro_rules! impl_vector {
     ($V:ident{$first:ident $(, $rest:ident )*}; $N:literal) => {
-
-        impl<T: Component> Vector for $V<T>
+        impl<T: Component> VectorSpace for $V<T>
         where
             for<'a> &'a T: RefComponent<T>,
         {
-            type Cmp = T;
+            type Scalar = T;
+        }
 
-            const DIM: usize = $N;
+        impl<T: Component> InnerProductSpace for $V<T>
+        where
+            for<'a> &'a T: RefComponent<T>,
+        {
+            #[inline]
+            fn dot(self, rhs: Self) -> Self::Scalar {
+                self.$first * rhs.$first $( + self.$rest * rhs.$rest )*
+            }
 
             #[inline]
-            fn norm_sqr(self) -> Self::Cmp {
+            fn norm_sqr(self) -> Self::Scalar {
                 self.$first * self.$first $( + self.$rest * self.$rest )*
             }
+        }
 
+        impl<T: Component> NormedSpace for $V<T>
+        where
+            for<'a> &'a T: RefComponent<T>,
+        {
             #[inline]
-            fn dot(self, rhs: Self) -> Self::Cmp {
-                self.$first * rhs.$first $( + self.$rest * rhs.$rest )*
+            fn norm(self) -> Self::Scalar {
+                self.norm_sqr().sqrt()
             }
+        }
+
+        impl<T: Component> Vector for $V<T>
+        where
+            for<'a> &'a T: RefComponent<T>,
+        {
+            type Cmp = T;
+
+            const DIM: usize = $N;
 
             #[inline]
             fn is_finite(self) -> bool {
@@ -119,6 +163,81 @@ macro_rules! impl_vector {
 impl_vector!(Vec2D { x, y }; 2);
 impl_vector!(Vec3D { x, y, z }; 3);
 
+/// Plane-specific operations, including the product of `Vec2D` as complex
+/// numbers `x + i y` under explicit names, since `*` is not element-wise.
+impl<T: Component> Vec2D<T>
+where
+    for<'a> &'a T: RefComponent<T>,
+{
+    /// The unit vector at the angle counterclockwise from the x-axis.
+    #[inline]
+    pub fn from_angle(angle: Rad<T>) -> Self {
+        let (sin, cos) = angle.sin_cos();
+        Self::new(cos, sin)
+    }
+
+    /// The angle in `[-π, π]` counterclockwise from the x-axis, i.e. the
+    /// argument of the complex number.
+    #[inline]
+    pub fn angle(self) -> Rad<T> {
+        Rad::atan2(self.y, self.x)
+    }
+
+    /// The vector rotated by the angle counterclockwise.
+    ///
+    /// Use [`Rot2`](crate::Rot2) to rotate many vectors by the same angle.
+    #[inline]
+    pub fn rotate(self, angle: Rad<T>) -> Self {
+        self.complex_mul(Self::from_angle(angle))
+    }
+
+    /// The vector rotated by 90° counterclockwise, `(-y, x)`.
+    #[inline]
+    pub fn perp(self) -> Self {
+        Self::new(-self.y, self.x)
+    }
+
+    /// The product of complex numbers, which rotates by the argument of
+    /// `rhs` and scales by its norm.
+    #[inline]
+    pub fn complex_mul(self, rhs: Self) -> Self {
+        Self::new(
+            self.x * rhs.x - self.y * rhs.y,
+            self.x * rhs.y + self.y * rhs.x,
+        )
+    }
+
+    /// The quotient of complex numbers, the inverse of
+    /// [`complex_mul`](Vec2D::complex_mul) by `rhs`.
+    ///
+    /// Components are NaN or infinite if `rhs` is zero.
+    #[inline]
+    pub fn complex_div(self, rhs: Self) -> Self {
+        self.complex_mul(rhs.complex_conj()) / rhs.norm_sqr()
+    }
+
+    /// The complex conjugate `(x, -y)`, the reflection in the x-axis.
+    #[inline]
+    pub fn complex_conj(self) -> Self {
+        Self::new(self.x, -self.y)
+    }
+}
+
+impl<T: Component> Vec3D<T>
+where
+    for<'a> &'a T: RefComponent<T>,
+{
+    /// The cross product, perpendicular to both in the right-handed system.
+    #[inline]
+    pub fn cross(self, rhs: Self) -> Self {
+        Self::new(
+            self.y * rhs.z - self.z * rhs.y,
+            self.z * rhs.x - self.x * rhs.z,
+            self.x * rhs.y - self.y * rhs.x,
+        )
+    }
+}
+
 macro_rules! impl_vec_vec_op {
     ($V:ident{$( $cmp:ident ),+}; $($LB:ident)?; $($RB:ident)?; $Op:ident, $op:ident, $L:ty, $R:ty, $sym:tt) => {
         impl<T: $Op<S>$(+ $LB)? , S$(: $RB)?> $Op<$R> for $L {
@@ -319,3 +438,39 @@ macro_rules! impl_vec_traits {
 impl_vec_traits!(Vec1D { x }; 1);
 impl_vec_traits!(Vec2D { x, y }; 2);
 impl_vec_traits!(Vec3D { x, y, z }; 3);
+
+macro_rules! impl_index {
+    ($V:ident{$( $i:literal => $cmp:ident ),+}) => {
+        impl<T> Index<usize> for $V<T> {
+            type Output = T;
+
+            #[inline]
+            fn index(&self, index: usize) -> &Self::Output {
+                match index {
+                    $($i => &self.$cmp,)+
+                    _ => panic!(
+                        concat!("index out of bounds: ", stringify!($V), " has no component {}"),
+                        index
+                    ),
+                }
+            }
+        }
+
+        impl<T> IndexMut<usize> for $V<T> {
+            #[inline]
+            fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+                match index {
+                    $($i => &mut self.$cmp,)+
+                    _ => panic!(
+                        concat!("index out of bounds: ", stringify!($V), " has no component {}"),
+                        index
+                    ),
+                }
+            }
+        }
+    };
+}
+
+impl_index!(Vec1D { 0 => x });
+impl_index!(Vec2D { 0 => x, 1 => y });
+impl_index!(Vec3D { 0 => x, 1 => y, 2 => z });
@@ -1,3 +1,6 @@
+#![allow(clippy::op_ref)]
+
+use crate::components::Components;
 use crate::*;
 
 #[test]
@@ -118,6 +121,131 @@ fn distance_sqr() {
     );
 }
 
+#[test]
+fn lp_norms() {
+    let v = Vec3D::new(3f64, -4f64, 12f64);
+    assert_eq!(v.norm_l1(), 19f64);
+    assert_eq!(v.norm_inf(), 12f64);
+    assert_eq!(v.norm_lp(1f64), 19f64);
+    assert_eq!(v.norm_lp(2f64), 13f64);
+    assert_eq!(v.norm_lp(f64::INFINITY), 12f64);
+    assert!((v.norm_lp(3f64) - 1819f64.cbrt()).abs() < 1e-13);
+    assert!(v.norm_lp(100f64) >= 12f64 && v.norm_lp(100f64) < 12.001f64);
+    assert_eq!(
+        Vec2D::new(3e300f64, 4e300f64).norm_lp(4f64),
+        4e300f64 * (1f64 + 0.75f64.powi(4)).powf(0.25)
+    );
+    assert_eq!(Vec2D::new(0f32, 0f32).norm_lp(3f32), 0f32);
+    assert_eq!(Vec1D::new(-2f32).norm_lp(7f32), 2f32);
+    assert!(Vec2D::new(f64::NAN, 1f64).norm_inf().is_nan());
+    assert!(Vec2D::new(f64::NAN, 1f64).norm_lp(3f64).is_nan());
+    assert_eq!(
+        Vec2D::new(3f32, 4f32).norm_weighted(Vec2D::new(1f32, 0.25f32)),
+        13f32.sqrt()
+    );
+}
+
+#[test]
+fn norm_stable() {
+    fn test<V: Components>(v: V, a: V::Cmp)
+    where
+        for<'l> &'l V: VectorRefOps<V::Cmp, V>,
+    {
+        assert_eq!(v.norm_stable(), a, "|{v:?}| != {a}");
+    }
+    test(Vec1D::new(-3f64), 3f64);
+    test(Vec2D::new(5f32, 12f32), 13f32);
+    test(Vec3D::new(-9f64, 6f64, 2f64), 11f64);
+    let (big, small) = (2f64.powi(600), 2f64.powi(-600));
+    test(Vec2D::new(3f64 * big, 4f64 * big), 5f64 * big);
+    test(Vec2D::new(3f64 * small, -4f64 * small), 5f64 * small);
+    let (big, small) = (2f32.powi(100), 2f32.powi(-100));
+    test(Vec3D::new(2f32 * big, 3f32 * big, 6f32 * big), 7f32 * big);
+    test(
+        Vec3D::new(2f32 * small, 3f32 * small, 6f32 * small),
+        7f32 * small,
+    );
+    test(Vec2D::new(f64::MAX, 0f64), f64::MAX);
+    test(Vec3D::new(f32::MIN_POSITIVE, 0f32, 0f32), f32::MIN_POSITIVE);
+    test(Vec2D::new(0f64, 0f64), 0f64);
+    test(Vec2D::new(f64::NEG_INFINITY, f64::NAN), f64::INFINITY);
+    let fixed64 = Fixed64::from_int;
+    test(Vec2D::new(fixed64(3), fixed64(4)), fixed64(5));
+    test(Vec2D::new(fixed64(3000), fixed64(-4000)), fixed64(5000));
+    let fixed32 = Fixed32::from_int;
+    test(Vec3D::new(fixed32(2), fixed32(-1), fixed32(2)), fixed32(3));
+    test(
+        Vec3D::new(fixed32(-60), fixed32(30), fixed32(60)),
+        fixed32(90),
+    );
+    assert!(Vec3D::new(0f64, f64::NAN, 1f64).norm_stable().is_nan());
+
+    let v = Vec3D::new(1e300f64, -1e300f64, 1e300f64);
+    assert!(v.norm().is_infinite());
+    assert!((v.norm_stable() / (3f64.sqrt() * 1e300) - 1f64).abs() < 1e-15);
+    let tiny = Vec2D::new(5e-324f64, 5e-324f64);
+    assert_eq!(tiny.norm(), 0f64);
+    assert!(tiny.norm_stable() > 0f64);
+}
+
+#[test]
+fn distance_stable() {
+    assert_eq!(
+        Vec2D::new(1f64, 2f64).distance_stable(Vec2D::new(5f64, -1f64)),
+        5f64
+    );
+    let a = Vec2D::new(f64::MAX, 0f64);
+    let b = Vec2D::new(-f64::MAX, 0f64);
+    assert!(a.distance(b).is_infinite());
+    assert_eq!(a.distance_stable(b), f64::INFINITY);
+    let a = Vec3D::new(6e307f64, 1f64, 0f64);
+    let b = Vec3D::new(0f64, 1f64, -8e307f64);
+    assert_eq!(a.distance_stable(b), 1e308f64);
+    let a = Vec3D::new(6e37f32, 0f32, 1f32);
+    let b = Vec3D::new(0f32, -8e37f32, 1f32);
+    assert_eq!(a.distance_stable(b), 1e38f32);
+    assert!(a.distance(b).is_infinite());
+    let a = Vec2D::new(Fixed64::from_int(1), Fixed64::from_int(2));
+    let b = Vec2D::new(Fixed64::from_int(5), Fixed64::from_int(-1));
+    assert_eq!(a.distance_stable(b), Fixed64::from_int(5));
+    let a = Vec2D::new(Fixed32::from_int(-6), Fixed32::ZERO);
+    let b = Vec2D::new(Fixed32::ZERO, Fixed32::from_int(8));
+    assert_eq!(a.distance_stable(b), Fixed32::from_int(10));
+}
+
+#[test]
+fn normalize() {
+    assert_eq!(Vec2D::new(3f64, -4f64).normalize(), Vec2D::new(0.6, -0.8));
+    assert_eq!(
+        Vec2D::new(3e300f64, 4e300f64).normalize(),
+        Vec2D::new(0.6, 0.8)
+    );
+    assert_eq!(
+        Vec2D::new(-3e-310f64, 4e-310f64).normalize(),
+        Vec2D::new(-0.6, 0.8)
+    );
+    assert_eq!(Vec1D::new(-1e-40f32).normalize(), Vec1D::new(-1f32));
+    let n = Vec3D::new(1e-30f32, 2e-30f32, 2e-30f32).normalize();
+    assert!((n.norm() - 1f32).abs() <= f32::EPSILON);
+    assert!(Vec3D::new(0f64, 0f64, 0f64).normalize().has_nan());
+    assert!(Vec2D::new(f64::INFINITY, 0f64).normalize().has_nan());
+    let n = Vec2D::new(Fixed64::from_int(3), Fixed64::from_int(4)).normalize();
+    assert!((n.x - Fixed64::from_f64(0.6)).abs() <= Fixed64::DELTA);
+    assert!((n.y - Fixed64::from_f64(0.8)).abs() <= Fixed64::DELTA);
+    let n = Vec3D::new(
+        Fixed32::from_int(2),
+        Fixed32::from_int(-1),
+        Fixed32::from_int(2),
+    );
+    let n = n.normalize() * Fixed32::from_int(3);
+    let expected = Vec3D::new(
+        Fixed32::from_int(2),
+        Fixed32::from_int(-1),
+        Fixed32::from_int(2),
+    );
+    assert!(n.distance(expected) <= Fixed32::DELTA * Fixed32::from_int(4));
+}
+
 #[test]
 fn zero() {
     assert_eq!(Vec1D::zero(), Vec1D::new(0f32));
@@ -525,3 +653,73 @@ fn into_nd() {
     let x: VecND<_, 3> = Vec3D::new(5f64, -2f64, 7f64).into();
     assert_eq!(x, VecND::from([5f64, -2f64, 7f64]));
 }
+
+#[test]
+fn index() {
+    let v = Vec3D::new(1f64, 2f64, 3f64);
+    assert_eq!([v[0], v[1], v[2]], [1f64, 2f64, 3f64]);
+    assert_eq!(Vec1D::new(4f32)[0], 4f32);
+    assert_eq!(Vec2D::new(4f32, 5f32)[1], 5f32);
+
+    let mut v = Vec2D::new(1f64, 2f64);
+    v[0] = 7f64;
+    v[1] *= 3f64;
+    assert_eq!(v, Vec2D::new(7f64, 6f64));
+}
+
+#[test]
+#[should_panic]
+fn index_out_of_bounds() {
+    let _ = Vec2D::new(1f64, 2f64)[2];
+}
+
+#[test]
+fn plane_rotations() {
+    let v = Vec2D::new(3f64, 4f64);
+    assert_eq!(v.perp(), Vec2D::new(-4f64, 3f64));
+    assert_eq!(v.perp().perp(), -v);
+    assert_eq!(v.perp().dot(v), 0f64);
+    assert!(v.rotate(Rad::right()).distance(v.perp()) < 1e-15);
+    assert!(v.rotate(Deg(180f64).into()).distance(-v) < 1e-14);
+    assert!(Vec2D::from_angle(Deg(60f64).into()).distance(Vec2D::new(0.5, 0.75f64.sqrt())) < 1e-15);
+    assert_eq!(Vec2D::new(0f32, 2f32).angle(), Rad::right());
+    assert_eq!(Vec2D::new(-1f64, 0f64).angle(), Rad::half_turn());
+    let a = Rad(2.5f64);
+    assert!((Vec2D::from_angle(a).angle() - a).abs() < Rad(1e-15));
+}
+
+#[test]
+fn cross_product() {
+    let (x, y, z) = (
+        Vec3D::new(1f64, 0f64, 0f64),
+        Vec3D::new(0f64, 1f64, 0f64),
+        Vec3D::new(0f64, 0f64, 1f64),
+    );
+    assert_eq!(x.cross(y), z);
+    assert_eq!(y.cross(z), x);
+    assert_eq!(z.cross(x), y);
+    let (a, b) = (Vec3D::new(1f32, 2f32, 3f32), Vec3D::new(-2f32, 0f32, 5f32));
+    assert_eq!(a.cross(b), Vec3D::new(10f32, -11f32, 4f32));
+    assert_eq!(b.cross(a), -a.cross(b));
+    assert_eq!(a.cross(b).dot(a), 0f32);
+    assert_eq!(a.cross(a * 2f32), Vec3D::zero());
+}
+
+#[test]
+fn complex_product() {
+    let (a, b) = (Vec2D::new(1f64, 2f64), Vec2D::new(3f64, -1f64));
+    // (1 + 2i)(3 - i) = 5 + 5i
+    assert_eq!(a.complex_mul(b), Vec2D::new(5f64, 5f64));
+    assert_eq!(a.complex_mul(b), b.complex_mul(a));
+    assert_eq!(a.complex_conj(), Vec2D::new(1f64, -2f64));
+    assert_eq!(a.complex_mul(b).complex_div(b), a);
+    assert_eq!(a.complex_div(a), Vec2D::new(1f64, 0f64));
+    // i² = -1
+    let i = Vec2D::new(0f32, 1f32);
+    assert_eq!(i.complex_mul(i), Vec2D::new(-1f32, 0f32));
+    assert_eq!(
+        i.complex_mul(Vec2D::new(1f32, 2f32)),
+        Vec2D::new(-2f32, 1f32)
+    );
+    assert!(a.complex_div(Vec2D::zero()).has_nan());
+}
@@ -0,0 +1,927 @@
+#[cfg(test)]
+mod tests;
+
+use std::error::Error;
+use std::fmt::Debug;
+use std::fmt::Display;
+use std::iter::Sum;
+use std::num::FpCategory;
+use std::ops::Add;
+use std::ops::AddAssign;
+use std::ops::Div;
+use std::ops::DivAssign;
+use std::ops::Mul;
+use std::ops::MulAssign;
+use std::ops::Neg;
+use std::ops::Rem;
+use std::ops::RemAssign;
+use std::ops::Sub;
+use std::ops::SubAssign;
+
+use num_traits::Float;
+use num_traits::Num;
+use num_traits::NumCast;
+use num_traits::One;
+use num_traits::ToPrimitive;
+use num_traits::Zero;
+#[cfg(feature = "serde")]
+use serde::Deserialize;
+#[cfg(feature = "serde")]
+use serde::Serialize;
+
+/// Error parsing a fixed-point number.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ParseFixedError;
+
+impl Display for ParseFixedError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "invalid fixed-point literal")
+    }
+}
+
+impl Error for ParseFixedError {}
+
+/// The integer square root rounded toward zero, by Newton's method.
+fn isqrt(n: u128) -> u128 {
+    if n < 2 {
+        return n;
+    }
+    // A power of two not less than the root.
+    let mut x = 1u128 << ((129 - n.leading_zeros()) / 2);
+    loop {
+        let y = (x + n / x) / 2;
+        if y >= x {
+            return x;
+        }
+        x = y;
+    }
+}
+
+macro_rules! declare_fixed {
+    ($(#[$attr:meta])* $F:ident, $I:ty, $W:ty, $WU:ty, $frac:expr) => {
+        $(#[$attr])*
+        #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Hash)]
+        #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+        #[cfg_attr(feature = "serde", serde(transparent))]
+        pub struct $F($I);
+
+        impl $F {
+            /// The number of fractional bits.
+            pub const FRAC_BITS: u32 = $frac;
+            /// Zero.
+            pub const ZERO: Self = Self(0);
+            /// One.
+            pub const ONE: Self = Self(1 << $frac);
+            /// The largest value, which positive overflow saturates to.
+            pub const MAX: Self = Self(<$I>::MAX);
+            /// The smallest value, which negative overflow saturates to.
+            pub const MIN: Self = Self(<$I>::MIN);
+            /// The smallest positive value, i.e. the resolution.
+            pub const DELTA: Self = Self(1);
+            /// Archimedes' constant `π`.
+            pub const PI: Self = Self::from_f64_const(std::f64::consts::PI);
+            /// `π / 2`.
+            pub const FRAC_PI_2: Self = Self::from_f64_const(std::f64::consts::FRAC_PI_2);
+            /// `2π`.
+            pub const TAU: Self = Self::from_f64_const(std::f64::consts::TAU);
+            /// `ln(2)`.
+            pub const LN_2: Self = Self::from_f64_const(std::f64::consts::LN_2);
+            /// `ln(10)`.
+            pub const LN_10: Self = Self::from_f64_const(std::f64::consts::LN_10);
+
+            const HALF: Self = Self(1 << ($frac - 1));
+            const SCALE: f64 = (1u64 << $frac) as f64;
+
+            /// Creates a number from its raw representation, i.e. the value times `2^FRAC_BITS`.
+            #[inline]
+            pub const fn from_bits(bits: $I) -> Self {
+                Self(bits)
+            }
+
+            /// The raw representation, i.e. the value times `2^FRAC_BITS`.
+            #[inline]
+            pub const fn to_bits(self) -> $I {
+                self.0
+            }
+
+            /// Converts an integer, saturating on overflow.
+            #[inline]
+            pub const fn from_int(n: $I) -> Self {
+                Self::saturate((n as $W) << $frac)
+            }
+
+            /// Converts a floating-point number rounding to the nearest,
+            /// saturating on overflow; NaN is converted to zero.
+            #[inline]
+            pub fn from_f64(x: f64) -> Self {
+                Self((x * Self::SCALE).round() as $I)
+            }
+
+            /// Converts to a floating-point number, rounding to the nearest.
+            #[inline]
+            pub fn to_f64(self) -> f64 {
+                self.0 as f64 / Self::SCALE
+            }
+
+            const fn from_f64_const(x: f64) -> Self {
+                Self((x * Self::SCALE + 0.5) as $I)
+            }
+
+            #[inline]
+            const fn saturate(w: $W) -> Self {
+                if w > <$I>::MAX as $W {
+                    Self::MAX
+                } else if w < <$I>::MIN as $W {
+                    Self::MIN
+                } else {
+                    Self(w as $I)
+                }
+            }
+
+            /// Divides by an integer, rounding toward zero.
+            #[inline]
+            fn div_int(self, n: $I) -> Self {
+                Self(self.0 / n)
+            }
+
+            /// Reduces an angle into `[-π, π]`.
+            #[inline]
+            fn reduce_angle(self) -> Self {
+                let r = self.0 % Self::TAU.0;
+                Self(if r > Self::PI.0 {
+                    r - Self::TAU.0
+                } else if r < -Self::PI.0 {
+                    r + Self::TAU.0
+                } else {
+                    r
+                })
+            }
+
+            /// The sine of an angle in `[-3π/2, 3π/2]`.
+            fn sin_reduced(self) -> Self {
+                let x = if self > Self::FRAC_PI_2 {
+                    Self::PI - self
+                } else if self < -Self::FRAC_PI_2 {
+                    -Self::PI - self
+                } else {
+                    self
+                };
+                let x2 = x * x;
+                let mut term = x;
+                let mut sum = x;
+                let mut n: $I = 1;
+                loop {
+                    term = -(term * x2).div_int((2 * n) * (2 * n + 1));
+                    if term.0 == 0 {
+                        break;
+                    }
+                    sum += term;
+                    n += 1;
+                }
+                sum.max(-Self::ONE).min(Self::ONE)
+            }
+
+            /// The arctangent of `x` in `[0, 1]`.
+            fn atan_unit(self) -> Self {
+                // halve the angle so that the series converges quickly
+                let y = self / (Self::ONE + (Self::ONE + self * self).sqrt());
+                let y2 = y * y;
+                let mut term = y;
+                let mut sum = y;
+                let mut n: $I = 1;
+                loop {
+                    term = -(term * y2);
+                    let t = term.div_int(2 * n + 1);
+                    if t.0 == 0 {
+                        break;
+                    }
+                    sum += t;
+                    n += 1;
+                }
+                sum + sum
+            }
+        }
+
+        impl Debug for $F {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                Display::fmt(self, f)
+            }
+        }
+
+        impl Display for $F {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                Display::fmt(&Self::to_f64(*self), f)
+            }
+        }
+
+        impl Neg for $F {
+            type Output = Self;
+
+            #[inline]
+            fn neg(self) -> Self {
+                Self(self.0.saturating_neg())
+            }
+        }
+
+        impl Neg for &$F {
+            type Output = $F;
+
+            #[inline]
+            fn neg(self) -> $F {
+                -*self
+            }
+        }
+
+        impl Add for $F {
+            type Output = Self;
+
+            #[inline]
+            fn add(self, rhs: Self) -> Self {
+                Self(self.0.saturating_add(rhs.0))
+            }
+        }
+
+        impl Sub for $F {
+            type Output = Self;
+
+            #[inline]
+            fn sub(self, rhs: Self) -> Self {
+                Self(self.0.saturating_sub(rhs.0))
+            }
+        }
+
+        impl Mul for $F {
+            type Output = Self;
+
+            /// The product rounded to the nearest, ties toward +∞.
+            #[inline]
+            fn mul(self, rhs: Self) -> Self {
+                let p = self.0 as $W * rhs.0 as $W;
+                Self::saturate((p + (1 << ($frac - 1))) >> $frac)
+            }
+        }
+
+        impl Div for $F {
+            type Output = Self;
+
+            /// The quotient rounded toward zero; division by zero saturates
+            /// unless the dividend is also zero, which gives zero.
+            #[inline]
+            fn div(self, rhs: Self) -> Self {
+                if rhs.0 == 0 {
+                    return match self.0.signum() {
+                        1 => Self::MAX,
+                        -1 => Self::MIN,
+                        _ => Self::ZERO,
+                    };
+                }
+                Self::saturate(((self.0 as $W) << $frac) / rhs.0 as $W)
+            }
+        }
+
+        impl Rem for $F {
+            type Output = Self;
+
+            /// The remainder with the sign of the dividend, zero for the divisor zero.
+            #[inline]
+            fn rem(self, rhs: Self) -> Self {
+                Self(self.0.checked_rem(rhs.0).unwrap_or(0))
+            }
+        }
+
+        impl_fixed_ref_ops!(
+            $F;
+            Add, add, AddAssign, add_assign;
+            Sub, sub, SubAssign, sub_assign;
+            Mul, mul, MulAssign, mul_assign;
+            Div, div, DivAssign, div_assign;
+            Rem, rem, RemAssign, rem_assign
+        );
+
+        impl Sum for $F {
+            #[inline]
+            fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+                iter.fold(Self::ZERO, |a, b| a + b)
+            }
+        }
+
+        impl Zero for $F {
+            #[inline]
+            fn zero() -> Self {
+                Self::ZERO
+            }
+
+            #[inline]
+            fn is_zero(&self) -> bool {
+                self.0 == 0
+            }
+        }
+
+        impl One for $F {
+            #[inline]
+            fn one() -> Self {
+                Self::ONE
+            }
+        }
+
+        impl Num for $F {
+            type FromStrRadixErr = ParseFixedError;
+
+            /// Parses `[+-]digits[.digits]`, rounding to the nearest and saturating on overflow.
+            fn from_str_radix(str: &str, radix: u32) -> Result<Self, ParseFixedError> {
+                if !(2..=36).contains(&radix) {
+                    return Err(ParseFixedError);
+                }
+                let (negative, str) = match str.as_bytes().first() {
+                    Some(b'-') => (true, &str[1..]),
+                    Some(b'+') => (false, &str[1..]),
+                    _ => (false, str),
+                };
+                let (int, frac) = str.split_once('.').unwrap_or((str, ""));
+                if int.is_empty() && frac.is_empty() {
+                    return Err(ParseFixedError);
+                }
+                let digit = |c: char| c.to_digit(radix).ok_or(ParseFixedError);
+
+                let limit = <$I>::MAX as $WU + 1;
+                let mut int_part: $WU = 0;
+                for c in int.chars() {
+                    int_part = (int_part * radix as $WU + digit(c)? as $WU).min(limit);
+                }
+                // keep the denominator small enough to be shifted by the fractional bits
+                let max_denominator: $WU = 1 << (<$WU>::BITS - $frac - 8);
+                let mut numerator: $WU = 0;
+                let mut denominator: $WU = 1;
+                for c in frac.chars() {
+                    let d = digit(c)? as $WU;
+                    if denominator < max_denominator / radix as $WU {
+                        numerator = numerator * radix as $WU + d;
+                        denominator *= radix as $WU;
+                    }
+                }
+                let frac_part = ((numerator << $frac) + denominator / 2) / denominator;
+                let magnitude = (int_part << $frac).saturating_add(frac_part);
+                let raw = if negative {
+                    -(magnitude.min(limit << $frac) as $W)
+                } else {
+                    magnitude.min(limit << $frac) as $W
+                };
+                Ok(Self::saturate(raw))
+            }
+        }
+
+        impl ToPrimitive for $F {
+            #[inline]
+            fn to_i64(&self) -> Option<i64> {
+                Some((self.0 / Self::ONE.0) as i64)
+            }
+
+            #[inline]
+            fn to_u64(&self) -> Option<u64> {
+                (self.0 / Self::ONE.0).to_u64()
+            }
+
+            #[inline]
+            fn to_f64(&self) -> Option<f64> {
+                Some(Self::to_f64(*self))
+            }
+        }
+
+        impl NumCast for $F {
+            /// Converts a number in range rounding to the nearest, or gives `None`.
+            #[inline]
+            fn from<P: ToPrimitive>(n: P) -> Option<Self> {
+                let x = (n.to_f64()? * Self::SCALE).round();
+                (x >= <$I>::MIN as f64 && x < <$I>::MAX as f64).then(|| Self(x as $I))
+            }
+        }
+
+        /// There is no NaN nor infinity: `nan()` is zero and the infinities are
+        /// [`MAX`](Self::MAX) and [`MIN`](Self::MIN).
+        /// Functions outside of their domain return zero, except that
+        /// the logarithms of non-positive numbers are `MIN`.
+        impl Float for $F {
+            #[inline]
+            fn nan() -> Self {
+                Self::ZERO
+            }
+
+            #[inline]
+            fn infinity() -> Self {
+                Self::MAX
+            }
+
+            #[inline]
+            fn neg_infinity() -> Self {
+                Self::MIN
+            }
+
+            #[inline]
+            fn neg_zero() -> Self {
+                Self::ZERO
+            }
+
+            #[inline]
+            fn min_value() -> Self {
+                Self::MIN
+            }
+
+            #[inline]
+            fn min_positive_value() -> Self {
+                Self::DELTA
+            }
+
+            #[inline]
+            fn epsilon() -> Self {
+                Self::DELTA
+            }
+
+            #[inline]
+            fn max_value() -> Self {
+                Self::MAX
+            }
+
+            #[inline]
+            fn is_nan(self) -> bool {
+                false
+            }
+
+            #[inline]
+            fn is_infinite(self) -> bool {
+                false
+            }
+
+            #[inline]
+            fn is_finite(self) -> bool {
+                true
+            }
+
+            #[inline]
+            fn is_normal(self) -> bool {
+                self.0 != 0
+            }
+
+            #[inline]
+            fn classify(self) -> FpCategory {
+                if self.0 == 0 {
+                    FpCategory::Zero
+                } else {
+                    FpCategory::Normal
+                }
+            }
+
+            #[inline]
+            fn floor(self) -> Self {
+                Self(self.0 & !(Self::ONE.0 - 1))
+            }
+
+            #[inline]
+            fn ceil(self) -> Self {
+                -(-self).floor()
+            }
+
+            /// Rounds half-way cases away from zero.
+            #[inline]
+            fn round(self) -> Self {
+                if self.0 < 0 {
+                    -(-self).round()
+                } else {
+                    (self + Self::HALF).floor()
+                }
+            }
+
+            #[inline]
+            fn trunc(self) -> Self {
+                if self.0 < 0 {
+                    self.ceil()
+                } else {
+                    self.floor()
+                }
+            }
+
+            #[inline]
+            fn fract(self) -> Self {
+                self - self.trunc()
+            }
+
+            #[inline]
+            fn abs(self) -> Self {
+                Self(self.0.saturating_abs())
+            }
+
+            #[inline]
+            fn signum(self) -> Self {
+                if self.0 < 0 {
+                    -Self::ONE
+                } else {
+                    Self::ONE
+                }
+            }
+
+            #[inline]
+            fn is_sign_positive(self) -> bool {
+                self.0 >= 0
+            }
+
+            #[inline]
+            fn is_sign_negative(self) -> bool {
+                self.0 < 0
+            }
+
+            #[inline]
+            fn mul_add(self, a: Self, b: Self) -> Self {
+                self * a + b
+            }
+
+            #[inline]
+            fn recip(self) -> Self {
+                Self::ONE / self
+            }
+
+            fn powi(self, n: i32) -> Self {
+                let mut base = self;
+                let mut result = Self::ONE;
+                let mut e = n.unsigned_abs();
+                while e > 0 {
+                    if e & 1 == 1 {
+                        result *= base;
+                    }
+                    e >>= 1;
+                    if e > 0 {
+                        base *= base;
+                    }
+                }
+                if n < 0 {
+                    result.recip()
+                } else {
+                    result
+                }
+            }
+
+            fn powf(self, n: Self) -> Self {
+                if self.0 > 0 {
+                    (n * self.ln()).exp()
+                } else if n.fract().0 == 0 {
+                    let e = (n.0 / Self::ONE.0).clamp(i32::MIN as $I, i32::MAX as $I);
+                    self.powi(e as i32)
+                } else {
+                    Self::ZERO
+                }
+            }
+
+            /// The square root rounded toward zero, computed exactly with integers.
+            #[inline]
+            fn sqrt(self) -> Self {
+                if self.0 <= 0 {
+                    return Self::ZERO;
+                }
+                Self::saturate(isqrt(((self.0 as $WU) << $frac) as u128) as $W)
+            }
+
+            fn exp(self) -> Self {
+                let bits = <$I>::BITS as $I;
+                if self > Self::from_int(bits) {
+                    return Self::MAX;
+                }
+                if self < Self::from_int(-bits) {
+                    return Self::ZERO;
+                }
+                // exp(x) = 2^k exp(r) with |r| <= ln(2) / 2
+                let k = (self / Self::LN_2).round().0 >> $frac;
+                let r = self - Self::LN_2 * Self::from_int(k);
+                let mut term = Self::ONE;
+                let mut sum = Self::ONE;
+                let mut n: $I = 1;
+                loop {
+                    term = (term * r).div_int(n);
+                    if term.0 == 0 {
+                        break;
+                    }
+                    sum += term;
+                    n += 1;
+                }
+                if k >= 0 {
+                    Self::saturate((sum.0 as $W) << k)
+                } else if -k < bits {
+                    Self(sum.0 >> -k)
+                } else {
+                    Self::ZERO
+                }
+            }
+
+            #[inline]
+            fn exp2(self) -> Self {
+                (self * Self::LN_2).exp()
+            }
+
+            fn ln(self) -> Self {
+                if self.0 <= 0 {
+                    return Self::MIN;
+                }
+                // ln(x) = k ln(2) + ln(m) with m in [1, 2)
+                let msb = (<$I>::BITS - 1 - self.0.leading_zeros()) as $I;
+                let k = msb - $frac;
+                let m = if k >= 0 {
+                    Self(self.0 >> k)
+                } else {
+                    Self(self.0 << -k)
+                };
+                // ln(m) = 2 atanh(s) with s = (m - 1) / (m + 1) in [0, 1/3)
+                let s = (m - Self::ONE) / (m + Self::ONE);
+                let s2 = s * s;
+                let mut term = s;
+                let mut sum = s;
+                let mut n: $I = 1;
+                loop {
+                    term *= s2;
+                    let t = term.div_int(2 * n + 1);
+                    if t.0 == 0 {
+                        break;
+                    }
+                    sum += t;
+                    n += 1;
+                }
+                Self::LN_2 * Self::from_int(k) + sum + sum
+            }
+
+            #[inline]
+            fn log(self, base: Self) -> Self {
+                self.ln() / base.ln()
+            }
+
+            #[inline]
+            fn log2(self) -> Self {
+                self.ln() / Self::LN_2
+            }
+
+            #[inline]
+            fn log10(self) -> Self {
+                self.ln() / Self::LN_10
+            }
+
+            #[inline]
+            fn to_degrees(self) -> Self {
+                (self / Self::PI) * Self::from_int(180)
+            }
+
+            #[inline]
+            fn to_radians(self) -> Self {
+                (self * Self::PI).div_int(180)
+            }
+
+            #[inline]
+            fn max(self, other: Self) -> Self {
+                Self(self.0.max(other.0))
+            }
+
+            #[inline]
+            fn min(self, other: Self) -> Self {
+                Self(self.0.min(other.0))
+            }
+
+            #[inline]
+            fn abs_sub(self, other: Self) -> Self {
+                (self - other).max(Self::ZERO)
+            }
+
+            /// The cube root rounded toward zero, computed exactly with integers.
+            fn cbrt(self) -> Self {
+                // bitwise integer cube root from Hacker's Delight
+                let mut x = (self.0.unsigned_abs() as $WU) << (2 * $frac);
+                let mut y: $WU = 0;
+                let mut s = (<$WU>::BITS - 1) / 3 * 3;
+                loop {
+                    y *= 2;
+                    let b = 3 * y * (y + 1) + 1;
+                    if (x >> s) >= b {
+                        x -= b << s;
+                        y += 1;
+                    }
+                    if s == 0 {
+                        break;
+                    }
+                    s -= 3;
+                }
+                let c = Self(y as $I);
+                if self.0 < 0 {
+                    -c
+                } else {
+                    c
+                }
+            }
+
+            /// `sqrt(x² + y²)` rounded toward zero, computed exactly with integers.
+            #[inline]
+            fn hypot(self, other: Self) -> Self {
+                let a = self.0.unsigned_abs() as $WU;
+                let b = other.0.unsigned_abs() as $WU;
+                Self::saturate(isqrt((a * a + b * b) as u128) as $W)
+            }
+
+            #[inline]
+            fn sin(self) -> Self {
+                self.reduce_angle().sin_reduced()
+            }
+
+            #[inline]
+            fn cos(self) -> Self {
+                (self.reduce_angle() + Self::FRAC_PI_2).sin_reduced()
+            }
+
+            #[inline]
+            fn tan(self) -> Self {
+                self.sin() / self.cos()
+            }
+
+            #[inline]
+            fn asin(self) -> Self {
+                let x = self.max(-Self::ONE).min(Self::ONE);
+                x.atan2((Self::ONE - x * x).sqrt())
+            }
+
+            #[inline]
+            fn acos(self) -> Self {
+                let x = self.max(-Self::ONE).min(Self::ONE);
+                (Self::ONE - x * x).sqrt().atan2(x)
+            }
+
+            fn atan(self) -> Self {
+                if self.0 < 0 {
+                    -(-self).atan()
+                } else if self > Self::ONE {
+                    Self::FRAC_PI_2 - self.recip().atan_unit()
+                } else {
+                    self.atan_unit()
+                }
+            }
+
+            fn atan2(self, other: Self) -> Self {
+                match (other.0.signum(), self.0.signum()) {
+                    (1, _) => (self / other).atan(),
+                    (-1, -1) => (self / other).atan() - Self::PI,
+                    (-1, _) => (self / other).atan() + Self::PI,
+                    (_, 1) => Self::FRAC_PI_2,
+                    (_, -1) => -Self::FRAC_PI_2,
+                    _ => Self::ZERO,
+                }
+            }
+
+            #[inline]
+            fn sin_cos(self) -> (Self, Self) {
+                (self.sin(), self.cos())
+            }
+
+            #[inline]
+            fn exp_m1(self) -> Self {
+                self.exp() - Self::ONE
+            }
+
+            #[inline]
+            fn ln_1p(self) -> Self {
+                (Self::ONE + self).ln()
+            }
+
+            #[inline]
+            fn sinh(self) -> Self {
+                (self.exp() - (-self).exp()).div_int(2)
+            }
+
+            #[inline]
+            fn cosh(self) -> Self {
+                (self.exp() + (-self).exp()).div_int(2)
+            }
+
+            #[inline]
+            fn tanh(self) -> Self {
+                let e = (-(self.abs() + self.abs())).exp();
+                let t = (Self::ONE - e) / (Self::ONE + e);
+                if self.0 < 0 {
+                    -t
+                } else {
+                    t
+                }
+            }
+
+            #[inline]
+            fn asinh(self) -> Self {
+                let a = self.abs();
+                let s = (a + (a * a + Self::ONE).sqrt()).ln();
+                if self.0 < 0 {
+                    -s
+                } else {
+                    s
+                }
+            }
+
+            #[inline]
+            fn acosh(self) -> Self {
+                if self < Self::ONE {
+                    return Self::ZERO;
+                }
+                (self + (self * self - Self::ONE).sqrt()).ln()
+            }
+
+            #[inline]
+            fn atanh(self) -> Self {
+                if self >= Self::ONE {
+                    return Self::MAX;
+                }
+                if self <= -Self::ONE {
+                    return Self::MIN;
+                }
+                ((Self::ONE + self) / (Self::ONE - self)).ln().div_int(2)
+            }
+
+            #[inline]
+            fn integer_decode(self) -> (u64, i16, i8) {
+                let sign = if self.0 < 0 { -1 } else { 1 };
+                (self.0.unsigned_abs() as u64, -($frac as i16), sign)
+            }
+        }
+    };
+}
+
+macro_rules! impl_fixed_ref_ops {
+    ($F:ident; $($Op:ident, $op:ident, $OpAssign:ident, $op_assign:ident);+) => {
+        $(
+            impl $Op<&$F> for $F {
+                type Output = $F;
+
+                #[inline]
+                fn $op(self, rhs: &$F) -> $F {
+                    self.$op(*rhs)
+                }
+            }
+
+            impl $Op<$F> for &$F {
+                type Output = $F;
+
+                #[inline]
+                fn $op(self, rhs: $F) -> $F {
+                    (*self).$op(rhs)
+                }
+            }
+
+            impl $Op<&$F> for &$F {
+                type Output = $F;
+
+                #[inline]
+                fn $op(self, rhs: &$F) -> $F {
+                    (*self).$op(*rhs)
+                }
+            }
+
+            impl $OpAssign for $F {
+                #[inline]
+                fn $op_assign(&mut self, rhs: $F) {
+                    *self = (*self).$op(rhs);
+                }
+            }
+
+            impl $OpAssign<&$F> for $F {
+                #[inline]
+                fn $op_assign(&mut self, rhs: &$F) {
+                    *self = (*self).$op(*rhs);
+                }
+            }
+        )+
+    };
+}
+
+declare_fixed!(
+    /// Q32.32 fixed-point number for deterministic computation.
+    ///
+    /// All the operations, including [`sqrt`](Float::sqrt) and the transcendental
+    /// functions, are computed with integers, so the results are bit-identical
+    /// on all platforms. Overflow saturates to [`MAX`](Self::MAX) or [`MIN`](Self::MIN).
+    ///
+    /// It implements [`Float`] and is therefore a [`Component`](crate::Component).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ::ndvec::*;
+    ///
+    /// let a = Vec2D::new(Fixed64::from_int(3), Fixed64::from_int(4));
+    /// assert_eq!(a.norm(), Fixed64::from_int(5));
+    /// assert_eq!(Fixed64::MAX + Fixed64::ONE, Fixed64::MAX);
+    /// ```
+    Fixed64, i64, i128, u128, 32
+);
+
+declare_fixed!(
+    /// Q16.16 fixed-point number for deterministic computation.
+    ///
+    /// See [`Fixed64`] for the details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ::ndvec::*;
+    ///
+    /// let a = Vec3D::new(Fixed32::from_int(2), Fixed32::from_int(-1), Fixed32::from_int(2));
+    /// assert_eq!(a.norm(), Fixed32::from_int(3));
+    /// assert_eq!(Fixed32::from_int(40000), Fixed32::MAX);
+    /// ```
+    Fixed32, i32, i64, u64, 16
+);
@@ -0,0 +1,213 @@
+#![allow(clippy::op_ref)]
+
+use num_traits::Float;
+use num_traits::Num;
+use num_traits::NumCast;
+
+use crate::testing::Lcg;
+use crate::*;
+
+fn q64(x: f64) -> Fixed64 {
+    Fixed64::from_f64(x)
+}
+
+fn q32(x: f64) -> Fixed32 {
+    Fixed32::from_f64(x)
+}
+
+#[test]
+fn conversions() {
+    assert_eq!(Fixed64::ONE.to_bits(), 1 << 32);
+    assert_eq!(Fixed32::ONE.to_bits(), 1 << 16);
+    assert_eq!(q64(1.5).to_f64(), 1.5);
+    assert_eq!(q32(-2.25).to_f64(), -2.25);
+    assert_eq!(Fixed64::from_int(-7).to_f64(), -7.0);
+    assert_eq!(Fixed32::from_int(1 << 20), Fixed32::MAX);
+    assert_eq!(Fixed32::from_f64(1e10), Fixed32::MAX);
+    assert_eq!(Fixed32::from_f64(f64::NAN), Fixed32::ZERO);
+    assert_eq!(<Fixed32 as NumCast>::from(2.5f32), Some(q32(2.5)));
+    assert_eq!(<Fixed32 as NumCast>::from(1e10), None);
+    assert_eq!(num_traits::ToPrimitive::to_i32(&q64(-2.75)), Some(-2));
+    assert_eq!(q64(0.5).to_string(), "0.5");
+    assert_eq!(format!("{:?}", q32(-1.25)), "-1.25");
+}
+
+#[test]
+fn parse() {
+    assert_eq!(Fixed64::from_str_radix("1.5", 10), Ok(q64(1.5)));
+    assert_eq!(Fixed64::from_str_radix("-0.125", 10), Ok(q64(-0.125)));
+    assert_eq!(
+        Fixed32::from_str_radix("+12", 10),
+        Ok(Fixed32::from_int(12))
+    );
+    assert_eq!(Fixed32::from_str_radix("ff.8", 16), Ok(q32(255.5)));
+    assert_eq!(Fixed64::from_str_radix(".1", 10), Ok(q64(0.1)));
+    assert_eq!(
+        Fixed64::from_str_radix("3.14159265358979323846264338327950288", 10),
+        Ok(Fixed64::PI)
+    );
+    assert_eq!(Fixed32::from_str_radix("99999999", 10), Ok(Fixed32::MAX));
+    assert_eq!(Fixed32::from_str_radix("-99999999", 10), Ok(Fixed32::MIN));
+    assert_eq!(Fixed32::from_str_radix("1.2.3", 10), Err(ParseFixedError));
+    assert_eq!(Fixed32::from_str_radix("", 10), Err(ParseFixedError));
+    assert_eq!(Fixed32::from_str_radix("1a", 10), Err(ParseFixedError));
+}
+
+#[test]
+fn saturating_arithmetic() {
+    assert_eq!(Fixed64::MAX + Fixed64::ONE, Fixed64::MAX);
+    assert_eq!(Fixed64::MIN - Fixed64::ONE, Fixed64::MIN);
+    assert_eq!(-Fixed64::MIN, Fixed64::MAX);
+    assert_eq!(
+        Fixed32::from_int(300) * Fixed32::from_int(300),
+        Fixed32::MAX
+    );
+    assert_eq!(
+        Fixed32::from_int(-300) * Fixed32::from_int(300),
+        Fixed32::MIN
+    );
+    assert_eq!(Fixed32::ONE / Fixed32::ZERO, Fixed32::MAX);
+    assert_eq!(-Fixed32::ONE / Fixed32::ZERO, Fixed32::MIN);
+    assert_eq!(Fixed32::ZERO / Fixed32::ZERO, Fixed32::ZERO);
+    assert_eq!(Fixed32::from_int(1000) / Fixed32::DELTA, Fixed32::MAX);
+    assert_eq!(Fixed32::MIN % -Fixed32::DELTA, Fixed32::ZERO);
+    assert_eq!(Fixed32::ONE % Fixed32::ZERO, Fixed32::ZERO);
+    assert_eq!(Fixed64::MIN.abs(), Fixed64::MAX);
+}
+
+#[test]
+fn exact_arithmetic() {
+    let a = q64(3.25);
+    let b = q64(-1.5);
+    assert_eq!(a + b, q64(1.75));
+    assert_eq!(&a - b, q64(4.75));
+    assert_eq!(a * &b, q64(-4.875));
+    assert_eq!(
+        &a / &b,
+        Fixed64::from_bits((-(3.25 / 1.5) * 2f64.powi(32)) as i64)
+    );
+    assert_eq!(a % b, q64(0.25));
+    let mut c = a;
+    c *= b;
+    c += &a;
+    assert_eq!(c, q64(-1.625));
+    let s: Fixed32 = [q32(0.5), q32(0.25), q32(1.0)].into_iter().sum();
+    assert_eq!(s, q32(1.75));
+}
+
+#[test]
+fn rounding_functions() {
+    assert_eq!(q32(2.5).floor(), q32(2.0));
+    assert_eq!(q32(-2.5).floor(), q32(-3.0));
+    assert_eq!(q32(-2.5).ceil(), q32(-2.0));
+    assert_eq!(q32(2.5).round(), q32(3.0));
+    assert_eq!(q32(-2.5).round(), q32(-3.0));
+    assert_eq!(q32(-2.75).trunc(), q32(-2.0));
+    assert_eq!(q32(-2.75).fract(), q32(-0.75));
+    assert_eq!(q32(-2.0).signum(), q32(-1.0));
+    assert_eq!(q32(1.0).max(q32(2.0)), q32(2.0));
+}
+
+#[test]
+fn integer_square_root() {
+    for n in 0..10000u128 {
+        let r = super::isqrt(n);
+        assert!(r * r <= n && (r + 1) * (r + 1) > n, "isqrt({n}) = {r}");
+    }
+    assert_eq!(super::isqrt(u128::MAX), u64::MAX as u128);
+    assert_eq!(super::isqrt(1 << 126), 1 << 63);
+    assert_eq!(super::isqrt((1 << 126) - 1), (1 << 63) - 1);
+}
+
+#[test]
+fn square_and_cube_roots_are_exact() {
+    assert_eq!(Fixed64::from_int(4).sqrt(), Fixed64::from_int(2));
+    assert_eq!(q64(0.25).sqrt(), q64(0.5));
+    assert_eq!(Fixed32::from_int(-4).sqrt(), Fixed32::ZERO);
+    assert_eq!(Fixed64::from_int(27).cbrt(), Fixed64::from_int(3));
+    assert_eq!(Fixed64::from_int(-8).cbrt(), Fixed64::from_int(-2));
+    assert_eq!(Fixed32::from_int(64).cbrt(), Fixed32::from_int(4));
+    let mut rng = Lcg(1);
+    for _ in 0..1000 {
+        let x = q64(rng.unit() * 1e6);
+        let r = x.sqrt();
+        // the largest root whose square does not exceed x
+        let raw = x.to_bits() as i128;
+        let s = r.to_bits() as i128;
+        assert!(s * s <= raw << 32 && (s + 1) * (s + 1) > raw << 32);
+        let c = x.cbrt().to_bits() as i128;
+        assert!(c * c * c <= raw << 64 && (c + 1) * (c + 1) * (c + 1) > raw << 64);
+    }
+}
+
+#[test]
+fn elementary_functions() {
+    let mut rng = Lcg(2);
+    type Case = (fn(f64) -> f64, fn(Fixed64) -> Fixed64, f64, f64);
+    let cases: [Case; 12] = [
+        (f64::exp, Fixed64::exp, -10.0, 10.0),
+        (f64::ln, Fixed64::ln, 1e-3, 1e6),
+        (f64::sin, Fixed64::sin, -100.0, 100.0),
+        (f64::cos, Fixed64::cos, -100.0, 100.0),
+        (f64::tan, Fixed64::tan, -1.5, 1.5),
+        (f64::atan, Fixed64::atan, -100.0, 100.0),
+        (f64::asin, Fixed64::asin, -1.0, 1.0),
+        (f64::acos, Fixed64::acos, -1.0, 1.0),
+        (f64::tanh, Fixed64::tanh, -10.0, 10.0),
+        (f64::sinh, Fixed64::sinh, -5.0, 5.0),
+        (f64::log2, Fixed64::log2, 1e-3, 1e6),
+        (f64::exp2, Fixed64::exp2, -10.0, 10.0),
+    ];
+    for (f, g, lo, hi) in cases {
+        for _ in 0..200 {
+            let x = q64(lo + (hi - lo) * rng.unit());
+            let expected = f(x.to_f64());
+            let actual = g(x).to_f64();
+            assert!(
+                (actual - expected).abs() <= 1e-8 * (1.0 + expected.abs()),
+                "{} {} {}",
+                x,
+                actual,
+                expected
+            );
+        }
+    }
+    assert!((Fixed32::ONE.exp().to_f64() - std::f64::consts::E).abs() < 1e-4);
+    assert!((Fixed32::PI.sin().to_f64()).abs() < 1e-4);
+    assert_eq!(Fixed64::ZERO.ln(), Fixed64::MIN);
+    assert_eq!(Fixed32::from_int(20).exp(), Fixed32::MAX);
+    assert_eq!(Fixed32::from_int(-20).exp(), Fixed32::ZERO);
+    let angle = Fixed64::from_int(-1).atan2(Fixed64::from_int(-1)).to_f64();
+    assert!((angle + 0.75 * std::f64::consts::PI).abs() < 1e-8);
+    assert!((q64(2.0).powf(q64(0.5)).to_f64() - 2f64.sqrt()).abs() < 1e-8);
+    assert_eq!(q64(-2.0).powf(q64(3.0)), q64(-8.0));
+    assert_eq!(q64(1.5).powi(-2), Fixed64::ONE / q64(2.25));
+}
+
+#[test]
+fn vectors_of_fixed_point() {
+    let a = Vec3D::new(q64(1.0), q64(2.0), q64(2.0));
+    let b = Vec3D::new(q64(4.0), q64(6.0), q64(2.0));
+    assert_eq!(a.norm(), Fixed64::from_int(3));
+    assert_eq!(a.distance(b), Fixed64::from_int(5));
+    assert_eq!(a.dot(b), Fixed64::from_int(20));
+    assert_eq!(&a + &b, Vec3D::new(q64(5.0), q64(8.0), q64(4.0)));
+    assert_eq!(-a * q64(0.5), Vec3D::new(q64(-0.5), q64(-1.0), q64(-1.0)));
+
+    let v = Vec2D::new(Fixed32::from_int(200), Fixed32::from_int(300));
+    assert_eq!(v.norm_sqr(), Fixed32::MAX);
+    assert_eq!(
+        v * Fixed32::from_int(1000),
+        Vec2D::new(Fixed32::MAX, Fixed32::MAX)
+    );
+    assert_eq!(v.norm().to_bits(), Fixed32::MAX.sqrt().to_bits());
+}
+
+#[test]
+fn deterministic_norms() {
+    // raw results pinned so that any change of the algorithms is noticed
+    let a = Vec2D::new(Fixed32::from_bits(123_456), Fixed32::from_bits(-654_321));
+    assert_eq!(a.norm().to_bits(), 665_865);
+    let b = Vec3D::new(q64(0.1), q64(0.2), q64(0.3));
+    assert_eq!(b.norm().to_bits(), 1_607_029_611);
+}
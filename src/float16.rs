@@ -0,0 +1,169 @@
+#[cfg(test)]
+mod tests;
+
+pub use half;
+
+use half::bf16;
+use half::f16;
+
+use crate::Vec1D;
+use crate::Vec2D;
+use crate::Vec3D;
+use crate::VecND;
+
+/// Storage of 16-bit floats convertible to and from its `f32` counterpart,
+/// e.g. [`f16`](half::f16) and `f32`, or `Vec3D<f16>` and [`V3D32`](crate::V3D32).
+///
+/// [`f16`](half::f16) and [`bf16`](half::bf16) implement [`Float`](num_traits::Float)
+/// and are therefore [`Component`](crate::Component)s, but each operation rounds to 16 bits;
+/// it is usually better to store in 16 bits and to compute in `f32`.
+///
+/// # Examples
+///
+/// ```
+/// use ::ndvec::*;
+/// use ::ndvec::half::f16;
+///
+/// let points = [Vec3D::new(0.1f32, -2.0, 65504.0), Vec3D::new(1.0, 1e-3, 7.0)];
+/// let stored: Vec<Vec3D<f16>> = from_f32_vec(&points);
+/// let restored = to_f32_vec(&stored);
+/// assert!(restored[0].distance(points[0]) < 1e-4);
+/// assert_eq!(restored[1].x, 1.0);
+/// ```
+pub trait HalfStorage: Copy {
+    /// The counterpart with `f32` components.
+    type F32: Copy;
+
+    /// Converts to `f32` without loss.
+    fn to_f32(self) -> Self::F32;
+
+    /// Converts from `f32` rounding each component to the nearest, ties to even.
+    /// Overflow gives infinity and NaN stays NaN.
+    fn from_f32(v: Self::F32) -> Self;
+}
+
+impl HalfStorage for f16 {
+    type F32 = f32;
+
+    #[inline]
+    fn to_f32(self) -> f32 {
+        f16::to_f32(self)
+    }
+
+    #[inline]
+    fn from_f32(x: f32) -> Self {
+        f16::from_f32(x)
+    }
+}
+
+impl HalfStorage for bf16 {
+    type F32 = f32;
+
+    #[inline]
+    fn to_f32(self) -> f32 {
+        bf16::to_f32(self)
+    }
+
+    #[inline]
+    fn from_f32(x: f32) -> Self {
+        bf16::from_f32(x)
+    }
+}
+
+macro_rules! impl_half_storage {
+    ($V:ident{$($cmp:ident),+}) => {
+        impl<H: HalfStorage<F32 = f32>> HalfStorage for $V<H> {
+            type F32 = $V<f32>;
+
+            #[inline]
+            fn to_f32(self) -> $V<f32> {
+                $V { $($cmp: self.$cmp.to_f32()),+ }
+            }
+
+            #[inline]
+            fn from_f32(v: $V<f32>) -> Self {
+                $V { $($cmp: H::from_f32(v.$cmp)),+ }
+            }
+        }
+
+        impl From<$V<f16>> for $V<f32> {
+            #[inline]
+            fn from(v: $V<f16>) -> Self {
+                v.to_f32()
+            }
+        }
+
+        impl From<$V<bf16>> for $V<f32> {
+            #[inline]
+            fn from(v: $V<bf16>) -> Self {
+                v.to_f32()
+            }
+        }
+    };
+}
+
+impl_half_storage!(Vec1D { x });
+impl_half_storage!(Vec2D { x, y });
+impl_half_storage!(Vec3D { x, y, z });
+
+impl<H: HalfStorage<F32 = f32>, const N: usize> HalfStorage for VecND<H, N> {
+    type F32 = VecND<f32, N>;
+
+    #[inline]
+    fn to_f32(self) -> VecND<f32, N> {
+        VecND::from(<[H; N]>::from(self).map(H::to_f32))
+    }
+
+    #[inline]
+    fn from_f32(v: VecND<f32, N>) -> Self {
+        VecND::from(<[f32; N]>::from(v).map(H::from_f32))
+    }
+}
+
+impl<const N: usize> From<VecND<f16, N>> for VecND<f32, N> {
+    #[inline]
+    fn from(v: VecND<f16, N>) -> Self {
+        v.to_f32()
+    }
+}
+
+impl<const N: usize> From<VecND<bf16, N>> for VecND<f32, N> {
+    #[inline]
+    fn from(v: VecND<bf16, N>) -> Self {
+        v.to_f32()
+    }
+}
+
+/// Converts 16-bit storage to `f32` element by element.
+///
+/// # Panics
+///
+/// Panics if the lengths of the slices differ.
+pub fn convert_to_f32<S: HalfStorage>(src: &[S], dst: &mut [S::F32]) {
+    assert_eq!(src.len(), dst.len(), "the lengths of the slices must match");
+    for (d, s) in dst.iter_mut().zip(src) {
+        *d = s.to_f32();
+    }
+}
+
+/// Converts `f32` to 16-bit storage element by element, rounding to the nearest.
+///
+/// # Panics
+///
+/// Panics if the lengths of the slices differ.
+pub fn convert_from_f32<S: HalfStorage>(src: &[S::F32], dst: &mut [S]) {
+    assert_eq!(src.len(), dst.len(), "the lengths of the slices must match");
+    for (d, &s) in dst.iter_mut().zip(src) {
+        *d = S::from_f32(s);
+    }
+}
+
+/// Converts 16-bit storage to a new vector of `f32`.
+pub fn to_f32_vec<S: HalfStorage>(src: &[S]) -> Vec<S::F32> {
+    src.iter().map(|s| s.to_f32()).collect()
+}
+
+/// Converts `f32` to a new vector of 16-bit storage, rounding to the nearest.
+pub fn from_f32_vec<S: HalfStorage>(src: &[S::F32]) -> Vec<S> {
+    src.iter().map(|&s| S::from_f32(s)).collect()
+}
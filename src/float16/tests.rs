@@ -0,0 +1,77 @@
+#![allow(clippy::op_ref)]
+
+use half::bf16;
+use half::f16;
+
+use crate::*;
+
+#[test]
+fn scalar_conversions() {
+    assert_eq!(f16::from_f32(0.5).to_f32(), 0.5);
+    assert_eq!(<f16 as HalfStorage>::from_f32(1e6), f16::INFINITY);
+    assert_eq!(<bf16 as HalfStorage>::from_f32(1e6).to_f32(), 999424.0);
+    assert!(<f16 as HalfStorage>::from_f32(f32::NAN).is_nan());
+}
+
+#[test]
+fn vector_conversions_round_to_nearest() {
+    let v = Vec3D::new(0.1f32, -3.0, 1000.5);
+    let h = Vec3D::<f16>::from_f32(v);
+    let b = Vec3D::<bf16>::from_f32(v);
+    assert_eq!(
+        h.to_f32(),
+        Vec3D::new(f16::from_f32(0.1).to_f32(), -3.0, 1000.5)
+    );
+    assert_eq!(
+        V3D32::from(b),
+        Vec3D::new(bf16::from_f32(0.1).to_f32(), -3.0, 1000.0)
+    );
+    for (exact, stored) in [(v.x, h.x.to_f32()), (v.z, h.z.to_f32())] {
+        assert!((exact - stored).abs() <= exact.abs() * f16::EPSILON.to_f32() / 2.0);
+    }
+
+    let w = Vec2D::new(1.0f32, 2.0);
+    assert_eq!(V2D32::from(Vec2D::<f16>::from_f32(w)), w);
+    assert_eq!(
+        Vec1D::<bf16>::from_f32(Vec1D::new(4.0)).to_f32(),
+        Vec1D::new(4.0)
+    );
+    let n = VecND::from([1.5f32, -0.25, 8.0, 0.0]);
+    assert_eq!(VecND::<f32, 4>::from(VecND::<f16, 4>::from_f32(n)), n);
+}
+
+#[test]
+fn bulk_conversions() {
+    let points: Vec<V3D32> = (0..100)
+        .map(|i| Vec3D::new(i as f32, -(i as f32) / 8.0, 0.5))
+        .collect();
+    let mut stored = vec![Vec3D::new(f16::ZERO, f16::ZERO, f16::ZERO); points.len()];
+    convert_from_f32(&points, &mut stored);
+    let mut restored = vec![Vec3D::new(0f32, 0f32, 0f32); points.len()];
+    convert_to_f32(&stored, &mut restored);
+    assert_eq!(restored, points);
+    assert_eq!(to_f32_vec(&stored), points);
+    assert_eq!(from_f32_vec::<Vec3D<f16>>(&points), stored);
+
+    let scalars = [0.25f32, 3.0, -7.5];
+    let halves: Vec<bf16> = from_f32_vec(&scalars);
+    assert_eq!(to_f32_vec(&halves), scalars);
+}
+
+#[test]
+#[should_panic]
+fn bulk_conversion_with_different_lengths() {
+    let mut dst = [0f32; 2];
+    convert_to_f32(&[f16::ONE; 3], &mut dst);
+}
+
+#[test]
+fn half_components() {
+    let a = Vec3D::new(f16::from_f32(3.0), f16::from_f32(4.0), f16::ZERO);
+    let b = Vec3D::new(f16::ZERO, f16::ZERO, f16::from_f32(12.0));
+    assert_eq!(a.norm(), f16::from_f32(5.0));
+    assert_eq!(a.distance(b), f16::from_f32(13.0));
+    assert_eq!((&a + &b).dot(a), f16::from_f32(25.0));
+    let c = Vec2D::new(bf16::from_f32(1.0), bf16::from_f32(-2.0)) * bf16::from_f32(0.5);
+    assert_eq!(c.to_f32(), Vec2D::new(0.5, -1.0));
+}
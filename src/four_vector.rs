@@ -0,0 +1,280 @@
+#[cfg(test)]
+mod tests;
+
+use std::iter::Sum;
+use std::ops::Add;
+use std::ops::AddAssign;
+use std::ops::Div;
+use std::ops::DivAssign;
+use std::ops::Mul;
+use std::ops::MulAssign;
+use std::ops::Neg;
+use std::ops::Sub;
+use std::ops::SubAssign;
+
+use num_traits::Float;
+use num_traits::Zero;
+#[cfg(feature = "serde")]
+use serde::Deserialize;
+#[cfg(feature = "serde")]
+use serde::Serialize;
+
+use crate::Component;
+use crate::InnerProductSpace;
+use crate::NormedSpace;
+use crate::RefComponent;
+use crate::Vec3D;
+use crate::Vector;
+use crate::VectorSpace;
+
+/// Four-vector of Minkowski spacetime with the signature `(+, -, -, -)`.
+///
+/// The speed of light is one, so that a four-momentum is `(E, p)` and a
+/// velocity is a fraction `β` of the speed of light.
+///
+/// # Examples
+///
+/// ```
+/// use ::ndvec::*;
+///
+/// let p = FourVector::from_mass_momentum(1f64, Vec3D::new(0.0, 0.0, 0.75));
+/// assert_eq!(p.t, 1.25);
+/// let q = p.boost(Vec3D::new(0.6, 0.0, 0.0));
+/// assert!((q.mass() - 1.0).abs() < 1e-15);
+/// assert!((q.norm_sqr() - p.norm_sqr()).abs() < 1e-15);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct FourVector<T> {
+    /// The time component, or the energy of a four-momentum.
+    pub t: T,
+    /// The spatial part, or the momentum of a four-momentum.
+    pub s: Vec3D<T>,
+}
+
+impl<T> FourVector<T> {
+    #[inline]
+    pub const fn new(t: T, s: Vec3D<T>) -> Self {
+        Self { t, s }
+    }
+}
+
+impl<T: Component> FourVector<T>
+where
+    for<'a> &'a T: RefComponent<T>,
+{
+    /// The four-momentum of a particle of the mass with the momentum.
+    pub fn from_mass_momentum(mass: T, momentum: Vec3D<T>) -> Self {
+        Self::new(mass.hypot(momentum.norm()), momentum)
+    }
+
+    /// The four-velocity `γ(1, β)` of the velocity.
+    pub fn from_velocity(velocity: Vec3D<T>) -> Self {
+        let gamma = lorentz_factor(velocity);
+        Self::new(gamma, velocity * gamma)
+    }
+
+    /// The invariant mass `sqrt(t² - s²)`.
+    ///
+    /// Negative `-sqrt(s² - t²)` if the four-vector is spacelike.
+    pub fn mass(self) -> T {
+        let m2 = self.norm_sqr();
+        if m2 < T::zero() {
+            -(-m2).sqrt()
+        } else {
+            m2.sqrt()
+        }
+    }
+
+    /// Checks if the four-vector is inside of the light cone.
+    pub fn is_timelike(self) -> bool {
+        self.t.abs() > self.s.norm()
+    }
+
+    /// Checks if the four-vector is outside of the light cone.
+    pub fn is_spacelike(self) -> bool {
+        self.t.abs() < self.s.norm()
+    }
+
+    /// The velocity `β = s / t`, e.g. of a particle of the four-momentum.
+    pub fn velocity(self) -> Vec3D<T> {
+        self.s / self.t
+    }
+
+    /// The Lorentz factor `γ = t / sqrt(t² - s²)`.
+    pub fn gamma(self) -> T {
+        self.t / self.mass()
+    }
+
+    /// The rapidity `atanh(|s| / t)` in the direction of the spatial part.
+    pub fn rapidity(self) -> T {
+        (self.s.norm() / self.t).atanh()
+    }
+
+    /// The rapidity `atanh(s_z / t)` along the z-axis, e.g. of the beam.
+    pub fn rapidity_z(self) -> T {
+        (self.s.z / self.t).atanh()
+    }
+
+    /// Lorentz boost by the velocity: a four-vector at rest is transformed
+    /// into one moving with the velocity.
+    ///
+    /// Components are NaN if the speed is not less than one.
+    pub fn boost(self, velocity: Vec3D<T>) -> Self {
+        let b2 = velocity.norm_sqr();
+        if b2.is_zero() {
+            return self;
+        }
+        let gamma = (T::one() - b2).sqrt().recip();
+        let bs = velocity.dot(self.s);
+        let k = (gamma - T::one()) / b2 * bs + gamma * self.t;
+        Self::new(gamma * (self.t + bs), self.s + velocity * k)
+    }
+
+    /// Lorentz boost by the rapidity along the direction, which need not be
+    /// normalized. Accurate even for large rapidities.
+    pub fn boost_rapidity(self, direction: Vec3D<T>, rapidity: T) -> Self {
+        let n = direction.normalize();
+        let (sh, ch) = (rapidity.sinh(), rapidity.cosh());
+        let ns = n.dot(self.s);
+        let k = (ch - T::one()) * ns + sh * self.t;
+        Self::new(ch * self.t + sh * ns, self.s + n * k)
+    }
+}
+
+/// The Lorentz factor `γ = 1 / sqrt(1 - β²)` of the velocity.
+pub fn lorentz_factor<T: Component>(velocity: Vec3D<T>) -> T
+where
+    for<'a> &'a T: RefComponent<T>,
+{
+    (T::one() - velocity.norm_sqr()).sqrt().recip()
+}
+
+/// Relativistic addition of velocities: the velocity of an object moving
+/// with `v` in a frame which moves with `u`.
+///
+/// Not commutative unless the velocities are collinear; the results differ
+/// by the Thomas-Wigner rotation.
+pub fn add_velocities<T: Component>(u: Vec3D<T>, v: Vec3D<T>) -> Vec3D<T>
+where
+    for<'a> &'a T: RefComponent<T>,
+{
+    let gamma = lorentz_factor(u);
+    let uv = u.dot(v);
+    let w = u * (T::one() + gamma / (T::one() + gamma) * uv) + v / gamma;
+    w / (T::one() + uv)
+}
+
+impl<T: Component> VectorSpace for FourVector<T>
+where
+    for<'a> &'a T: RefComponent<T>,
+{
+    type Scalar = T;
+}
+
+impl<T: Component> InnerProductSpace for FourVector<T>
+where
+    for<'a> &'a T: RefComponent<T>,
+{
+    /// The Minkowski inner product `t t' - s·s'`.
+    #[inline]
+    fn dot(self, rhs: Self) -> T {
+        self.t * rhs.t - self.s.dot(rhs.s)
+    }
+}
+
+impl<T: Zero> Zero for FourVector<T> {
+    #[inline]
+    fn zero() -> Self {
+        Self::new(T::zero(), Vec3D::zero())
+    }
+
+    #[inline]
+    fn is_zero(&self) -> bool {
+        self.t.is_zero() && self.s.is_zero()
+    }
+}
+
+impl<T: Neg<Output = T>> Neg for FourVector<T> {
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self {
+        Self::new(-self.t, -self.s)
+    }
+}
+
+impl<T: Add<Output = T>> Add for FourVector<T> {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, rhs: Self) -> Self {
+        Self::new(self.t + rhs.t, self.s + rhs.s)
+    }
+}
+
+impl<T: Sub<Output = T>> Sub for FourVector<T> {
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, rhs: Self) -> Self {
+        Self::new(self.t - rhs.t, self.s - rhs.s)
+    }
+}
+
+impl<T: Mul<Output = T> + Copy> Mul<T> for FourVector<T> {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: T) -> Self {
+        Self::new(self.t * rhs, self.s * rhs)
+    }
+}
+
+impl<T: Div<Output = T> + Copy> Div<T> for FourVector<T> {
+    type Output = Self;
+
+    #[inline]
+    fn div(self, rhs: T) -> Self {
+        Self::new(self.t / rhs, self.s / rhs)
+    }
+}
+
+impl<T: AddAssign> AddAssign for FourVector<T> {
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) {
+        self.t += rhs.t;
+        self.s += rhs.s;
+    }
+}
+
+impl<T: SubAssign> SubAssign for FourVector<T> {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Self) {
+        self.t -= rhs.t;
+        self.s -= rhs.s;
+    }
+}
+
+impl<T: MulAssign + Copy> MulAssign<T> for FourVector<T> {
+    #[inline]
+    fn mul_assign(&mut self, rhs: T) {
+        self.t *= rhs;
+        self.s *= rhs;
+    }
+}
+
+impl<T: DivAssign + Copy> DivAssign<T> for FourVector<T> {
+    #[inline]
+    fn div_assign(&mut self, rhs: T) {
+        self.t /= rhs;
+        self.s /= rhs;
+    }
+}
+
+impl<T: Float> Sum for FourVector<T> {
+    #[inline]
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::zero(), |a, b| a + b)
+    }
+}
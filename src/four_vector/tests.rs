@@ -0,0 +1,127 @@
+use crate::testing::Lcg;
+use crate::*;
+
+impl Lcg {
+    /// A velocity whose speed is less than `0.87`.
+    fn velocity(&mut self) -> V3D64 {
+        self.vec3()
+    }
+
+    fn four(&mut self) -> FourVector<f64> {
+        FourVector::new(4.0 * self.next(), self.vec3() * 4.0)
+    }
+}
+
+fn assert_close(a: FourVector<f64>, b: FourVector<f64>, eps: f64) {
+    assert!(
+        (a.t - b.t).abs() <= eps && a.s.distance(b.s) <= eps,
+        "{:?} {:?}",
+        a,
+        b
+    );
+}
+
+#[test]
+fn minkowski_inner_product() {
+    let a = FourVector::new(5.0, Vec3D::new(1.0, 2.0, 3.0));
+    let b = FourVector::new(2.0, Vec3D::new(-1.0, 0.0, 1.0));
+    assert_eq!(a.dot(b), 8.0);
+    assert_eq!(a.norm_sqr(), 11.0);
+    assert!(a.is_timelike() && !a.is_spacelike());
+    assert!(b.is_timelike());
+    let c = FourVector::new(1.0, Vec3D::new(1.0, 1.0, 0.0));
+    assert!(c.is_spacelike());
+    assert_eq!(c.mass(), -1.0);
+    let light = FourVector::new(1.0, Vec3D::new(0.0, 0.6, 0.8));
+    assert!(!light.is_timelike() && !light.is_spacelike());
+    assert_eq!(light.mass(), 0.0);
+    assert_eq!(a + b - b, a);
+    assert_eq!(
+        -a * 2.0 / 4.0,
+        FourVector::new(-2.5, Vec3D::new(-0.5, -1.0, -1.5))
+    );
+    let total: FourVector<f64> = [a, b, c].into_iter().sum();
+    assert_eq!(total, FourVector::new(8.0, Vec3D::new(1.0, 3.0, 4.0)));
+}
+
+#[test]
+fn kinematics() {
+    let p = FourVector::from_mass_momentum(3.0, Vec3D::new(0.0, 4.0, 0.0));
+    assert_eq!(p, FourVector::new(5.0, Vec3D::new(0.0, 4.0, 0.0)));
+    assert_eq!(p.mass(), 3.0);
+    assert_eq!(p.velocity(), Vec3D::new(0.0, 0.8, 0.0));
+    assert!((p.gamma() - 5.0 / 3.0).abs() < 1e-15);
+    assert!((p.rapidity() - 3f64.ln()).abs() < 1e-15);
+    assert_eq!(p.rapidity_z(), 0.0);
+
+    let u = FourVector::from_velocity(Vec3D::new(0.6, 0.0, 0.0));
+    assert_eq!(u, FourVector::new(1.25, Vec3D::new(0.75, 0.0, 0.0)));
+    assert!((u.norm_sqr() - 1.0).abs() < 1e-15);
+    assert_eq!(lorentz_factor(Vec3D::new(0.0, 0.0, 0.6)), 1.25);
+}
+
+#[test]
+fn interval_is_invariant() {
+    let mut rng = Lcg(7);
+    for _ in 0..1000 {
+        let (a, b) = (rng.four(), rng.four());
+        let beta = rng.velocity();
+        let (a2, b2) = (a.boost(beta), b.boost(beta));
+        assert!((a2.dot(b2) - a.dot(b)).abs() < 1e-12);
+        assert!(((a2 - b2).norm_sqr() - (a - b).norm_sqr()).abs() < 1e-12);
+        // the inverse boost restores the four-vector
+        assert_close(a2.boost(-beta), a, 1e-12);
+
+        let eta = 3.0 * rng.next();
+        let n = rng.vec3();
+        let a3 = a.boost_rapidity(n, eta);
+        assert!((a3.norm_sqr() - a.norm_sqr()).abs() < 1e-11);
+        let beta = n.normalize() * eta.tanh();
+        assert_close(a3, a.boost(beta), 1e-12);
+    }
+}
+
+#[test]
+fn boosts_of_rest_frame() {
+    let rest = FourVector::new(2.0, Vec3D::zero());
+    let beta = Vec3D::new(0.0, 0.0, 0.8);
+    let p = rest.boost(beta);
+    assert_close(
+        p,
+        FourVector::new(2.0 / 0.6, Vec3D::new(0.0, 0.0, 1.6 / 0.6)),
+        1e-15,
+    );
+    assert!(p.velocity().distance(beta) < 1e-15);
+    assert_eq!(rest.boost(Vec3D::zero()), rest);
+    assert!(rest.boost(Vec3D::new(1.0, 0.0, 0.0)).t.is_infinite());
+
+    // collinear rapidities are additive
+    let z = Vec3D::new(0.0, 0.0, 2.0);
+    let q = rest.boost_rapidity(z, 0.5).boost_rapidity(z, 1.5);
+    assert!((q.rapidity_z() - 2.0).abs() < 1e-14);
+    assert!((q.mass() - 2.0).abs() < 1e-14);
+    let far = rest.boost_rapidity(z, 15.0);
+    assert!((far.t / (2.0 * 15f64.cosh()) - 1.0).abs() < 1e-15);
+    assert!((far.s.z / (2.0 * 15f64.sinh()) - 1.0).abs() < 1e-15);
+    assert!((rest.boost_rapidity(z, 5.0).rapidity() - 5.0).abs() < 1e-11);
+}
+
+#[test]
+fn velocity_addition() {
+    let u = Vec3D::new(0.5, 0.0, 0.0);
+    let w = add_velocities(u, u);
+    assert!(w.distance(Vec3D::new(0.8, 0.0, 0.0)) < 1e-15);
+    assert!(add_velocities(u, Vec3D::new(-0.5, 0.0, 0.0)).norm() < 1e-15);
+    let c = add_velocities(Vec3D::new(0.9, 0.0, 0.0), Vec3D::new(0.0, 0.0, 0.0));
+    assert!(c.distance(Vec3D::new(0.9, 0.0, 0.0)) < 1e-15);
+
+    let mut rng = Lcg(11);
+    for _ in 0..1000 {
+        let (u, v) = (rng.velocity(), rng.velocity());
+        let w = add_velocities(u, v);
+        assert!(w.norm() < 1.0);
+        // the same as boosting the four-velocity
+        let expected = FourVector::from_velocity(v).boost(u).velocity();
+        assert!(w.distance(expected) < 1e-14);
+    }
+}
@@ -7,6 +7,8 @@ use std::ops::Add;
 use std::ops::AddAssign;
 use std::ops::Div;
 use std::ops::DivAssign;
+use std::ops::Index;
+use std::ops::IndexMut;
 use std::ops::Mul;
 use std::ops::MulAssign;
 use std::ops::Neg;
@@ -22,7 +24,10 @@ use serde::Serialize;
 
 use crate::array::arr_zip_map;
 use crate::Component;
+use crate::InnerProductSpace;
+use crate::NormedSpace;
 use crate::Vector;
+use crate::VectorSpace;
 
 /// General-fixed-size low-dimensional vector.
 ///
@@ -38,18 +43,31 @@ pub struct VecND<T, const N: usize> {
     arr: [T; N],
 }
 
-impl<T: Component, const N: usize> Vector for VecND<T, N> {
-    type Cmp = T;
+impl<T: Component, const N: usize> VectorSpace for VecND<T, N> {
+    type Scalar = T;
+}
 
-    const DIM: usize = N;
+impl<T: Component, const N: usize> InnerProductSpace for VecND<T, N> {
+    fn dot(self, rhs: Self) -> Self::Scalar {
+        self.arr.into_iter().zip(rhs.arr).map(|(a, b)| a * b).sum()
+    }
 
-    fn norm_sqr(self) -> Self::Cmp {
+    fn norm_sqr(self) -> Self::Scalar {
         self.arr.into_iter().map(|c| c * c).sum::<T>()
     }
+}
 
-    fn dot(self, rhs: Self) -> Self::Cmp {
-        self.arr.into_iter().zip(rhs.arr).map(|(a, b)| a * b).sum()
+impl<T: Component, const N: usize> NormedSpace for VecND<T, N> {
+    #[inline]
+    fn norm(self) -> Self::Scalar {
+        self.norm_sqr().sqrt()
     }
+}
+
+impl<T: Component, const N: usize> Vector for VecND<T, N> {
+    type Cmp = T;
+
+    const DIM: usize = N;
 
     fn is_finite(self) -> bool {
         self.arr.into_iter().all(|c| c.is_finite())
@@ -239,3 +257,19 @@ impl<T, const N: usize> AsMut<[T; N]> for VecND<T, N> {
         &mut self.arr
     }
 }
+
+impl<T, const N: usize> Index<usize> for VecND<T, N> {
+    type Output = T;
+
+    #[inline]
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.arr[index]
+    }
+}
+
+impl<T, const N: usize> IndexMut<usize> for VecND<T, N> {
+    #[inline]
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        &mut self.arr[index]
+    }
+}
@@ -1,3 +1,5 @@
+#![allow(clippy::op_ref)]
+
 use crate::*;
 
 #[test]
@@ -118,6 +120,43 @@ fn distance_sqr() {
     );
 }
 
+#[test]
+fn norm_stable() {
+    let big = 2f64.powi(700);
+    let v = VecND::from([1f64, -2f64, 2f64, 4f64]) * big;
+    assert!(v.norm().is_infinite());
+    assert_eq!(v.norm_stable(), 5f64 * big);
+    let small = 2f64.powi(-700);
+    let v = VecND::from([1f64, -2f64, 2f64, 4f64]) * small;
+    assert_eq!(v.norm(), 0f64);
+    assert_eq!(v.norm_stable(), 5f64 * small);
+    assert_eq!(VecND::from([0f32; 5]).norm_stable(), 0f32);
+    assert_eq!(VecND::from([f32::MAX, 0f32]).norm_stable(), f32::MAX);
+    assert!(VecND::from([f32::NAN, 1f32]).norm_stable().is_nan());
+    assert_eq!(
+        VecND::from([1f64, f64::NAN, f64::INFINITY]).norm_stable(),
+        f64::INFINITY
+    );
+}
+
+#[test]
+fn distance_stable_and_normalize() {
+    let a = VecND::from([f64::MAX, 0f64, 0f64, 1f64]);
+    let b = VecND::from([0f64, -f64::MAX, 0f64, 1f64]);
+    assert!(a.distance(b).is_infinite());
+    assert_eq!(a.distance_stable(b), f64::INFINITY);
+    let a = VecND::from([3e307f64, 0f64, 0f64]);
+    let b = VecND::from([-3e307f64, 0f64, -8e307f64]);
+    assert!(a.distance(b).is_infinite());
+    assert_eq!(a.distance_stable(b), 1e308f64);
+    let n = VecND::from([1e-310f64, 2e-310, 2e-310]).normalize();
+    assert!((n.norm() - 1f64).abs() <= f64::EPSILON);
+    assert_eq!(
+        VecND::from([0f32, -7e-45f32]).normalize(),
+        VecND::from([0f32, -1f32])
+    );
+}
+
 #[test]
 fn zero() {
     assert_eq!(VecND::zero(), VecND::from([0f32]));
@@ -536,3 +575,11 @@ fn from_into_arr() {
     let x: [_; 3] = VecND::from([5f64, -2f64, 7f64]).into();
     assert_eq!(x, [5f64, -2f64, 7f64]);
 }
+
+#[test]
+fn index() {
+    let mut v = VecND::from([1f64, 2f64, 3f64, 4f64]);
+    assert_eq!(v[3], 4f64);
+    v[2] = -1f64;
+    assert_eq!(v, VecND::from([1f64, 2f64, -1f64, 4f64]));
+}
@@ -0,0 +1,677 @@
+#[cfg(test)]
+mod tests;
+
+use std::ops::Add;
+use std::ops::AddAssign;
+use std::ops::Div;
+use std::ops::Mul;
+use std::ops::MulAssign;
+use std::ops::Neg;
+use std::ops::Sub;
+use std::ops::SubAssign;
+
+use num_traits::Float;
+use num_traits::Zero;
+#[cfg(feature = "serde")]
+use serde::Deserialize;
+#[cfg(feature = "serde")]
+use serde::Serialize;
+
+use crate::Component;
+use crate::InnerProductSpace;
+use crate::NormedSpace;
+use crate::Rad;
+use crate::RefComponent;
+use crate::Vec2D;
+use crate::Vec3D;
+use crate::Vector;
+
+/// Multivector of the geometric algebra G(2) of the plane.
+///
+/// `s + v.x e1 + v.y e2 + b e12` where `e12² = -1`.
+///
+/// # Examples
+///
+/// ```
+/// use ::ndvec::*;
+///
+/// let e1 = Multivector2::vector(Vec2D::new(1.0, 0.0));
+/// let e2 = Multivector2::vector(Vec2D::new(0.0, 1.0));
+/// let e12 = e1 * e2;
+/// assert_eq!(e12, Multivector2::bivector(1.0));
+/// assert_eq!(e12 * e12, Multivector2::scalar(-1.0));
+/// assert_eq!(e1.wedge(e1), Multivector2::scalar(0.0));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Multivector2<T> {
+    /// The scalar part.
+    pub s: T,
+    /// The vector part.
+    pub v: Vec2D<T>,
+    /// The bivector part, the coefficient of `e12`.
+    pub b: T,
+}
+
+/// Multivector of the geometric algebra G(3) of the space.
+///
+/// `s + v + B + t e123` where the bivector `B = e123 b` is stored as the
+/// vector `b`, i.e. `b.x e23 + b.y e31 + b.z e12`, so that the bivector of
+/// the plane orthogonal to a vector has the components of the vector.
+/// The pseudoscalar `e123` commutes with everything and squares to `-1`.
+///
+/// # Examples
+///
+/// ```
+/// use ::ndvec::*;
+///
+/// let a = Multivector3::vector(Vec3D::new(1.0, 0.0, 0.0));
+/// let b = Multivector3::vector(Vec3D::new(1.0, 1.0, 0.0));
+/// // the product of vectors is the dot product plus the wedge product
+/// let zero = Vec3D::new(0.0, 0.0, 0.0);
+/// assert_eq!(a * b, Multivector3::new(1.0, zero, Vec3D::new(0.0, 0.0, 1.0), 0.0));
+/// // reflection in the plane orthogonal to `a`
+/// assert_eq!(a.transform(Vec3D::new(2.0, 3.0, 4.0)), Vec3D::new(-2.0, 3.0, 4.0));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Multivector3<T> {
+    /// The scalar part.
+    pub s: T,
+    /// The vector part.
+    pub v: Vec3D<T>,
+    /// The bivector part as the coefficients of `e23`, `e31` and `e12`.
+    pub b: Vec3D<T>,
+    /// The trivector part, the coefficient of `e123`.
+    pub t: T,
+}
+
+/// Rotor of G(3): an even multivector `s + B` of unit norm which rotates
+/// vectors by the sandwich product `R v R̃`.
+///
+/// The bivector is stored as a vector like [`Multivector3`].
+///
+/// # Examples
+///
+/// ```
+/// use ::ndvec::*;
+///
+/// let r = Rotor3::from_vectors(Vec3D::new(1.0, 0.0, 0.0), Vec3D::new(0.0, 1.0, 0.0));
+/// let v = r.rotate(Vec3D::new(1.0, 0.0, 1.0));
+/// assert!(v.distance(Vec3D::new(0.0, 1.0, 1.0)) < 1e-15);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Rotor3<T> {
+    /// The scalar part.
+    pub s: T,
+    /// The bivector part as the coefficients of `e23`, `e31` and `e12`.
+    pub b: Vec3D<T>,
+}
+
+/// The z-component of the cross product.
+#[inline]
+fn cross2<T: Float>(a: Vec2D<T>, b: Vec2D<T>) -> T {
+    a.x * b.y - a.y * b.x
+}
+
+impl<T> Multivector2<T> {
+    #[inline]
+    pub const fn new(s: T, v: Vec2D<T>, b: T) -> Self {
+        Self { s, v, b }
+    }
+}
+
+impl<T: Component> Multivector2<T>
+where
+    for<'a> &'a T: RefComponent<T>,
+{
+    /// The scalar.
+    pub fn scalar(s: T) -> Self {
+        Self::new(s, Vec2D::zero(), T::zero())
+    }
+
+    /// The vector.
+    pub fn vector(v: Vec2D<T>) -> Self {
+        Self::new(T::zero(), v, T::zero())
+    }
+
+    /// The bivector `b e12`.
+    pub fn bivector(b: T) -> Self {
+        Self::new(T::zero(), Vec2D::zero(), b)
+    }
+
+    /// The rotor `exp(-e12 θ/2)` which rotates vectors counterclockwise by the angle.
+    pub fn rotor(angle: Rad<T>) -> Self {
+        let (sin, cos) = (angle / (T::one() + T::one())).sin_cos();
+        Self::new(cos, Vec2D::zero(), -sin)
+    }
+
+    /// The part of the grade, e.g. the vector part for `1`.
+    /// Zero if the grade is greater than 2.
+    pub fn grade(self, k: usize) -> Self {
+        match k {
+            0 => Self::scalar(self.s),
+            1 => Self::vector(self.v),
+            2 => Self::bivector(self.b),
+            _ => Self::zero(),
+        }
+    }
+
+    /// The reverse, which negates the bivector part.
+    pub fn reverse(self) -> Self {
+        Self::new(self.s, self.v, -self.b)
+    }
+
+    /// The grade involution, which negates the vector part.
+    pub fn involute(self) -> Self {
+        Self::new(self.s, -self.v, self.b)
+    }
+
+    /// The wedge (outer) product.
+    pub fn wedge(self, rhs: Self) -> Self {
+        Self::new(
+            self.s * rhs.s,
+            rhs.v * self.s + self.v * rhs.s,
+            self.s * rhs.b + self.b * rhs.s + cross2(self.v, rhs.v),
+        )
+    }
+
+    /// The inner product as the left contraction `A ⌋ B`.
+    pub fn inner(self, rhs: Self) -> Self {
+        Self::new(
+            self.s * rhs.s + self.v.dot(rhs.v) - self.b * rhs.b,
+            rhs.v * self.s + self.v.perp() * rhs.b,
+            self.s * rhs.b,
+        )
+    }
+
+    /// The square of the norm, the scalar part of `A Ã`.
+    pub fn norm_sqr(self) -> T {
+        self.s * self.s + self.v.norm_sqr() + self.b * self.b
+    }
+
+    /// The norm.
+    pub fn norm(self) -> T {
+        self.norm_sqr().sqrt()
+    }
+
+    /// The inverse `Ã / (A Ã)` of a versor, i.e. a product of vectors.
+    pub fn inverse(self) -> Self {
+        self.reverse() / self.norm_sqr()
+    }
+
+    /// The sandwich product `A X Ã`.
+    pub fn sandwich(self, x: Self) -> Self {
+        self * x * self.reverse()
+    }
+
+    /// The action `Â v A⁻¹` of a versor on a vector: rotation by a rotor,
+    /// or reflection in the line orthogonal to a vector.
+    pub fn transform(self, v: Vec2D<T>) -> Vec2D<T> {
+        (self.involute() * Self::vector(v) * self.inverse()).v
+    }
+}
+
+impl<T> Multivector3<T> {
+    #[inline]
+    pub const fn new(s: T, v: Vec3D<T>, b: Vec3D<T>, t: T) -> Self {
+        Self { s, v, b, t }
+    }
+}
+
+impl<T: Component> Multivector3<T>
+where
+    for<'a> &'a T: RefComponent<T>,
+{
+    /// The scalar.
+    pub fn scalar(s: T) -> Self {
+        Self::new(s, Vec3D::zero(), Vec3D::zero(), T::zero())
+    }
+
+    /// The vector.
+    pub fn vector(v: Vec3D<T>) -> Self {
+        Self::new(T::zero(), v, Vec3D::zero(), T::zero())
+    }
+
+    /// The bivector `e123 b = b.x e23 + b.y e31 + b.z e12`.
+    pub fn bivector(b: Vec3D<T>) -> Self {
+        Self::new(T::zero(), Vec3D::zero(), b, T::zero())
+    }
+
+    /// The trivector `t e123`.
+    pub fn trivector(t: T) -> Self {
+        Self::new(T::zero(), Vec3D::zero(), Vec3D::zero(), t)
+    }
+
+    /// The part of the grade, e.g. the bivector part for `2`.
+    /// Zero if the grade is greater than 3.
+    pub fn grade(self, k: usize) -> Self {
+        match k {
+            0 => Self::scalar(self.s),
+            1 => Self::vector(self.v),
+            2 => Self::bivector(self.b),
+            3 => Self::trivector(self.t),
+            _ => Self::zero(),
+        }
+    }
+
+    /// The reverse, which negates the bivector and trivector parts.
+    pub fn reverse(self) -> Self {
+        Self::new(self.s, self.v, -self.b, -self.t)
+    }
+
+    /// The grade involution, which negates the vector and trivector parts.
+    pub fn involute(self) -> Self {
+        Self::new(self.s, -self.v, self.b, -self.t)
+    }
+
+    /// The dual `A e123⁻¹`.
+    pub fn dual(self) -> Self {
+        // e123⁻¹ = -e123, and e123 maps a vector to the bivector of its components
+        Self::new(self.t, self.b, -self.v, -self.s)
+    }
+
+    /// The wedge (outer) product.
+    pub fn wedge(self, rhs: Self) -> Self {
+        let (a, b) = (self, rhs);
+        Self::new(
+            a.s * b.s,
+            b.v * a.s + a.v * b.s,
+            b.b * a.s + a.b * b.s + a.v.cross(b.v),
+            a.s * b.t + a.t * b.s + a.v.dot(b.b) + a.b.dot(b.v),
+        )
+    }
+
+    /// The inner product as the left contraction `A ⌋ B`.
+    pub fn inner(self, rhs: Self) -> Self {
+        let (a, b) = (self, rhs);
+        Self::new(
+            a.s * b.s + a.v.dot(b.v) - a.b.dot(b.b) - a.t * b.t,
+            b.v * a.s - a.v.cross(b.b) - a.b * b.t,
+            b.b * a.s + a.v * b.t,
+            a.s * b.t,
+        )
+    }
+
+    /// The square of the norm, the scalar part of `A Ã`.
+    pub fn norm_sqr(self) -> T {
+        self.s * self.s + self.v.norm_sqr() + self.b.norm_sqr() + self.t * self.t
+    }
+
+    /// The norm.
+    pub fn norm(self) -> T {
+        self.norm_sqr().sqrt()
+    }
+
+    /// The inverse `Ã / (A Ã)` of a versor, i.e. a product of vectors.
+    pub fn inverse(self) -> Self {
+        self.reverse() / self.norm_sqr()
+    }
+
+    /// The sandwich product `A X Ã`.
+    pub fn sandwich(self, x: Self) -> Self {
+        self * x * self.reverse()
+    }
+
+    /// The action `Â v A⁻¹` of a versor on a vector: rotation by a rotor,
+    /// or reflection in the plane orthogonal to a vector.
+    pub fn transform(self, v: Vec3D<T>) -> Vec3D<T> {
+        (self.involute() * Self::vector(v) * self.inverse()).v
+    }
+}
+
+impl<T: Component> Rotor3<T>
+where
+    for<'a> &'a T: RefComponent<T>,
+{
+    #[inline]
+    pub const fn new(s: T, b: Vec3D<T>) -> Self {
+        Self { s, b }
+    }
+
+    /// The rotor of no rotation.
+    pub fn identity() -> Self {
+        Self::new(T::one(), Vec3D::zero())
+    }
+
+    /// The rotor of the counterclockwise rotation by the angle around the axis,
+    /// which need not be normalized.
+    pub fn from_axis_angle(axis: Vec3D<T>, angle: Rad<T>) -> Self {
+        let (sin, cos) = (angle / (T::one() + T::one())).sin_cos();
+        Self::new(cos, -axis.normalize() * sin)
+    }
+
+    /// The rotor of the smallest rotation which takes the direction of `from`
+    /// to the direction of `to`, i.e. `(1 + t f) / |1 + t f|` of the unit vectors.
+    ///
+    /// If the vectors are opposite, the rotation is by a half turn around
+    /// an arbitrary axis orthogonal to them.
+    pub fn from_vectors(from: Vec3D<T>, to: Vec3D<T>) -> Self {
+        let f = from.normalize();
+        let t = to.normalize();
+        let s = T::one() + f.dot(t);
+        // the bivector of t f = t·f + t∧f is the dual of t × f
+        let b = t.cross(f);
+        if s <= T::epsilon() {
+            // any axis orthogonal to `from`
+            let other = if f.x.abs() < f.y.abs() {
+                Vec3D::new(T::one(), T::zero(), T::zero())
+            } else {
+                Vec3D::new(T::zero(), T::one(), T::zero())
+            };
+            return Self::new(T::zero(), f.cross(other).normalize());
+        }
+        let r = Self::new(s, b);
+        r / r.norm()
+    }
+
+    /// The rotor of the rotation matrix given as columns, by Shepperd's
+    /// method from the largest of the diagonal combinations.
+    ///
+    /// The matrix is expected to be orthogonal with the determinant one.
+    pub fn from_matrix(m: [Vec3D<T>; 3]) -> Self {
+        let [c0, c1, c2] = m;
+        let (one, quarter) = (T::one(), (T::one() + T::one()).powi(-2));
+        let trace = c0.x + c1.y + c2.z;
+        // the quaternion (w, x, y, z) is the rotor (w, -(x, y, z))
+        let (w, x, y, z) = if trace > T::zero() {
+            let s = (trace + one).sqrt() * (one + one);
+            (
+                s * quarter,
+                (c1.z - c2.y) / s,
+                (c2.x - c0.z) / s,
+                (c0.y - c1.x) / s,
+            )
+        } else if c0.x > c1.y && c0.x > c2.z {
+            let s = (one + c0.x - c1.y - c2.z).sqrt() * (one + one);
+            (
+                (c1.z - c2.y) / s,
+                s * quarter,
+                (c1.x + c0.y) / s,
+                (c2.x + c0.z) / s,
+            )
+        } else if c1.y > c2.z {
+            let s = (one + c1.y - c0.x - c2.z).sqrt() * (one + one);
+            (
+                (c2.x - c0.z) / s,
+                (c1.x + c0.y) / s,
+                s * quarter,
+                (c2.y + c1.z) / s,
+            )
+        } else {
+            let s = (one + c2.z - c0.x - c1.y).sqrt() * (one + one);
+            (
+                (c0.y - c1.x) / s,
+                (c2.x + c0.z) / s,
+                (c2.y + c1.z) / s,
+                s * quarter,
+            )
+        };
+        Self::new(w, -Vec3D::new(x, y, z))
+    }
+
+    /// The rotor `b a` of the rotation by twice the angle from `a` to `b`,
+    /// the composition of the reflections in the planes orthogonal to them.
+    pub fn from_reflections(a: Vec3D<T>, b: Vec3D<T>) -> Self {
+        let r = Self::new(b.dot(a), b.cross(a));
+        r / r.norm()
+    }
+
+    /// The reverse, i.e. the inverse rotation.
+    pub fn reverse(self) -> Self {
+        Self::new(self.s, -self.b)
+    }
+
+    /// The norm, which is one for rotors.
+    pub fn norm(self) -> T {
+        self.s.hypot(self.b.norm())
+    }
+
+    /// The rotor scaled to the unit norm to remove accumulated rounding.
+    pub fn normalize(self) -> Self {
+        self / self.norm()
+    }
+
+    /// The angle of the rotation in `[0, 2π]`.
+    pub fn angle(self) -> Rad<T> {
+        let two = T::one() + T::one();
+        Rad::atan2(self.b.norm(), self.s) * two
+    }
+
+    /// The unit axis of the counterclockwise rotation by [`angle`](Rotor3::angle).
+    ///
+    /// Components are NaN if there is no rotation.
+    pub fn axis(self) -> Vec3D<T> {
+        -self.b.normalize()
+    }
+
+    /// Rotates the vector by the sandwich product `R v R̃`.
+    pub fn rotate(self, v: Vec3D<T>) -> Vec3D<T> {
+        // the same as the quaternion (s, -b)
+        let u = -self.b;
+        let two = T::one() + T::one();
+        let c = u.cross(v) * two;
+        v + c * self.s + u.cross(c)
+    }
+
+    /// Spherical linear interpolation along the shorter arc, the rotation
+    /// at the fraction `t` of the way from `self` to `other`.
+    pub fn slerp(self, other: Self, t: T) -> Self {
+        let mut d = self.s * other.s + self.b.dot(other.b);
+        // `R` and `-R` are the same rotation
+        let other = if d < T::zero() {
+            d = -d;
+            -other
+        } else {
+            other
+        };
+        if d > T::one() - T::epsilon() {
+            return (self + (other - self) * t).normalize();
+        }
+        let theta = d.acos();
+        let sin = theta.sin();
+        self * (((T::one() - t) * theta).sin() / sin) + other * ((t * theta).sin() / sin)
+    }
+}
+
+impl<T: Component> From<Vec2D<T>> for Multivector2<T>
+where
+    for<'a> &'a T: RefComponent<T>,
+{
+    #[inline]
+    fn from(v: Vec2D<T>) -> Self {
+        Self::vector(v)
+    }
+}
+
+impl<T: Component> From<Vec3D<T>> for Multivector3<T>
+where
+    for<'a> &'a T: RefComponent<T>,
+{
+    #[inline]
+    fn from(v: Vec3D<T>) -> Self {
+        Self::vector(v)
+    }
+}
+
+impl<T: Zero> From<Rotor3<T>> for Multivector3<T> {
+    #[inline]
+    fn from(r: Rotor3<T>) -> Self {
+        Self::new(r.s, Vec3D::zero(), r.b, T::zero())
+    }
+}
+
+impl<T: Zero> Zero for Multivector2<T> {
+    #[inline]
+    fn zero() -> Self {
+        Self::new(T::zero(), Vec2D::zero(), T::zero())
+    }
+
+    #[inline]
+    fn is_zero(&self) -> bool {
+        self.s.is_zero() && self.v.is_zero() && self.b.is_zero()
+    }
+}
+
+impl<T: Zero> Zero for Multivector3<T> {
+    #[inline]
+    fn zero() -> Self {
+        Self::new(T::zero(), Vec3D::zero(), Vec3D::zero(), T::zero())
+    }
+
+    #[inline]
+    fn is_zero(&self) -> bool {
+        self.s.is_zero() && self.v.is_zero() && self.b.is_zero() && self.t.is_zero()
+    }
+}
+
+macro_rules! impl_linear_ops {
+    ($M:ident{$($cmp:ident),+}) => {
+        impl<T: Neg<Output = T>> Neg for $M<T> {
+            type Output = Self;
+
+            #[inline]
+            fn neg(self) -> Self {
+                Self { $($cmp: -self.$cmp),+ }
+            }
+        }
+
+        impl<T: Add<Output = T>> Add for $M<T> {
+            type Output = Self;
+
+            #[inline]
+            fn add(self, rhs: Self) -> Self {
+                Self { $($cmp: self.$cmp + rhs.$cmp),+ }
+            }
+        }
+
+        impl<T: Sub<Output = T>> Sub for $M<T> {
+            type Output = Self;
+
+            #[inline]
+            fn sub(self, rhs: Self) -> Self {
+                Self { $($cmp: self.$cmp - rhs.$cmp),+ }
+            }
+        }
+
+        impl<T: Mul<Output = T> + Copy> Mul<T> for $M<T> {
+            type Output = Self;
+
+            #[inline]
+            fn mul(self, rhs: T) -> Self {
+                Self { $($cmp: self.$cmp * rhs),+ }
+            }
+        }
+
+        impl<T: Div<Output = T> + Copy> Div<T> for $M<T> {
+            type Output = Self;
+
+            #[inline]
+            fn div(self, rhs: T) -> Self {
+                Self { $($cmp: self.$cmp / rhs),+ }
+            }
+        }
+
+        impl<T: AddAssign> AddAssign for $M<T> {
+            #[inline]
+            fn add_assign(&mut self, rhs: Self) {
+                $(self.$cmp += rhs.$cmp;)+
+            }
+        }
+
+        impl<T: SubAssign> SubAssign for $M<T> {
+            #[inline]
+            fn sub_assign(&mut self, rhs: Self) {
+                $(self.$cmp -= rhs.$cmp;)+
+            }
+        }
+    };
+}
+
+impl_linear_ops!(Multivector2 { s, v, b });
+impl_linear_ops!(Multivector3 { s, v, b, t });
+impl_linear_ops!(Rotor3 { s, b });
+
+impl<T: Component> Mul for Multivector2<T>
+where
+    for<'a> &'a T: RefComponent<T>,
+{
+    type Output = Self;
+
+    /// The geometric product.
+    fn mul(self, rhs: Self) -> Self {
+        let (a, b) = (self, rhs);
+        Self::new(
+            a.s * b.s + a.v.dot(b.v) - a.b * b.b,
+            b.v * a.s + a.v * b.s + a.v.perp() * b.b - b.v.perp() * a.b,
+            a.s * b.b + a.b * b.s + cross2(a.v, b.v),
+        )
+    }
+}
+
+impl<T: Component> Mul for Multivector3<T>
+where
+    for<'a> &'a T: RefComponent<T>,
+{
+    type Output = Self;
+
+    /// The geometric product.
+    fn mul(self, rhs: Self) -> Self {
+        // with the central pseudoscalar I, a multivector is (s + I t) + (v + I b),
+        // and the product of vectors is u w = u·w + I (u × w)
+        let (a, b) = (self, rhs);
+        Self::new(
+            a.s * b.s - a.t * b.t + a.v.dot(b.v) - a.b.dot(b.b),
+            b.v * a.s - b.b * a.t + a.v * b.s - a.b * b.t - a.v.cross(b.b) - a.b.cross(b.v),
+            b.b * a.s + b.v * a.t + a.b * b.s + a.v * b.t + a.v.cross(b.v) - a.b.cross(b.b),
+            a.s * b.t + a.t * b.s + a.v.dot(b.b) + a.b.dot(b.v),
+        )
+    }
+}
+
+impl<T: Component> Mul for Rotor3<T>
+where
+    for<'a> &'a T: RefComponent<T>,
+{
+    type Output = Self;
+
+    /// The composition: `a * b` rotates by `b` and then by `a`.
+    fn mul(self, rhs: Self) -> Self {
+        let (a, b) = (self, rhs);
+        Self::new(
+            a.s * b.s - a.b.dot(b.b),
+            b.b * a.s + a.b * b.s - a.b.cross(b.b),
+        )
+    }
+}
+
+impl<T: Component> MulAssign for Multivector3<T>
+where
+    for<'a> &'a T: RefComponent<T>,
+{
+    #[inline]
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+impl<T: Component> MulAssign for Multivector2<T>
+where
+    for<'a> &'a T: RefComponent<T>,
+{
+    #[inline]
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+impl<T: Component> MulAssign for Rotor3<T>
+where
+    for<'a> &'a T: RefComponent<T>,
+{
+    #[inline]
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
@@ -0,0 +1,224 @@
+use std::f64::consts::FRAC_PI_2;
+use std::f64::consts::PI;
+
+use crate::testing::Lcg;
+use crate::*;
+
+impl Lcg {
+    fn mv2(&mut self) -> Multivector2<f64> {
+        Multivector2::new(
+            self.next(),
+            Vec2D::new(self.next(), self.next()),
+            self.next(),
+        )
+    }
+
+    fn mv3(&mut self) -> Multivector3<f64> {
+        Multivector3::new(self.next(), self.vec3(), self.vec3(), self.next())
+    }
+}
+
+/// The sign of the product of basis blades given as bitmasks.
+fn blade_sign(a: usize, b: usize) -> f64 {
+    let mut swaps = 0;
+    let mut a = a >> 1;
+    while a != 0 {
+        swaps += (a & b).count_ones();
+        a >>= 1;
+    }
+    if swaps % 2 == 0 {
+        1.0
+    } else {
+        -1.0
+    }
+}
+
+/// Reference products on the coefficients of basis blades:
+/// the geometric product, the wedge product and the left contraction.
+fn reference<const N: usize>(a: [f64; N], b: [f64; N]) -> [[f64; N]; 3] {
+    let mut r = [[0.0; N]; 3];
+    for i in 0..N {
+        for j in 0..N {
+            let c = blade_sign(i, j) * a[i] * b[j];
+            let (gi, gj, gk) = (i.count_ones(), j.count_ones(), (i ^ j).count_ones());
+            r[0][i ^ j] += c;
+            if gk == gi + gj {
+                r[1][i ^ j] += c;
+            }
+            if gj >= gi && gk == gj - gi {
+                r[2][i ^ j] += c;
+            }
+        }
+    }
+    r
+}
+
+/// Blades `1, e1, e2, e12`.
+fn blades2(m: Multivector2<f64>) -> [f64; 4] {
+    [m.s, m.v.x, m.v.y, m.b]
+}
+
+/// Blades `1, e1, e2, e12, e3, e13, e23, e123`.
+fn blades3(m: Multivector3<f64>) -> [f64; 8] {
+    [m.s, m.v.x, m.v.y, m.b.z, m.v.z, -m.b.y, m.b.x, m.t]
+}
+
+fn assert_close<const N: usize>(a: [f64; N], b: [f64; N]) {
+    for i in 0..N {
+        assert!((a[i] - b[i]).abs() < 1e-14, "{:?} {:?}", a, b);
+    }
+}
+
+#[test]
+fn products_of_g2() {
+    let mut rng = Lcg(1);
+    for _ in 0..200 {
+        let (a, b) = (rng.mv2(), rng.mv2());
+        let [gp, wedge, inner] = reference(blades2(a), blades2(b));
+        assert_close(blades2(a * b), gp);
+        assert_close(blades2(a.wedge(b)), wedge);
+        assert_close(blades2(a.inner(b)), inner);
+        assert!(((a * a.reverse()).s - a.norm_sqr()).abs() < 1e-15);
+    }
+    let e1 = Multivector2::vector(Vec2D::new(1.0, 0.0));
+    let e2 = Multivector2::from(Vec2D::new(0.0, 1.0));
+    assert_eq!(e2 * e1, Multivector2::bivector(-1.0));
+    assert_eq!(e1.inner(e1 * e2), e2);
+    assert_eq!((e1 + e2 * 2.0).grade(1), e1 + e2 * 2.0);
+    assert_eq!((e1 * e2).grade(1), Multivector2::zero());
+}
+
+#[test]
+fn products_of_g3() {
+    let mut rng = Lcg(2);
+    for _ in 0..200 {
+        let (a, b, c) = (rng.mv3(), rng.mv3(), rng.mv3());
+        let [gp, wedge, inner] = reference(blades3(a), blades3(b));
+        assert_close(blades3(a * b), gp);
+        assert_close(blades3(a.wedge(b)), wedge);
+        assert_close(blades3(a.inner(b)), inner);
+        assert_close(blades3((a * b) * c), blades3(a * (b * c)));
+        assert_close(
+            blades3((a * b).reverse()),
+            blades3(b.reverse() * a.reverse()),
+        );
+        assert!(((a * a.reverse()).s - a.norm_sqr()).abs() < 1e-15);
+        let i = Multivector3::trivector(1.0);
+        assert_close(blades3(a.dual()), blades3(a * i.inverse()));
+    }
+}
+
+#[test]
+fn basis_of_g3() {
+    let e = |x: f64, y: f64, z: f64| Multivector3::vector(Vec3D::new(x, y, z));
+    let (e1, e2, e3) = (e(1.0, 0.0, 0.0), e(0.0, 1.0, 0.0), e(0.0, 0.0, 1.0));
+    let b = |x: f64, y: f64, z: f64| Multivector3::bivector(Vec3D::new(x, y, z));
+    assert_eq!(e2 * e3, b(1.0, 0.0, 0.0));
+    assert_eq!(e3 * e1, b(0.0, 1.0, 0.0));
+    assert_eq!(e1 * e2, b(0.0, 0.0, 1.0));
+    assert_eq!(e1 * e2 * e3, Multivector3::trivector(1.0));
+    assert_eq!(
+        b(0.0, 0.0, 1.0) * b(0.0, 0.0, 1.0),
+        Multivector3::scalar(-1.0)
+    );
+    let i = Multivector3::trivector(1.0);
+    assert_eq!(i * i, Multivector3::scalar(-1.0));
+    assert_eq!(i * e1, b(1.0, 0.0, 0.0));
+    assert_eq!(e1.wedge(e2).wedge(e3), i);
+    assert_eq!(e1.inner(e1.wedge(e2)), e2);
+    assert_eq!((e1 + i).grade(3), i);
+    assert_eq!(i.grade(4), Multivector3::zero());
+    let mut m = e1;
+    m *= e2;
+    m += e3;
+    m -= e3;
+    assert_eq!(m, e1 * e2);
+}
+
+#[test]
+fn reflections_and_rotations_of_versors() {
+    let n = Multivector3::vector(Vec3D::new(0.0, 0.0, 2.0));
+    assert_eq!(
+        n.transform(Vec3D::new(1.0, 2.0, 3.0)),
+        Vec3D::new(1.0, 2.0, -3.0)
+    );
+    // two reflections make a rotation by twice the angle between the normals
+    let m = Multivector3::vector(Vec3D::new(0.0, 1.0, 1.0));
+    let r = m * n;
+    let v = r.transform(Vec3D::new(1.0, 0.0, 0.0));
+    assert!(v.distance(Vec3D::new(1.0, 0.0, 0.0)) < 1e-15);
+    let v = r.transform(Vec3D::new(0.0, 1.0, 0.0));
+    assert!(v.distance(Vec3D::new(0.0, 0.0, -1.0)) < 1e-15);
+
+    let r = Multivector2::rotor(Rad(FRAC_PI_2));
+    let v = r.transform(Vec2D::new(1.0, 2.0));
+    assert!(v.distance(Vec2D::new(-2.0, 1.0)) < 1e-15);
+    let s = r.sandwich(Multivector2::vector(Vec2D::new(1.0, 2.0)));
+    assert!(s.v.distance(Vec2D::new(-2.0, 1.0)) < 1e-15);
+    let n = Multivector2::vector(Vec2D::new(1.0, 1.0));
+    let v = n.transform(Vec2D::new(1.0, 0.0));
+    assert!(v.distance(Vec2D::new(0.0, -1.0)) < 1e-15);
+}
+
+#[test]
+fn rotors() {
+    let mut rng = Lcg(3);
+    for _ in 0..200 {
+        let (a, b, v) = (rng.vec3(), rng.vec3(), rng.vec3());
+        let r = Rotor3::from_vectors(a, b);
+        assert!((r.norm() - 1.0).abs() < 1e-15);
+        assert!(r.rotate(a).normalize().distance(b.normalize()) < 1e-14);
+        let w = r.rotate(v);
+        assert!((w.norm() - v.norm()).abs() < 1e-15);
+        let m = Multivector3::from(r);
+        assert!(m.transform(v).distance(w) < 1e-15);
+        assert!(m.sandwich(Multivector3::from(v)).v.distance(w) < 1e-15);
+        assert!(r.reverse().rotate(w).distance(v) < 1e-15);
+
+        let angle = PI * (rng.next() + 0.5);
+        let q = Rotor3::from_axis_angle(a, Rad(angle));
+        assert!((q.angle().0 - angle).abs() < 1e-13);
+        assert!(q.axis().distance(a.normalize()) < 1e-13);
+        // Rodrigues' rotation formula
+        let k = a.normalize();
+        let expected =
+            v * angle.cos() + k.cross(v) * angle.sin() + k * (k.dot(v) * (1.0 - angle.cos()));
+        assert!(q.rotate(v).distance(expected) < 1e-14);
+        assert!((q * r).rotate(v).distance(q.rotate(r.rotate(v))) < 1e-14);
+        assert!(
+            (Multivector3::from(q * r) - Multivector3::from(q) * Multivector3::from(r)).norm()
+                < 1e-15
+        );
+
+        let p = Rotor3::from_reflections(a, b);
+        let expected = Multivector3::from(b) * Multivector3::from(a);
+        assert!(p.rotate(v).distance(expected.transform(v)) < 1e-14);
+    }
+    let x = Vec3D::new(1.0, 0.0, 0.0);
+    let r = Rotor3::from_vectors(x, -x * 3.0);
+    assert!(r.rotate(x).distance(-x) < 1e-15);
+    let r = Rotor3::from_axis_angle(Vec3D::new(0.0, 0.0, 1.0), Rad(FRAC_PI_2));
+    assert!(r.rotate(x).distance(Vec3D::new(0.0, 1.0, 0.0)) < 1e-15);
+    assert_eq!(Rotor3::<f64>::identity().rotate(x), x);
+    assert_eq!(Rotor3::<f64>::identity().angle(), Rad(0.0));
+    assert!(((r * 2.0).normalize() - r).b.norm() < 1e-16);
+}
+
+#[test]
+fn slerp_of_rotors() {
+    let mut rng = Lcg(5);
+    let id = Rotor3::identity();
+    for _ in 0..100 {
+        let (axis, v) = (rng.vec3(), rng.vec3());
+        let angle = PI * (rng.next() + 0.5);
+        let q = Rotor3::from_axis_angle(axis, Rad(angle));
+        for t in [0.0, 0.25, 0.5, 1.0] {
+            let expected = Rotor3::from_axis_angle(axis, Rad(angle * t)).rotate(v);
+            assert!(id.slerp(q, t).rotate(v).distance(expected) < 1e-14);
+            // the shorter arc to the same rotation
+            assert!(id.slerp(-q, t).rotate(v).distance(expected) < 1e-14);
+        }
+    }
+    let q = Rotor3::from_axis_angle(Vec3D::new(1.0, 2.0, 3.0), Rad(1e-9));
+    assert!((id.slerp(q, 0.5).norm() - 1.0).abs() < 1e-15);
+}
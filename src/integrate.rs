@@ -0,0 +1,249 @@
+#[cfg(test)]
+mod tests;
+
+use num_traits::Float;
+use num_traits::One;
+
+use crate::Vector;
+use crate::VectorRefOps;
+
+/// Fixed-step integrator of second-order systems `x'' = a(t, x, x')`
+/// such as particles moved by forces.
+///
+/// The acceleration callback `accel(t, x, v, a)` receives the time,
+/// the positions and the velocities of all the particles,
+/// and writes the accelerations into `a`.
+/// Symplectic integrators assume that the acceleration depends only on the
+/// positions; they pass the latest known velocities otherwise.
+///
+/// # Examples
+///
+/// ```
+/// use ::ndvec::*;
+///
+/// // harmonic oscillator
+/// let mut x = [Vec2D::new(1f64, 0f64)];
+/// let mut v = [Vec2D::new(0f64, 1f64)];
+/// let mut t = 0f64;
+/// let dt = 1e-3;
+/// while t < std::f64::consts::PI {
+///     VelocityVerlet.step(t, dt, &mut x, &mut v, |_, x, _, a| a[0] = -x[0]);
+///     t += dt;
+/// }
+/// assert!(x[0].distance(Vec2D::new(-1.0, 0.0)) < 1e-2);
+/// ```
+pub trait Integrator {
+    /// The order of the global error.
+    const ORDER: u32;
+
+    /// Whether the integrator is symplectic.
+    const SYMPLECTIC: bool;
+
+    /// Advances the positions `x` and the velocities `v` from time `t` by `dt`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the lengths of `x` and `v` differ.
+    fn step<V: Vector, F>(&self, t: V::Cmp, dt: V::Cmp, x: &mut [V], v: &mut [V], accel: F)
+    where
+        for<'l> &'l V: VectorRefOps<V::Cmp, V>,
+        F: FnMut(V::Cmp, &[V], &[V], &mut [V]);
+}
+
+/// Explicit (forward) Euler method, first order.
+///
+/// Both the positions and the velocities are advanced with the derivatives
+/// at the beginning of the step. The energy of orbits grows steadily.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ExplicitEuler;
+
+/// Semi-implicit (symplectic) Euler method, first order.
+///
+/// The velocities are kicked first, then the positions drift with the new velocities.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct SemiImplicitEuler;
+
+/// Velocity Verlet method, i.e. kick-drift-kick leapfrog, second order.
+///
+/// Evaluates the acceleration twice per step.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct VelocityVerlet;
+
+/// Drift-kick-drift leapfrog, second order.
+///
+/// Evaluates the acceleration once per step, at the middle of the step.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Leapfrog;
+
+/// Classical 4th-order Runge-Kutta method.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Rk4;
+
+/// Yoshida's 4th-order symplectic method, a composition of three leapfrog steps.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Yoshida4;
+
+#[inline]
+fn check_len<V>(x: &[V], v: &[V]) {
+    assert_eq!(x.len(), v.len(), "each position must have its velocity");
+}
+
+#[inline]
+fn two<T: Float>() -> T {
+    T::one() + T::one()
+}
+
+/// `x += v * h` for all particles.
+#[inline]
+fn drift<V: Vector>(x: &mut [V], v: &[V], h: V::Cmp)
+where
+    for<'l> &'l V: VectorRefOps<V::Cmp, V>,
+{
+    for (x, v) in x.iter_mut().zip(v) {
+        *x += v * h;
+    }
+}
+
+impl Integrator for ExplicitEuler {
+    const ORDER: u32 = 1;
+    const SYMPLECTIC: bool = false;
+
+    fn step<V: Vector, F>(&self, t: V::Cmp, dt: V::Cmp, x: &mut [V], v: &mut [V], mut accel: F)
+    where
+        for<'l> &'l V: VectorRefOps<V::Cmp, V>,
+        F: FnMut(V::Cmp, &[V], &[V], &mut [V]),
+    {
+        check_len(x, v);
+        let mut a = vec![V::zero(); x.len()];
+        accel(t, x, v, &mut a);
+        drift(x, v, dt);
+        drift(v, &a, dt);
+    }
+}
+
+impl Integrator for SemiImplicitEuler {
+    const ORDER: u32 = 1;
+    const SYMPLECTIC: bool = true;
+
+    fn step<V: Vector, F>(&self, t: V::Cmp, dt: V::Cmp, x: &mut [V], v: &mut [V], mut accel: F)
+    where
+        for<'l> &'l V: VectorRefOps<V::Cmp, V>,
+        F: FnMut(V::Cmp, &[V], &[V], &mut [V]),
+    {
+        check_len(x, v);
+        let mut a = vec![V::zero(); x.len()];
+        accel(t, x, v, &mut a);
+        drift(v, &a, dt);
+        drift(x, v, dt);
+    }
+}
+
+impl Integrator for VelocityVerlet {
+    const ORDER: u32 = 2;
+    const SYMPLECTIC: bool = true;
+
+    fn step<V: Vector, F>(&self, t: V::Cmp, dt: V::Cmp, x: &mut [V], v: &mut [V], mut accel: F)
+    where
+        for<'l> &'l V: VectorRefOps<V::Cmp, V>,
+        F: FnMut(V::Cmp, &[V], &[V], &mut [V]),
+    {
+        check_len(x, v);
+        let half = dt / two::<V::Cmp>();
+        let mut a = vec![V::zero(); x.len()];
+        accel(t, x, v, &mut a);
+        drift(v, &a, half);
+        drift(x, v, dt);
+        accel(t + dt, x, v, &mut a);
+        drift(v, &a, half);
+    }
+}
+
+impl Integrator for Leapfrog {
+    const ORDER: u32 = 2;
+    const SYMPLECTIC: bool = true;
+
+    fn step<V: Vector, F>(&self, t: V::Cmp, dt: V::Cmp, x: &mut [V], v: &mut [V], mut accel: F)
+    where
+        for<'l> &'l V: VectorRefOps<V::Cmp, V>,
+        F: FnMut(V::Cmp, &[V], &[V], &mut [V]),
+    {
+        check_len(x, v);
+        let half = dt / two::<V::Cmp>();
+        let mut a = vec![V::zero(); x.len()];
+        drift(x, v, half);
+        accel(t + half, x, v, &mut a);
+        drift(v, &a, dt);
+        drift(x, v, half);
+    }
+}
+
+impl Integrator for Rk4 {
+    const ORDER: u32 = 4;
+    const SYMPLECTIC: bool = false;
+
+    fn step<V: Vector, F>(&self, t: V::Cmp, dt: V::Cmp, x: &mut [V], v: &mut [V], mut accel: F)
+    where
+        for<'l> &'l V: VectorRefOps<V::Cmp, V>,
+        F: FnMut(V::Cmp, &[V], &[V], &mut [V]),
+    {
+        check_len(x, v);
+        let n = x.len();
+        let two = two::<V::Cmp>();
+        let half = dt / two;
+        let sixth = dt / (two + two + two);
+
+        let x1 = x.to_vec();
+        let v1 = v.to_vec();
+        let mut a1 = vec![V::zero(); n];
+        accel(t, &x1, &v1, &mut a1);
+
+        let x2: Vec<V> = x1.iter().zip(&v1).map(|(x, v)| x + v * half).collect();
+        let v2: Vec<V> = v1.iter().zip(&a1).map(|(v, a)| v + a * half).collect();
+        let mut a2 = vec![V::zero(); n];
+        accel(t + half, &x2, &v2, &mut a2);
+
+        let x3: Vec<V> = x1.iter().zip(&v2).map(|(x, v)| x + v * half).collect();
+        let v3: Vec<V> = v1.iter().zip(&a2).map(|(v, a)| v + a * half).collect();
+        let mut a3 = vec![V::zero(); n];
+        accel(t + half, &x3, &v3, &mut a3);
+
+        let x4: Vec<V> = x1.iter().zip(&v3).map(|(x, v)| x + v * dt).collect();
+        let v4: Vec<V> = v1.iter().zip(&a3).map(|(v, a)| v + a * dt).collect();
+        let mut a4 = vec![V::zero(); n];
+        accel(t + dt, &x4, &v4, &mut a4);
+
+        for (i, (x, v)) in x.iter_mut().zip(v.iter_mut()).enumerate() {
+            *x += (v1[i] + (v2[i] + v3[i]) * two + v4[i]) * sixth;
+            *v += (a1[i] + (a2[i] + a3[i]) * two + a4[i]) * sixth;
+        }
+    }
+}
+
+impl Integrator for Yoshida4 {
+    const ORDER: u32 = 4;
+    const SYMPLECTIC: bool = true;
+
+    fn step<V: Vector, F>(&self, t: V::Cmp, dt: V::Cmp, x: &mut [V], v: &mut [V], mut accel: F)
+    where
+        for<'l> &'l V: VectorRefOps<V::Cmp, V>,
+        F: FnMut(V::Cmp, &[V], &[V], &mut [V]),
+    {
+        check_len(x, v);
+        let two = two::<V::Cmp>();
+        let cbrt2 = two.cbrt();
+        let w1 = V::Cmp::one() / (two - cbrt2);
+        let w0 = -cbrt2 * w1;
+        let c = [w1 / two, (w0 + w1) / two, (w0 + w1) / two, w1 / two];
+        let d = [w1, w0, w1];
+
+        let mut a = vec![V::zero(); x.len()];
+        let mut t = t;
+        for (c, d) in c.into_iter().zip(d) {
+            drift(x, v, c * dt);
+            t += c * dt;
+            accel(t, x, v, &mut a);
+            drift(v, &a, d * dt);
+        }
+        drift(x, v, c[3] * dt);
+    }
+}
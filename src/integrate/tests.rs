@@ -0,0 +1,147 @@
+use std::f64::consts::PI;
+
+use crate::*;
+
+fn kepler(_: f64, x: &[V2D64], _: &[V2D64], a: &mut [V2D64]) {
+    let r = x[0].norm();
+    a[0] = x[0] * (-1.0 / (r * r * r));
+}
+
+fn energy(x: V2D64, v: V2D64) -> f64 {
+    v.norm_sqr() / 2.0 - 1.0 / x.norm()
+}
+
+/// Energy errors in the first and the last orbits of an eccentric Kepler orbit.
+fn kepler_energy_errors<I: Integrator>(integrator: I, steps_per_orbit: usize) -> (f64, f64) {
+    let e = 0.5;
+    let mut x = [Vec2D::new(1.0 - e, 0.0)];
+    let mut v = [Vec2D::new(0.0, ((1.0 + e) / (1.0 - e)).sqrt())];
+    let e0 = energy(x[0], v[0]);
+    let dt = 2.0 * PI / steps_per_orbit as f64;
+    let orbits = 10;
+    let mut first = 0f64;
+    let mut last = 0f64;
+    for i in 0..orbits * steps_per_orbit {
+        integrator.step(i as f64 * dt, dt, &mut x, &mut v, kepler);
+        let error = (energy(x[0], v[0]) - e0).abs();
+        if i < steps_per_orbit {
+            first = first.max(error);
+        } else if i >= (orbits - 1) * steps_per_orbit {
+            last = last.max(error);
+        }
+    }
+    (first, last)
+}
+
+#[test]
+fn kepler_explicit_euler() {
+    let (first, last) = kepler_energy_errors(ExplicitEuler, 2000);
+    assert!(last > 2.0 * first, "energy must drift: {first} {last}");
+}
+
+#[test]
+fn kepler_semi_implicit_euler() {
+    let (first, last) = kepler_energy_errors(SemiImplicitEuler, 2000);
+    assert!(first < 5e-2, "{first}");
+    assert!(last < 1.2 * first, "energy must not drift: {first} {last}");
+}
+
+#[test]
+fn kepler_velocity_verlet() {
+    let (first, last) = kepler_energy_errors(VelocityVerlet, 2000);
+    assert!(first < 1e-3, "{first}");
+    assert!(last < 1.2 * first, "energy must not drift: {first} {last}");
+}
+
+#[test]
+fn kepler_leapfrog() {
+    let (first, last) = kepler_energy_errors(Leapfrog, 2000);
+    assert!(first < 1e-3, "{first}");
+    assert!(last < 1.2 * first, "energy must not drift: {first} {last}");
+}
+
+#[test]
+fn kepler_rk4() {
+    let (first, last) = kepler_energy_errors(Rk4, 2000);
+    assert!(last < 1e-6, "{first} {last}");
+}
+
+#[test]
+fn kepler_yoshida4() {
+    let (first, last) = kepler_energy_errors(Yoshida4, 2000);
+    assert!(first < 1e-6, "{first}");
+    assert!(last < 1.2 * first, "energy must not drift: {first} {last}");
+}
+
+/// Global error of a harmonic oscillator at `t = 1`.
+fn oscillator_error<I: Integrator>(integrator: &I, steps: usize) -> f64 {
+    let mut x = [VecND::from([1.0, 0.0, 0.5])];
+    let mut v = [VecND::from([0.0, 1.0, 0.0])];
+    let dt = 1.0 / steps as f64;
+    for i in 0..steps {
+        integrator.step(i as f64 * dt, dt, &mut x, &mut v, |_, x, _, a| {
+            a[0] = -x[0];
+        });
+    }
+    let expected = VecND::from([1.0f64.cos(), 1.0f64.sin(), 0.5 * 1.0f64.cos()]);
+    x[0].distance(expected)
+}
+
+#[test]
+fn order() {
+    fn test<I: Integrator + std::fmt::Debug>(integrator: I) {
+        let ratio = oscillator_error(&integrator, 50) / oscillator_error(&integrator, 100);
+        let expected = 2f64.powi(I::ORDER as i32);
+        assert!(
+            (ratio / expected - 1.0).abs() < 0.1,
+            "{integrator:?}: {ratio} != {expected}"
+        );
+    }
+    test(ExplicitEuler);
+    test(SemiImplicitEuler);
+    test(VelocityVerlet);
+    test(Leapfrog);
+    test(Rk4);
+    test(Yoshida4);
+}
+
+#[test]
+fn time_dependent() {
+    // x'' = t, x(0) = 0, x'(0) = 0 => x = t^3 / 6
+    let mut x = [Vec1D::new(0f64)];
+    let mut v = [Vec1D::new(0f64)];
+    for i in 0..10 {
+        Rk4.step(i as f64 * 0.1, 0.1, &mut x, &mut v, |t, _, _, a| {
+            a[0] = Vec1D::new(t);
+        });
+    }
+    assert!((x[0].x - 1.0 / 6.0).abs() < 1e-12);
+    assert!((v[0].x - 0.5).abs() < 1e-12);
+}
+
+#[test]
+fn many_particles() {
+    let mut x = [Vec3D::new(1f32, 0f32, 0f32), Vec3D::new(-1f32, 0f32, 0f32)];
+    let mut v = [
+        Vec3D::new(0f32, 0.5f32, 0f32),
+        Vec3D::new(0f32, -0.5f32, 0f32),
+    ];
+    let accel = |_, x: &[V3D32], _: &[V3D32], a: &mut [V3D32]| {
+        let d = x[1] - x[0];
+        let f = d / d.norm().powi(3);
+        a[0] = f;
+        a[1] = -f;
+    };
+    for i in 0..1000 {
+        VelocityVerlet.step(i as f32 * 0.01, 0.01, &mut x, &mut v, accel);
+    }
+    assert!((x[0] + x[1]).norm() < 1e-4, "center of mass must stay");
+    assert!((v[0] + v[1]).norm() < 1e-4, "momentum must be conserved");
+}
+
+#[test]
+#[should_panic]
+fn length_mismatch() {
+    let mut x = [Vec2D::new(0f64, 0f64)];
+    Leapfrog.step(0.0, 0.1, &mut x, &mut [], |_, _, _, _| {});
+}
@@ -0,0 +1,1099 @@
+#[cfg(test)]
+mod tests;
+
+use std::cmp::Ordering;
+use std::fmt::Display;
+use std::iter::Sum;
+use std::num::FpCategory;
+use std::ops::Add;
+use std::ops::AddAssign;
+use std::ops::Div;
+use std::ops::DivAssign;
+use std::ops::Mul;
+use std::ops::MulAssign;
+use std::ops::Neg;
+use std::ops::Rem;
+use std::ops::RemAssign;
+use std::ops::Sub;
+use std::ops::SubAssign;
+
+use num_traits::Float;
+use num_traits::Num;
+use num_traits::NumCast;
+use num_traits::One;
+use num_traits::ToPrimitive;
+use num_traits::Zero;
+#[cfg(feature = "serde")]
+use serde::Deserialize;
+#[cfg(feature = "serde")]
+use serde::Serialize;
+
+use crate::components::Components;
+use crate::Aabb;
+use crate::VectorRefOps;
+
+/// Closed interval `[lo, hi]` of floating-point numbers with outward rounding.
+///
+/// Every operation returns an interval containing all the exact results for
+/// the operands in the input intervals, so a chain of operations gives
+/// guaranteed bounds of the exact value.
+/// The rounding errors of `+`, `-`, `*`, `/` and `sqrt` are computed exactly,
+/// thus their bounds are as tight as possible; the other functions are widened
+/// by one ulp, assuming that the standard library is accurate to within one ulp.
+/// The bounds can be infinite, and an interval with a NaN bound is NaN.
+///
+/// It implements [`Float`] and is therefore a [`Component`](crate::Component).
+/// Comparisons are certain: `a < b` holds only if every element of `a` is less
+/// than every element of `b`, and `a == b` only for the same bounds.
+///
+/// # Examples
+///
+/// ```
+/// use ::ndvec::*;
+///
+/// let a = Vec3D::new(Interval::point(0.1), Interval::point(0.2), Interval::point(0.3));
+/// let b = Vec3D::new(Interval::point(3.0), Interval::new(-1.0, 1.0), Interval::point(0.0));
+/// let d = a.dot(b);
+/// assert!(d.lo() <= 0.1 && 0.5 <= d.hi());
+/// assert!(d.width() < 0.4 + 1e-15);
+/// ```
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Interval<T> {
+    lo: T,
+    hi: T,
+}
+
+/// The next representable number toward +∞, or the one after it.
+#[inline]
+fn up<T: Float>(x: T) -> T {
+    if x.is_nan() || x == T::infinity() {
+        x
+    } else if x == T::neg_infinity() {
+        -T::max_value()
+    } else {
+        let tiny = T::min_positive_value() * T::epsilon();
+        x + (x.abs() * T::epsilon()).max(tiny)
+    }
+}
+
+/// The next representable number toward -∞, or the one after it.
+#[inline]
+fn down<T: Float>(x: T) -> T {
+    -up(-x)
+}
+
+/// Rounds the computed `value` toward -∞ (`upward == false`) or +∞
+/// given the error `exact - value`, where `None` means the error is unknown.
+#[inline]
+fn round<T: Float>(value: T, error: Option<T>, upward: bool) -> T {
+    match error {
+        Some(e) if e.is_zero() => value,
+        Some(e) if (e > T::zero()) != upward => value,
+        _ if upward => up(value),
+        _ => down(value),
+    }
+}
+
+/// Magnitude below which the error terms of products and quotients may be inexact.
+#[inline]
+fn underflow<T: Float>() -> T {
+    let two = T::one() + T::one();
+    T::min_positive_value() / T::epsilon() * two * two
+}
+
+fn add_rounded<T: Float>(a: T, b: T, upward: bool) -> T {
+    let s = a + b;
+    if !a.is_finite() || !b.is_finite() {
+        return s;
+    }
+    if !s.is_finite() {
+        return round(s, None, upward);
+    }
+    // Knuth's TwoSum
+    let bp = s - a;
+    let ap = s - bp;
+    round(s, Some((a - ap) + (b - bp)), upward)
+}
+
+fn mul_rounded<T: Float>(a: T, b: T, upward: bool) -> T {
+    let p = a * b;
+    if a.is_zero() || b.is_zero() {
+        // 0 * ∞ is 0 for the closed intervals of reals
+        return T::zero();
+    }
+    if !a.is_finite() || !b.is_finite() {
+        return p;
+    }
+    if !p.is_finite() || p.abs() < underflow() {
+        return round(p, None, upward);
+    }
+    round(p, Some(a.mul_add(b, -p)), upward)
+}
+
+fn div_rounded<T: Float>(a: T, b: T, upward: bool) -> T {
+    let q = a / b;
+    if a.is_zero() || !a.is_finite() || !b.is_finite() {
+        return q;
+    }
+    if !q.is_finite() || q.abs() < underflow() || a.abs() < underflow() {
+        return round(q, None, upward);
+    }
+    // a - q b is exact, and a / b - q has the sign of (a - q b) / b
+    let r = (-q).mul_add(b, a);
+    let e = if (r > T::zero()) == (b > T::zero()) {
+        r.abs()
+    } else {
+        -r.abs()
+    };
+    round(q, Some(e), upward)
+}
+
+fn sqrt_rounded<T: Float>(a: T, upward: bool) -> T {
+    let s = a.sqrt();
+    if a.is_zero() || !s.is_finite() {
+        return s;
+    }
+    if a < underflow() {
+        return round(s, None, upward).max(T::zero());
+    }
+    round(s, Some(-s.mul_add(s, -a)), upward)
+}
+
+impl<T: Float> Interval<T> {
+    /// Creates an interval from its bounds.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `lo > hi`.
+    #[inline]
+    pub fn new(lo: T, hi: T) -> Self {
+        assert!(
+            lo <= hi || lo.is_nan() || hi.is_nan(),
+            "the lower bound must not exceed the upper bound"
+        );
+        Self { lo, hi }
+    }
+
+    /// Creates the interval containing only `x`.
+    #[inline]
+    pub fn point(x: T) -> Self {
+        Self { lo: x, hi: x }
+    }
+
+    /// Creates the interval of all the numbers, `[-∞, ∞]`.
+    #[inline]
+    pub fn entire() -> Self {
+        Self {
+            lo: T::neg_infinity(),
+            hi: T::infinity(),
+        }
+    }
+
+    /// Creates the smallest interval containing both `a` and `b`.
+    #[inline]
+    pub fn hull(a: T, b: T) -> Self {
+        Self {
+            lo: a.min(b),
+            hi: a.max(b),
+        }
+    }
+
+    /// The lower bound.
+    #[inline]
+    pub fn lo(&self) -> T {
+        self.lo
+    }
+
+    /// The upper bound.
+    #[inline]
+    pub fn hi(&self) -> T {
+        self.hi
+    }
+
+    /// The midpoint, which is inside of the interval.
+    pub fn mid(&self) -> T {
+        let two = T::one() + T::one();
+        match (self.lo.is_infinite(), self.hi.is_infinite()) {
+            (true, true) => T::zero(),
+            (true, false) => self.hi,
+            (false, true) => self.lo,
+            (false, false) => (self.lo / two + self.hi / two).max(self.lo).min(self.hi),
+        }
+    }
+
+    /// The width `hi - lo`, rounded upward.
+    #[inline]
+    pub fn width(&self) -> T {
+        add_rounded(self.hi, -self.lo, true)
+    }
+
+    /// Checks if `x` is in the interval.
+    #[inline]
+    pub fn contains(&self, x: T) -> bool {
+        self.lo <= x && x <= self.hi
+    }
+
+    /// Checks if the another interval is completely inside of this interval.
+    #[inline]
+    pub fn contains_interval(&self, other: &Self) -> bool {
+        self.lo <= other.lo && other.hi <= self.hi
+    }
+
+    /// The intersection of the intervals, or `None` if they are disjoint.
+    #[inline]
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        let lo = self.lo.max(other.lo);
+        let hi = self.hi.min(other.hi);
+        (lo <= hi).then_some(Self { lo, hi })
+    }
+
+    /// The smallest interval containing both of the intervals.
+    #[inline]
+    pub fn union(&self, other: &Self) -> Self {
+        Self {
+            lo: self.lo.min(other.lo),
+            hi: self.hi.max(other.hi),
+        }
+    }
+
+    /// Applies a non-decreasing function accurate to within one ulp.
+    #[inline]
+    fn increasing(self, f: impl Fn(T) -> T) -> Self {
+        if self.is_nan() {
+            return self;
+        }
+        Self {
+            lo: down(f(self.lo)),
+            hi: up(f(self.hi)),
+        }
+    }
+
+    /// Applies a non-increasing function accurate to within one ulp.
+    #[inline]
+    fn decreasing(self, f: impl Fn(T) -> T) -> Self {
+        if self.is_nan() {
+            return self;
+        }
+        Self {
+            lo: down(f(self.hi)),
+            hi: up(f(self.lo)),
+        }
+    }
+
+    /// Restricts the interval to the domain `[lo, hi]` of a function,
+    /// giving NaN outside of it.
+    #[inline]
+    fn restrict(self, lo: T, hi: T) -> Self {
+        self.intersection(&Self { lo, hi })
+            .unwrap_or_else(Self::nan)
+    }
+
+    /// Clamps the bounds into the range `[lo, hi]` of a function.
+    #[inline]
+    fn clamp_range(self, lo: T, hi: T) -> Self {
+        if self.is_nan() {
+            return self;
+        }
+        Self {
+            lo: self.lo.max(lo).min(hi),
+            hi: self.hi.min(hi).max(lo),
+        }
+    }
+
+    /// `π` enclosed.
+    #[inline]
+    fn pi() -> Self {
+        let pi = T::from(std::f64::consts::PI).expect("π must be representable");
+        Self {
+            lo: down(pi),
+            hi: up(pi),
+        }
+    }
+
+    /// Checks if the interval may contain `offset + k period` for any integer `k`.
+    fn may_contain_periodic(&self, offset: T, period: T) -> bool {
+        let a = (self.lo - offset) / period;
+        let b = (self.hi - offset) / period;
+        let four = T::from(4).expect("4 must be representable");
+        let margin = |x: T| T::epsilon() * (x.abs() + T::one()) * four;
+        (b + margin(b)).floor() >= (a - margin(a)).ceil()
+    }
+
+    /// The sine or the cosine, whose maximum is at `phase + 2kπ`.
+    fn sinusoid(self, f: impl Fn(T) -> T, phase: T) -> Self {
+        if self.is_nan() {
+            return self;
+        }
+        let one = T::one();
+        let pi = Self::pi().mid();
+        let two_pi = pi + pi;
+        if self.hi - self.lo >= two_pi {
+            return Self { lo: -one, hi: one };
+        }
+        let (a, b) = (f(self.lo), f(self.hi));
+        let mut result = Self {
+            lo: down(a.min(b)),
+            hi: up(a.max(b)),
+        };
+        if self.may_contain_periodic(phase, two_pi) {
+            result.hi = one;
+        }
+        if self.may_contain_periodic(phase + pi, two_pi) {
+            result.lo = -one;
+        }
+        result.clamp_range(-one, one)
+    }
+
+    /// `x^n` for `n > 0` and `lo >= 0` by squaring.
+    fn powu_nonnegative(self, n: u32) -> Self {
+        let mut base = self;
+        let mut result = Self::one();
+        let mut n = n;
+        while n > 0 {
+            if n & 1 == 1 {
+                result *= base;
+            }
+            n >>= 1;
+            if n > 0 {
+                base *= base;
+            }
+        }
+        result
+    }
+
+    fn powu(self, n: u32) -> Self {
+        if n == 0 {
+            return Self::one();
+        }
+        if n & 1 == 0 {
+            return self.abs().powu_nonnegative(n);
+        }
+        // odd powers are increasing
+        let bound = |x: T| {
+            let p = Self::point(x.abs()).powu_nonnegative(n);
+            if x < T::zero() {
+                -p
+            } else {
+                p
+            }
+        };
+        Self {
+            lo: bound(self.lo).lo,
+            hi: bound(self.hi).hi,
+        }
+    }
+}
+
+impl<T: Float> PartialEq for Interval<T> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.lo == other.lo && self.hi == other.hi
+    }
+}
+
+impl<T: Float> PartialOrd for Interval<T> {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        if self == other {
+            Some(Ordering::Equal)
+        } else if self.hi < other.lo {
+            Some(Ordering::Less)
+        } else if self.lo > other.hi {
+            Some(Ordering::Greater)
+        } else {
+            None
+        }
+    }
+}
+
+impl<T: Display> Display for Interval<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "[")?;
+        self.lo.fmt(f)?;
+        write!(f, ", ")?;
+        self.hi.fmt(f)?;
+        write!(f, "]")
+    }
+}
+
+impl<T: Float> Neg for Interval<T> {
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self {
+        Self {
+            lo: -self.hi,
+            hi: -self.lo,
+        }
+    }
+}
+
+impl<T: Float> Neg for &Interval<T> {
+    type Output = Interval<T>;
+
+    #[inline]
+    fn neg(self) -> Interval<T> {
+        -*self
+    }
+}
+
+impl<T: Float> Add for Interval<T> {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, rhs: Self) -> Self {
+        if self.is_nan() || rhs.is_nan() {
+            return Self::nan();
+        }
+        Self {
+            lo: add_rounded(self.lo, rhs.lo, false),
+            hi: add_rounded(self.hi, rhs.hi, true),
+        }
+    }
+}
+
+impl<T: Float> Sub for Interval<T> {
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, rhs: Self) -> Self {
+        self + -rhs
+    }
+}
+
+impl<T: Float> Mul for Interval<T> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        if self.is_nan() || rhs.is_nan() {
+            return Self::nan();
+        }
+        let corners = [
+            (self.lo, rhs.lo),
+            (self.lo, rhs.hi),
+            (self.hi, rhs.lo),
+            (self.hi, rhs.hi),
+        ];
+        Self {
+            lo: corners
+                .iter()
+                .map(|&(a, b)| mul_rounded(a, b, false))
+                .fold(T::infinity(), T::min),
+            hi: corners
+                .iter()
+                .map(|&(a, b)| mul_rounded(a, b, true))
+                .fold(T::neg_infinity(), T::max),
+        }
+    }
+}
+
+impl<T: Float> Div for Interval<T> {
+    type Output = Self;
+
+    /// The quotient, which is [`entire`](Interval::entire) if the divisor contains zero.
+    fn div(self, rhs: Self) -> Self {
+        if self.is_nan() || rhs.is_nan() {
+            return Self::nan();
+        }
+        if rhs.contains(T::zero()) {
+            return Self::entire();
+        }
+        let corners = [
+            (self.lo, rhs.lo),
+            (self.lo, rhs.hi),
+            (self.hi, rhs.lo),
+            (self.hi, rhs.hi),
+        ];
+        Self {
+            lo: corners
+                .iter()
+                .map(|&(a, b)| div_rounded(a, b, false))
+                .fold(T::infinity(), T::min),
+            hi: corners
+                .iter()
+                .map(|&(a, b)| div_rounded(a, b, true))
+                .fold(T::neg_infinity(), T::max),
+        }
+    }
+}
+
+impl<T: Float> Rem for Interval<T> {
+    type Output = Self;
+
+    /// Encloses `a - b trunc(a / b)`.
+    #[inline]
+    fn rem(self, rhs: Self) -> Self {
+        if self.is_nan() || rhs.is_nan() {
+            return Self::nan();
+        }
+        if rhs.lo == rhs.hi && self.lo == self.hi {
+            return Self::point(self.lo % rhs.lo);
+        }
+        self - rhs * (self / rhs).trunc()
+    }
+}
+
+macro_rules! impl_ref_ops {
+    ($($Op:ident, $op:ident, $OpAssign:ident, $op_assign:ident);+) => {
+        $(
+            impl<T: Float> $Op<&Interval<T>> for Interval<T> {
+                type Output = Interval<T>;
+
+                #[inline]
+                fn $op(self, rhs: &Interval<T>) -> Interval<T> {
+                    self.$op(*rhs)
+                }
+            }
+
+            impl<T: Float> $Op<Interval<T>> for &Interval<T> {
+                type Output = Interval<T>;
+
+                #[inline]
+                fn $op(self, rhs: Interval<T>) -> Interval<T> {
+                    (*self).$op(rhs)
+                }
+            }
+
+            impl<T: Float> $Op<&Interval<T>> for &Interval<T> {
+                type Output = Interval<T>;
+
+                #[inline]
+                fn $op(self, rhs: &Interval<T>) -> Interval<T> {
+                    (*self).$op(*rhs)
+                }
+            }
+
+            impl<T: Float> $OpAssign for Interval<T> {
+                #[inline]
+                fn $op_assign(&mut self, rhs: Interval<T>) {
+                    *self = (*self).$op(rhs);
+                }
+            }
+
+            impl<T: Float> $OpAssign<&Interval<T>> for Interval<T> {
+                #[inline]
+                fn $op_assign(&mut self, rhs: &Interval<T>) {
+                    *self = (*self).$op(*rhs);
+                }
+            }
+        )+
+    };
+}
+
+impl_ref_ops!(
+    Add, add, AddAssign, add_assign;
+    Sub, sub, SubAssign, sub_assign;
+    Mul, mul, MulAssign, mul_assign;
+    Div, div, DivAssign, div_assign;
+    Rem, rem, RemAssign, rem_assign
+);
+
+impl<T: Float> Sum for Interval<T> {
+    #[inline]
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::zero(), |a, b| a + b)
+    }
+}
+
+impl<T: Float> Zero for Interval<T> {
+    #[inline]
+    fn zero() -> Self {
+        Self::point(T::zero())
+    }
+
+    #[inline]
+    fn is_zero(&self) -> bool {
+        self.lo.is_zero() && self.hi.is_zero()
+    }
+}
+
+impl<T: Float> One for Interval<T> {
+    #[inline]
+    fn one() -> Self {
+        Self::point(T::one())
+    }
+}
+
+impl<T: Float> Num for Interval<T> {
+    type FromStrRadixErr = T::FromStrRadixErr;
+
+    #[inline]
+    fn from_str_radix(str: &str, radix: u32) -> Result<Self, Self::FromStrRadixErr> {
+        T::from_str_radix(str, radix).map(Self::point)
+    }
+}
+
+impl<T: Float> ToPrimitive for Interval<T> {
+    #[inline]
+    fn to_i64(&self) -> Option<i64> {
+        self.mid().to_i64()
+    }
+
+    #[inline]
+    fn to_u64(&self) -> Option<u64> {
+        self.mid().to_u64()
+    }
+
+    #[inline]
+    fn to_f64(&self) -> Option<f64> {
+        self.mid().to_f64()
+    }
+}
+
+impl<T: Float> NumCast for Interval<T> {
+    #[inline]
+    fn from<P: ToPrimitive>(n: P) -> Option<Self> {
+        T::from(n).map(Self::point)
+    }
+}
+
+impl<T: Float> Float for Interval<T> {
+    #[inline]
+    fn nan() -> Self {
+        Self::point(T::nan())
+    }
+
+    #[inline]
+    fn infinity() -> Self {
+        Self::point(T::infinity())
+    }
+
+    #[inline]
+    fn neg_infinity() -> Self {
+        Self::point(T::neg_infinity())
+    }
+
+    #[inline]
+    fn neg_zero() -> Self {
+        Self::point(T::neg_zero())
+    }
+
+    #[inline]
+    fn min_value() -> Self {
+        Self::point(T::min_value())
+    }
+
+    #[inline]
+    fn min_positive_value() -> Self {
+        Self::point(T::min_positive_value())
+    }
+
+    #[inline]
+    fn epsilon() -> Self {
+        Self::point(T::epsilon())
+    }
+
+    #[inline]
+    fn max_value() -> Self {
+        Self::point(T::max_value())
+    }
+
+    #[inline]
+    fn is_nan(self) -> bool {
+        self.lo.is_nan() || self.hi.is_nan()
+    }
+
+    #[inline]
+    fn is_infinite(self) -> bool {
+        self.lo.is_infinite() || self.hi.is_infinite()
+    }
+
+    #[inline]
+    fn is_finite(self) -> bool {
+        self.lo.is_finite() && self.hi.is_finite()
+    }
+
+    #[inline]
+    fn is_normal(self) -> bool {
+        self.lo.is_normal() && self.hi.is_normal()
+    }
+
+    #[inline]
+    fn classify(self) -> FpCategory {
+        if self.is_nan() {
+            FpCategory::Nan
+        } else if self.is_infinite() {
+            FpCategory::Infinite
+        } else {
+            self.mid().classify()
+        }
+    }
+
+    #[inline]
+    fn floor(self) -> Self {
+        Self {
+            lo: self.lo.floor(),
+            hi: self.hi.floor(),
+        }
+    }
+
+    #[inline]
+    fn ceil(self) -> Self {
+        Self {
+            lo: self.lo.ceil(),
+            hi: self.hi.ceil(),
+        }
+    }
+
+    #[inline]
+    fn round(self) -> Self {
+        Self {
+            lo: self.lo.round(),
+            hi: self.hi.round(),
+        }
+    }
+
+    #[inline]
+    fn trunc(self) -> Self {
+        Self {
+            lo: self.lo.trunc(),
+            hi: self.hi.trunc(),
+        }
+    }
+
+    fn fract(self) -> Self {
+        if self.is_nan() || self.lo.trunc() == self.hi.trunc() {
+            return Self {
+                lo: self.lo.fract(),
+                hi: self.hi.fract(),
+            };
+        }
+        let one = T::one();
+        let lo = if self.lo < T::zero() { -one } else { T::zero() };
+        let hi = if self.hi > T::zero() { one } else { T::zero() };
+        Self { lo, hi }
+    }
+
+    #[inline]
+    fn abs(self) -> Self {
+        if self.lo >= T::zero() {
+            self
+        } else if self.hi <= T::zero() {
+            -self
+        } else {
+            Self {
+                lo: T::zero(),
+                hi: (-self.lo).max(self.hi),
+            }
+        }
+    }
+
+    #[inline]
+    fn signum(self) -> Self {
+        Self {
+            lo: self.lo.signum(),
+            hi: self.hi.signum(),
+        }
+    }
+
+    /// Checks if all the elements have the positive sign.
+    #[inline]
+    fn is_sign_positive(self) -> bool {
+        self.lo.is_sign_positive()
+    }
+
+    /// Checks if all the elements have the negative sign.
+    #[inline]
+    fn is_sign_negative(self) -> bool {
+        self.hi.is_sign_negative()
+    }
+
+    #[inline]
+    fn mul_add(self, a: Self, b: Self) -> Self {
+        self * a + b
+    }
+
+    #[inline]
+    fn recip(self) -> Self {
+        Self::one() / self
+    }
+
+    fn powi(self, n: i32) -> Self {
+        let p = self.powu(n.unsigned_abs());
+        if n < 0 {
+            p.recip()
+        } else {
+            p
+        }
+    }
+
+    fn powf(self, n: Self) -> Self {
+        if n.lo == n.hi
+            && n.lo.trunc() == n.lo
+            && n.lo.abs() <= T::from(i32::MAX).expect("i32 must be representable")
+        {
+            return self.powi(n.lo.to_i32().expect("integer exponent must fit"));
+        }
+        (self.ln() * n).exp()
+    }
+
+    #[inline]
+    fn sqrt(self) -> Self {
+        let x = self.restrict(T::zero(), T::infinity());
+        if x.is_nan() {
+            return x;
+        }
+        Self {
+            lo: sqrt_rounded(x.lo, false),
+            hi: sqrt_rounded(x.hi, true),
+        }
+    }
+
+    #[inline]
+    fn exp(self) -> Self {
+        self.increasing(T::exp)
+            .clamp_range(T::zero(), T::infinity())
+    }
+
+    #[inline]
+    fn exp2(self) -> Self {
+        self.increasing(T::exp2)
+            .clamp_range(T::zero(), T::infinity())
+    }
+
+    #[inline]
+    fn ln(self) -> Self {
+        self.restrict(T::zero(), T::infinity()).increasing(T::ln)
+    }
+
+    #[inline]
+    fn log(self, base: Self) -> Self {
+        self.ln() / base.ln()
+    }
+
+    #[inline]
+    fn log2(self) -> Self {
+        self.restrict(T::zero(), T::infinity()).increasing(T::log2)
+    }
+
+    #[inline]
+    fn log10(self) -> Self {
+        self.restrict(T::zero(), T::infinity()).increasing(T::log10)
+    }
+
+    #[inline]
+    fn to_degrees(self) -> Self {
+        self * Self::point(T::from(180).expect("180 must be representable")) / Self::pi()
+    }
+
+    #[inline]
+    fn to_radians(self) -> Self {
+        self * Self::pi() / Self::point(T::from(180).expect("180 must be representable"))
+    }
+
+    #[inline]
+    fn max(self, other: Self) -> Self {
+        Self {
+            lo: self.lo.max(other.lo),
+            hi: self.hi.max(other.hi),
+        }
+    }
+
+    #[inline]
+    fn min(self, other: Self) -> Self {
+        Self {
+            lo: self.lo.min(other.lo),
+            hi: self.hi.min(other.hi),
+        }
+    }
+
+    #[inline]
+    fn abs_sub(self, other: Self) -> Self {
+        (self - other).max(Self::zero())
+    }
+
+    #[inline]
+    fn cbrt(self) -> Self {
+        self.increasing(T::cbrt)
+    }
+
+    #[inline]
+    fn hypot(self, other: Self) -> Self {
+        (self.powi(2) + other.powi(2)).sqrt()
+    }
+
+    #[inline]
+    fn sin(self) -> Self {
+        let half_pi = Self::pi().mid() / (T::one() + T::one());
+        self.sinusoid(T::sin, half_pi)
+    }
+
+    #[inline]
+    fn cos(self) -> Self {
+        self.sinusoid(T::cos, T::zero())
+    }
+
+    fn tan(self) -> Self {
+        if self.is_nan() {
+            return self;
+        }
+        let pi = Self::pi().mid();
+        let half_pi = pi / (T::one() + T::one());
+        if self.hi - self.lo >= pi || self.may_contain_periodic(half_pi, pi) {
+            return Self::entire();
+        }
+        self.increasing(T::tan)
+    }
+
+    #[inline]
+    fn asin(self) -> Self {
+        let half_pi = Self::pi().hi / (T::one() + T::one());
+        self.restrict(-T::one(), T::one())
+            .increasing(T::asin)
+            .clamp_range(-half_pi, half_pi)
+    }
+
+    #[inline]
+    fn acos(self) -> Self {
+        self.restrict(-T::one(), T::one())
+            .decreasing(T::acos)
+            .clamp_range(T::zero(), Self::pi().hi)
+    }
+
+    #[inline]
+    fn atan(self) -> Self {
+        let half_pi = Self::pi().hi / (T::one() + T::one());
+        self.increasing(T::atan).clamp_range(-half_pi, half_pi)
+    }
+
+    /// The angle of the points `(other, self)`, which is `[-π, π]`
+    /// if the box of the points touches the negative x-axis or the origin.
+    fn atan2(self, other: Self) -> Self {
+        if self.is_nan() || other.is_nan() {
+            return Self::nan();
+        }
+        let pi = Self::pi().hi;
+        if other.lo <= T::zero() && self.contains(T::zero()) {
+            return Self { lo: -pi, hi: pi };
+        }
+        let corners = [
+            self.lo.atan2(other.lo),
+            self.lo.atan2(other.hi),
+            self.hi.atan2(other.lo),
+            self.hi.atan2(other.hi),
+        ];
+        Self {
+            lo: down(corners.iter().copied().fold(T::infinity(), T::min)),
+            hi: up(corners.iter().copied().fold(T::neg_infinity(), T::max)),
+        }
+        .clamp_range(-pi, pi)
+    }
+
+    #[inline]
+    fn sin_cos(self) -> (Self, Self) {
+        (self.sin(), self.cos())
+    }
+
+    #[inline]
+    fn exp_m1(self) -> Self {
+        self.increasing(T::exp_m1)
+            .clamp_range(-T::one(), T::infinity())
+    }
+
+    #[inline]
+    fn ln_1p(self) -> Self {
+        self.restrict(-T::one(), T::infinity()).increasing(T::ln_1p)
+    }
+
+    #[inline]
+    fn sinh(self) -> Self {
+        self.increasing(T::sinh)
+    }
+
+    fn cosh(self) -> Self {
+        if self.lo >= T::zero() {
+            self.increasing(T::cosh)
+        } else if self.hi <= T::zero() {
+            self.decreasing(T::cosh)
+        } else {
+            Self {
+                lo: T::one(),
+                hi: up(self.lo.cosh().max(self.hi.cosh())),
+            }
+        }
+        .clamp_range(T::one(), T::infinity())
+    }
+
+    #[inline]
+    fn tanh(self) -> Self {
+        self.increasing(T::tanh).clamp_range(-T::one(), T::one())
+    }
+
+    #[inline]
+    fn asinh(self) -> Self {
+        self.increasing(T::asinh)
+    }
+
+    #[inline]
+    fn acosh(self) -> Self {
+        self.restrict(T::one(), T::infinity())
+            .increasing(T::acosh)
+            .clamp_range(T::zero(), T::infinity())
+    }
+
+    #[inline]
+    fn atanh(self) -> Self {
+        self.restrict(-T::one(), T::one()).increasing(T::atanh)
+    }
+
+    #[inline]
+    fn integer_decode(self) -> (u64, i16, i8) {
+        self.mid().integer_decode()
+    }
+}
+
+impl<V: Components> Aabb<V>
+where
+    for<'l> &'l V: VectorRefOps<V::Cmp, V>,
+{
+    /// Creates the box enclosing a vector of intervals of the same dimension.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the dimensions differ.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ::ndvec::*;
+    ///
+    /// let v = Vec2D::new(Interval::new(0.0, 1.0), Interval::new(2.0, 4.0));
+    /// let b: Aabb<V2D64> = Aabb::from_intervals(v);
+    /// assert_eq!(b, Aabb::new(Vec2D::new(0.0, 2.0), Vec2D::new(1.0, 4.0)));
+    /// let w: Vec2D<Interval<f64>> = b.to_intervals();
+    /// assert_eq!(w, v);
+    /// ```
+    pub fn from_intervals<W>(v: W) -> Self
+    where
+        W: Components<Cmp = Interval<V::Cmp>>,
+        for<'l> &'l W: VectorRefOps<W::Cmp, W>,
+    {
+        assert_eq!(V::DIM, W::DIM, "dimensions must match");
+        let mut min = V::zero();
+        let mut max = V::zero();
+        for i in 0..V::DIM {
+            min[i] = v[i].lo;
+            max[i] = v[i].hi;
+        }
+        Self { min, max }
+    }
+
+    /// The vector of intervals of the same dimension spanned by the box.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the dimensions differ or the box is not [valid](Aabb::is_valid).
+    pub fn to_intervals<W>(&self) -> W
+    where
+        W: Components<Cmp = Interval<V::Cmp>>,
+        for<'l> &'l W: VectorRefOps<W::Cmp, W>,
+    {
+        assert_eq!(V::DIM, W::DIM, "dimensions must match");
+        let mut v = W::zero();
+        for i in 0..V::DIM {
+            v[i] = Interval::new(self.min[i], self.max[i]);
+        }
+        v
+    }
+}
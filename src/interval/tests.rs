@@ -0,0 +1,222 @@
+#![allow(clippy::op_ref)]
+
+use num_traits::Float;
+
+use crate::testing::Lcg;
+use crate::*;
+
+impl Lcg {
+    /// A dyadic number `k / 2^20` with `|k| < 2^23`, as `(k, k / 2^20)`.
+    fn dyadic(&mut self) -> (i128, f64) {
+        let k = (self.bits() % (1 << 24)) as i128 - (1 << 23);
+        (k, k as f64 / (1u64 << 20) as f64)
+    }
+}
+
+/// A function and its interval extension.
+type Function = (fn(f64) -> f64, fn(Interval<f64>) -> Interval<f64>);
+
+const SCALE: f64 = (1u64 << 40) as f64;
+
+fn point3(c: [f64; 3]) -> Vec3D<Interval<f64>> {
+    Vec3D::new(
+        Interval::point(c[0]),
+        Interval::point(c[1]),
+        Interval::point(c[2]),
+    )
+}
+
+#[test]
+fn rounding_is_outward_and_tight() {
+    let a = Interval::point(0.1) + Interval::point(0.2);
+    assert!(a.lo() < a.hi());
+    assert!(a.contains(0.1 + 0.2));
+    assert_eq!(f64::next_up(a.lo()), a.hi());
+
+    assert_eq!(
+        Interval::point(1.0) + Interval::point(2.0),
+        Interval::point(3.0)
+    );
+    assert_eq!(
+        Interval::point(1.5) * Interval::point(-2.0),
+        Interval::point(-3.0)
+    );
+    assert_eq!(
+        Interval::point(1.0) / Interval::point(4.0),
+        Interval::point(0.25)
+    );
+    assert_eq!(Interval::point(9.0).sqrt(), Interval::point(3.0));
+
+    let third = Interval::point(1.0) / Interval::point(3.0);
+    assert_eq!(f64::next_up(third.lo()), third.hi());
+    assert!(third.lo() * 3.0 <= 1.0 && 1.0 <= third.hi() * 3.0);
+    let root = Interval::point(2.0).sqrt();
+    assert_eq!(f64::next_up(root.lo()), root.hi());
+    assert!(root.lo().mul_add(root.lo(), -2.0) < 0.0);
+    assert!(root.hi().mul_add(root.hi(), -2.0) > 0.0);
+}
+
+#[test]
+fn arithmetic_of_wide_intervals() {
+    let a = Interval::new(-1.0, 2.0);
+    let b = Interval::new(3.0, 4.0);
+    assert_eq!(a + b, Interval::new(2.0, 6.0));
+    assert_eq!(a - b, Interval::new(-5.0, -1.0));
+    assert_eq!(a * b, Interval::new(-4.0, 8.0));
+    assert_eq!(a * a, Interval::new(-2.0, 4.0));
+    assert_eq!(a.powi(2), Interval::new(0.0, 4.0));
+    assert_eq!(a.powi(3), Interval::new(-1.0, 8.0));
+    assert_eq!(a / Interval::new(2.0, 4.0), Interval::new(-0.5, 1.0));
+    assert_eq!(b / a, Interval::entire());
+    assert_eq!(-&a, Interval::new(-2.0, 1.0));
+    assert_eq!(a.abs(), Interval::new(0.0, 2.0));
+    let r = b.recip();
+    assert_eq!(r.lo(), 0.25);
+    assert!(r.contains(1.0 / 3.0) && r.hi() < 1.0 / 3.0 + 1e-15);
+    assert!((Interval::new(0.0, 1.0) * Interval::entire()).contains(0.0));
+    assert!(Interval::<f64>::nan().is_nan());
+    assert!((a + Interval::nan()).is_nan());
+}
+
+#[test]
+fn comparisons_are_certain() {
+    let a = Interval::new(0.0, 1.0);
+    let b = Interval::new(2.0, 3.0);
+    let c = Interval::new(0.5, 2.5);
+    assert!(a < b);
+    assert!(b > a);
+    assert!(a.partial_cmp(&c).is_none() && a != c);
+    assert_eq!(a.max(c), Interval::new(0.5, 2.5));
+    assert_eq!(a.min(c), Interval::new(0.0, 1.0));
+}
+
+#[test]
+fn elementary_functions_enclose_samples() {
+    let mut rng = Lcg(7);
+    let functions: [Function; 14] = [
+        (f64::exp, Interval::exp),
+        (f64::ln, Interval::ln),
+        (f64::sqrt, Interval::sqrt),
+        (f64::cbrt, Interval::cbrt),
+        (f64::sin, Interval::sin),
+        (f64::cos, Interval::cos),
+        (f64::atan, Interval::atan),
+        (f64::sinh, Interval::sinh),
+        (f64::cosh, Interval::cosh),
+        (f64::tanh, Interval::tanh),
+        (f64::exp_m1, Interval::exp_m1),
+        (|x| x.abs().ln_1p(), |x| x.abs().ln_1p()),
+        (|x| x.powi(4), |x| x.powi(4)),
+        (|x| x.powi(-3), |x| x.powi(-3)),
+    ];
+    for _ in 0..200 {
+        let a = rng.dyadic().1;
+        let b = rng.dyadic().1;
+        let x = Interval::hull(a, b);
+        for (f, g) in functions {
+            let y = g(x);
+            for t in 0..=16 {
+                let s = x.lo() + (x.hi() - x.lo()) * t as f64 / 16.0;
+                let v = f(s);
+                if !v.is_nan() {
+                    assert!(y.contains(v) || v.is_infinite(), "{} {} {}", x, y, v);
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn trigonometric_extrema() {
+    assert_eq!(Interval::new(1.0, 2.0).sin().hi(), 1.0);
+    assert_eq!(Interval::new(-0.1, 0.1).cos().hi(), 1.0);
+    assert_eq!(Interval::new(3.0, 3.5).cos().lo(), -1.0);
+    assert_eq!(Interval::new(0.0, 10.0).sin(), Interval::new(-1.0, 1.0));
+    assert!(Interval::new(0.1, 0.2).sin().hi() < 0.2);
+    assert_eq!(Interval::new(1.0, 2.0).tan(), Interval::entire());
+    assert!(Interval::new(-1.0, 1.0).tan().is_finite());
+    let angle = Interval::new(1.0, 2.0).atan2(Interval::new(-1.0, 1.0));
+    assert!(angle.contains(2f64.atan2(-1.0)) && angle.contains(1f64.atan2(1.0)));
+    assert!(angle.hi() < 3.0);
+    let cut = Interval::new(-1.0, 1.0).atan2(Interval::new(-2.0, -1.0));
+    assert!(cut.contains(std::f64::consts::PI) && cut.contains(-std::f64::consts::PI));
+}
+
+#[test]
+fn guaranteed_dot_norm_distance() {
+    let mut rng = Lcg(42);
+    for _ in 0..1000 {
+        let a: [(i128, f64); 3] = [rng.dyadic(), rng.dyadic(), rng.dyadic()];
+        let b: [(i128, f64); 3] = [rng.dyadic(), rng.dyadic(), rng.dyadic()];
+        let x = point3(a.map(|c| c.1));
+        let y = point3(b.map(|c| c.1));
+
+        // exact values scaled by 2^40 fit in 53 bits
+        let dot: i128 = (0..3).map(|i| a[i].0 * b[i].0).sum();
+        let norm_sqr: i128 = a.iter().map(|c| c.0 * c.0).sum();
+        let dist_sqr: i128 = (0..3).map(|i| (a[i].0 - b[i].0).pow(2)).sum();
+        let [dot, norm_sqr, dist_sqr] = [dot, norm_sqr, dist_sqr].map(|v| v as f64 / SCALE);
+
+        let d = x.dot(y);
+        assert!(d.contains(dot), "{} {}", d, dot);
+        assert!(d.width() <= 4.0 * f64::EPSILON * (1.0 + dot.abs()));
+        let n = x.norm_sqr();
+        assert!(n.contains(norm_sqr));
+        let r = x.distance(y);
+        assert!(r.lo().mul_add(r.lo(), -dist_sqr) <= 0.0);
+        assert!(r.hi().mul_add(r.hi(), -dist_sqr) >= 0.0);
+        assert!(r.width() <= 8.0 * f64::EPSILON * (1.0 + r.hi()));
+    }
+}
+
+#[test]
+fn enclosure_of_box() {
+    let mut rng = Lcg(3);
+    for _ in 0..100 {
+        let lo = Vec3D::new(rng.dyadic().1, rng.dyadic().1, rng.dyadic().1);
+        let hi = lo + Vec3D::new(0.25, 0.5, 1.0);
+        let b = Aabb::new(lo, hi);
+        let x: Vec3D<Interval<f64>> = b.to_intervals();
+        let y = point3([1.0, -2.0, 0.5]);
+        let d = x.dot(y);
+        let n = x.norm_sqr();
+        for t in 0..8 {
+            let p = Vec3D::new(
+                lo.x + 0.25 * (t & 1) as f64,
+                lo.y + 0.5 * ((t >> 1) & 1) as f64,
+                lo.z + 1.0 * ((t >> 2) & 1) as f64,
+            );
+            assert!(b.contains(p));
+            assert!(d.contains(p.dot(Vec3D::new(1.0, -2.0, 0.5))));
+            assert!(n.contains(p.norm_sqr()));
+        }
+    }
+}
+
+#[test]
+fn conversion_with_aabb() {
+    let b = Aabb::new(Vec2D::new(-1f32, 2f32), Vec2D::new(0f32, 5f32));
+    let v: Vec2D<Interval<f32>> = b.to_intervals();
+    assert_eq!(
+        v,
+        Vec2D::new(Interval::new(-1.0, 0.0), Interval::new(2.0, 5.0))
+    );
+    assert_eq!(Aabb::from_intervals(v), b);
+
+    let v = VecND::from([Interval::new(1.0, 2.0), Interval::point(3.0)]);
+    let b: Aabb<VecND<f64, 2>> = Aabb::from_intervals(v);
+    assert_eq!(b.min, VecND::from([1.0, 3.0]));
+    assert_eq!(b.to_intervals::<VecND<Interval<f64>, 2>>(), v);
+}
+
+#[test]
+#[should_panic]
+fn to_intervals_of_invalid_box() {
+    let b = Aabb::new(Vec2D::new(1.0, 0.0), Vec2D::new(0.0, 1.0));
+    let _: Vec2D<Interval<f64>> = b.to_intervals();
+}
+
+#[test]
+fn display() {
+    assert_eq!(Interval::new(1.5, 2.0).to_string(), "[1.5, 2]");
+}
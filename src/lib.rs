@@ -11,11 +11,45 @@
 //!
 //! + `serde`: activates serialization of [`Vec2D`], [`Vec3D`] etc.
 //! + `serde_arrays`: needed to serialize/deserialize [`VecND`]
+//! + `half`: activates 16-bit float components `half::f16` and `half::bf16`
+//!   with conversions to `f32`
 //!
+pub mod autodiff;
+pub mod calculus;
+
+mod aabb;
+mod angle;
 mod array;
+mod barnes_hut;
+mod components;
+mod decomposition;
+mod dual;
+mod dual_quat;
+mod eigen3;
+mod euler;
 mod fixed;
+mod fixed_point;
+#[cfg(feature = "half")]
+mod float16;
+mod four_vector;
 mod general;
+mod geometric_algebra;
+mod integrate;
+mod interval;
+mod lie;
+mod matrix;
+mod metric;
+mod ode;
 mod ops;
+mod orthonormal;
+mod rot2;
+mod space;
+mod summation;
+mod tensor;
+#[cfg(test)]
+mod testing;
+mod transform;
+mod tree;
 
 use std::fmt::Debug;
 use std::iter::Sum;
@@ -23,9 +57,37 @@ use std::iter::Sum;
 use num_traits::Float;
 use num_traits::Zero;
 
+use crate::components::Components;
+
+pub use crate::aabb::*;
+pub use crate::angle::*;
+pub use crate::barnes_hut::*;
+pub use crate::decomposition::*;
+pub use crate::dual::*;
+pub use crate::dual_quat::*;
+pub use crate::eigen3::*;
+pub use crate::euler::*;
 pub use crate::fixed::*;
+pub use crate::fixed_point::*;
+#[cfg(feature = "half")]
+pub use crate::float16::*;
+pub use crate::four_vector::*;
 pub use crate::general::*;
+pub use crate::geometric_algebra::*;
+pub use crate::integrate::*;
+pub use crate::interval::*;
+pub use crate::lie::*;
+pub use crate::matrix::*;
+pub use crate::metric::*;
+pub use crate::ode::*;
 pub use crate::ops::*;
+pub use crate::orthonormal::*;
+pub use crate::rot2::*;
+pub use crate::space::*;
+pub use crate::summation::*;
+pub use crate::tensor::*;
+pub use crate::transform::*;
+pub use crate::tree::*;
 
 /// 1-dimensional vector with 64bit component
 pub type V1D64 = Vec1D<f64>;
@@ -41,46 +103,100 @@ pub type V3D64 = Vec3D<f64>;
 pub type V3D32 = Vec3D<f32>;
 
 /// Fixed-size vector.
+///
+/// Methods that access individual components, such as
+/// [`norm_l1`](Vector::norm_l1), are available when the vector also
+/// implements [`Index<usize>`](std::ops::Index) and
+/// [`IndexMut<usize>`](std::ops::IndexMut) as all vectors of this crate do.
+///
+/// The Euclidean space of the dimension: the inner product is the dot
+/// product, the norm is the L2-norm and the distance is the euclid distance.
 pub trait Vector:
-    Clone
-    + Copy
+    Copy
     + Debug
     + PartialEq
-    + Zero
     + Sum
+    + VectorSpace<Scalar = <Self as Vector>::Cmp>
+    + InnerProductSpace
+    + NormedSpace
+    + MetricSpace
     + VectorRefOps<Self::Cmp, Self>
     + VectorRefAssignOps<Self::Cmp, Self>
 where
     for<'l> &'l Self: VectorRefOps<Self::Cmp, Self>,
 {
-    /// Component scalar type of the vector.
+    /// Component scalar type of the vector, the same as
+    /// [`Scalar`](VectorSpace::Scalar).
     type Cmp: Component;
 
     /// Dimension of the vector.
     const DIM: usize;
 
-    /// The square of the L2-norm.
-    fn norm_sqr(self) -> Self::Cmp;
-
-    /// The dot product with another vector.
-    fn dot(self, rhs: Self) -> Self::Cmp;
-
     /// Checks if all components are finite.
     fn is_finite(self) -> bool;
 
     /// Checks if any of the components is NaN.
     fn has_nan(self) -> bool;
 
-    /// The L2-norm.
+    /// The dot product as accurate as if computed in twice the working
+    /// precision, using FMA-based error-free transformations (Dot2).
+    #[inline]
+    fn dot_accurate(self, rhs: Self) -> Self::Cmp
+    where
+        Self: Components,
+    {
+        crate::summation::dot2(&self, &rhs)
+    }
+
+    /// The L1-norm, i.e. the sum of the absolute components.
     #[inline]
-    fn norm(self) -> Self::Cmp {
-        self.norm_sqr().sqrt()
+    fn norm_l1(self) -> Self::Cmp
+    where
+        Self: Components,
+    {
+        (0..Self::DIM).map(|i| self[i].abs()).sum()
     }
 
-    /// The euclid distance from the another vector.
+    /// The L∞-norm, i.e. the largest absolute component.
+    ///
+    /// NaN if any component is NaN.
     #[inline]
-    fn distance(self, rhs: Self) -> Self::Cmp {
-        (self - rhs).norm()
+    fn norm_inf(self) -> Self::Cmp
+    where
+        Self: Components,
+    {
+        if self.has_nan() {
+            return Self::Cmp::nan();
+        }
+        max_abs(&self)
+    }
+
+    /// The Lp-norm `(Σ|x|^p)^(1/p)`, scaled by the largest absolute component
+    /// so that the powers neither overflow nor underflow.
+    ///
+    /// `p` is expected to be at least one, and infinite `p` gives the L∞-norm.
+    fn norm_lp(self, p: Self::Cmp) -> Self::Cmp
+    where
+        Self: Components,
+    {
+        let m = self.norm_inf();
+        if p.is_infinite() || m.is_zero() || m.is_infinite() || m.is_nan() {
+            return m;
+        }
+        let s: Self::Cmp = (0..Self::DIM).map(|i| (self[i].abs() / m).powf(p)).sum();
+        s.powf(p.recip()) * m
+    }
+
+    /// The weighted L2-norm `sqrt(Σ w x²)`.
+    #[inline]
+    fn norm_weighted(self, weights: Self) -> Self::Cmp
+    where
+        Self: Components,
+    {
+        (0..Self::DIM)
+            .map(|i| weights[i] * self[i] * self[i])
+            .sum::<Self::Cmp>()
+            .sqrt()
     }
 
     /// The square of the euclid distance from the another vector.
@@ -88,4 +204,74 @@ where
     fn distance_sqr(self, rhs: Self) -> Self::Cmp {
         (self - rhs).norm_sqr()
     }
+
+    /// The L2-norm computed with scaling like `hypot`,
+    /// so that the squares neither overflow nor underflow.
+    ///
+    /// Infinite if any component is infinite, otherwise NaN if any is NaN.
+    fn norm_stable(self) -> Self::Cmp
+    where
+        Self: Components,
+    {
+        let m = max_abs(&self);
+        if m.is_infinite() {
+            return m;
+        }
+        let scale = norm_scale(m);
+        (self * scale).norm() / scale
+    }
+
+    /// The euclid distance from the another vector without overflow or
+    /// underflow of the squares, see [`norm_stable`](Vector::norm_stable).
+    #[inline]
+    fn distance_stable(self, rhs: Self) -> Self::Cmp
+    where
+        Self: Components,
+    {
+        (self - rhs).norm_stable()
+    }
+
+    /// The unit vector in the same direction, scaled in advance like
+    /// [`norm_stable`](Vector::norm_stable) so that any finite non-zero
+    /// vector can be normalized.
+    ///
+    /// Components are NaN if the vector is zero or not finite.
+    fn normalize(self) -> Self
+    where
+        Self: Components,
+    {
+        let v = self * norm_scale(max_abs(&self));
+        v / v.norm()
+    }
+}
+
+/// The largest absolute component ignoring NaN.
+fn max_abs<V: Components>(v: &V) -> V::Cmp
+where
+    for<'l> &'l V: VectorRefOps<V::Cmp, V>,
+{
+    (0..V::DIM).fold(V::Cmp::zero(), |m, i| m.max(v[i].abs()))
+}
+
+/// A power of two to scale a vector whose largest absolute component is `m`,
+/// so that the sum of the squares is neither overflow nor underflow.
+///
+/// One unless the squares are out of the range of normal numbers, or if the
+/// scale itself is not representable, e.g. for fixed-point components without
+/// a floating exponent.
+fn norm_scale<T: Float>(m: T) -> T {
+    let eps = T::epsilon();
+    let tiny = T::min_positive_value();
+    let scale = if m > (T::max_value() * eps).sqrt() {
+        tiny.sqrt() * eps
+    } else if m < tiny.sqrt() {
+        eps / tiny
+    } else {
+        T::one()
+    };
+    if scale.is_zero() || !scale.is_finite() {
+        T::one()
+    } else {
+        scale
+    }
 }
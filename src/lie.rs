@@ -0,0 +1,858 @@
+#[cfg(test)]
+mod tests;
+
+use std::array;
+use std::ops::Mul;
+use std::ops::MulAssign;
+
+use num_traits::Float;
+use num_traits::Zero;
+#[cfg(feature = "serde")]
+use serde::Deserialize;
+#[cfg(feature = "serde")]
+use serde::Serialize;
+
+use crate::Affine2;
+use crate::Affine3;
+use crate::Component;
+use crate::InnerProductSpace;
+use crate::Isometry2;
+use crate::Isometry3;
+use crate::Rad;
+use crate::RefComponent;
+use crate::Rot2;
+use crate::Rotor3;
+use crate::Vec2D;
+use crate::Vec3D;
+use crate::VecND;
+
+/// The special orthogonal group SO(2) of rotations of the plane.
+///
+/// The tangent space is the angle.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct So2<T> {
+    /// The rotation as the unit complex number.
+    pub rotation: Rot2<T>,
+}
+
+/// The special orthogonal group SO(3) of rotations of the space.
+///
+/// The tangent space is the rotation vector `φ`, the axis scaled by the
+/// angle counterclockwise.
+///
+/// # Examples
+///
+/// ```
+/// use ::ndvec::*;
+///
+/// let phi = Vec3D::new(0.3, -0.2, 0.1);
+/// let r = So3::exp(phi);
+/// assert!(r.log().distance(phi) < 1e-16);
+/// // a small perturbation on the left
+/// let d = Vec3D::new(1e-8, 0.0, 0.0);
+/// let e = (So3::exp(phi + d) * r.inverse()).log();
+/// let j = So3::left_jacobian(phi);
+/// let jd = j[0] * d.x + j[1] * d.y + j[2] * d.z;
+/// assert!(e.distance(jd) < 1e-14);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct So3<T> {
+    /// The rotor of the rotation, expected to be of the unit norm.
+    pub rotor: Rotor3<T>,
+}
+
+/// The special Euclidean group SE(2) of rigid motions of the plane.
+///
+/// The tangent space is the twist `(ρx, ρy, θ)` of the translational
+/// part `ρ` and the angle `θ`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Se2<T> {
+    /// The rotation applied first.
+    pub rotation: So2<T>,
+    /// The translation applied after the rotation.
+    pub translation: Vec2D<T>,
+}
+
+/// The special Euclidean group SE(3) of rigid motions of the space.
+///
+/// The tangent space is the twist `(ρ, φ)` of six components: the
+/// translational part `ρ` followed by the rotation vector `φ`.
+///
+/// # Examples
+///
+/// ```
+/// use ::ndvec::*;
+///
+/// // integrate a constant twist for a second in ten steps
+/// let twist = VecND::from([1.0, 0.0, 0.0, 0.0, 0.0, 0.5]);
+/// let mut pose = Se3::identity();
+/// for _ in 0..10 {
+///     pose = pose.plus(twist * 0.1);
+/// }
+/// assert!(pose.minus(Se3::exp(twist)).norm() < 1e-15);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Se3<T> {
+    /// The rotation applied first.
+    pub rotation: So3<T>,
+    /// The translation applied after the rotation.
+    pub translation: Vec3D<T>,
+}
+
+/// The floating point constant.
+#[inline]
+fn constant<T: Float>(x: f64) -> T {
+    T::from(x).expect("constants must be representable")
+}
+
+/// Checks if `θ²` is so small that the closed forms of the coefficients
+/// lose digits by cancellation, about 0.1 for `f64`, and the Taylor series
+/// truncated after the `θ⁸` terms is accurate instead.
+#[inline]
+fn is_small<T: Float>(theta2: T) -> bool {
+    theta2 < T::epsilon().powf(constant(1.0 / 16.0))
+}
+
+/// The Taylor series `c[0] + c[1] θ² + ... + c[4] θ⁸`.
+#[inline]
+fn series<T: Float>(theta2: T, c: [f64; 5]) -> T {
+    c.into_iter()
+        .rev()
+        .fold(T::zero(), |s, c| s * theta2 + constant(c))
+}
+
+/// Taylor coefficients of `sin(θ/2) / θ` in `θ²`.
+const HALF_SINC: [f64; 5] = [
+    1.0 / 2.0,
+    -1.0 / 48.0,
+    1.0 / 3840.0,
+    -1.0 / 645120.0,
+    1.0 / 185794560.0,
+];
+
+/// Taylor coefficients of `(θ - sin θ) / θ³` in `θ²`.
+const SIN_REMAINDER: [f64; 5] = [
+    1.0 / 6.0,
+    -1.0 / 120.0,
+    1.0 / 5040.0,
+    -1.0 / 362880.0,
+    1.0 / 39916800.0,
+];
+
+/// Taylor coefficients of `(1 - (θ/2) cot(θ/2)) / θ²` in `θ²`.
+const COT_REMAINDER: [f64; 5] = [
+    1.0 / 12.0,
+    1.0 / 720.0,
+    1.0 / 30240.0,
+    1.0 / 1209600.0,
+    1.0 / 47900160.0,
+];
+
+/// Taylor coefficients of `(θ² + 2 cos θ - 2) / 2θ⁴` in `θ²`.
+const Q_COS: [f64; 5] = [
+    1.0 / 24.0,
+    -1.0 / 720.0,
+    1.0 / 40320.0,
+    -1.0 / 3628800.0,
+    1.0 / 479001600.0,
+];
+
+/// Taylor coefficients of `(2θ - 3 sin θ + θ cos θ) / 2θ⁵` in `θ²`.
+const Q_SIN: [f64; 5] = [
+    1.0 / 120.0,
+    -1.0 / 2520.0,
+    1.0 / 120960.0,
+    -1.0 / 9979200.0,
+    1.0 / 1245404160.0,
+];
+
+/// `sin(θ/2) / θ`, which is one half at zero.
+fn half_sinc<T: Float>(theta2: T) -> T {
+    if is_small(theta2) {
+        series(theta2, HALF_SINC)
+    } else {
+        let theta = theta2.sqrt();
+        (theta / (T::one() + T::one())).sin() / theta
+    }
+}
+
+/// The coefficients `(1 - cos θ) / θ²` and `(θ - sin θ) / θ³`.
+fn coefficients<T: Float>(theta2: T) -> (T, T) {
+    // 1 - cos θ = 2 sin²(θ/2) without cancellation
+    let h = half_sinc(theta2);
+    let b = if is_small(theta2) {
+        series(theta2, SIN_REMAINDER)
+    } else {
+        let theta = theta2.sqrt();
+        (theta - theta.sin()) / (theta2 * theta)
+    };
+    ((h + h) * h, b)
+}
+
+/// The product of a 3×3 matrix of the columns and a vector.
+#[inline]
+pub(crate) fn mat_vec<T: Component>(m: &[Vec3D<T>; 3], v: Vec3D<T>) -> Vec3D<T>
+where
+    for<'a> &'a T: RefComponent<T>,
+{
+    m[0] * v.x + m[1] * v.y + m[2] * v.z
+}
+
+/// The product of 3×3 matrices of the columns.
+#[inline]
+fn mat_mul<T: Component>(a: &[Vec3D<T>; 3], b: [Vec3D<T>; 3]) -> [Vec3D<T>; 3]
+where
+    for<'a> &'a T: RefComponent<T>,
+{
+    b.map(|c| mat_vec(a, c))
+}
+
+/// The 3×3 identity matrix.
+fn identity<T: Component>() -> [Vec3D<T>; 3]
+where
+    for<'a> &'a T: RefComponent<T>,
+{
+    let (o, l) = (T::zero(), T::one());
+    [
+        Vec3D::new(l, o, o),
+        Vec3D::new(o, l, o),
+        Vec3D::new(o, o, l),
+    ]
+}
+
+/// The twist of the translational and rotational parts.
+#[inline]
+fn join<T: Copy>(rho: Vec3D<T>, phi: Vec3D<T>) -> VecND<T, 6> {
+    VecND::from([rho.x, rho.y, rho.z, phi.x, phi.y, phi.z])
+}
+
+/// The translational and rotational parts of the twist.
+#[inline]
+fn split<T: Copy>(tau: VecND<T, 6>) -> (Vec3D<T>, Vec3D<T>) {
+    (
+        Vec3D::new(tau[0], tau[1], tau[2]),
+        Vec3D::new(tau[3], tau[4], tau[5]),
+    )
+}
+
+/// The 6×6 matrix `[[a, b], [0, d]]` of the 3×3 blocks.
+fn upper_blocks<T: Component>(
+    a: [Vec3D<T>; 3],
+    b: [Vec3D<T>; 3],
+    d: [Vec3D<T>; 3],
+) -> [VecND<T, 6>; 6]
+where
+    for<'a> &'a T: RefComponent<T>,
+{
+    array::from_fn(|j| {
+        if j < 3 {
+            join(a[j], Vec3D::zero())
+        } else {
+            join(b[j - 3], d[j - 3])
+        }
+    })
+}
+
+impl<T: Component> So2<T>
+where
+    for<'a> &'a T: RefComponent<T>,
+{
+    /// The rotation of the unit complex number.
+    #[inline]
+    pub const fn new(rotation: Rot2<T>) -> Self {
+        Self { rotation }
+    }
+
+    /// The rotation of no angle.
+    pub fn identity() -> Self {
+        Self::new(Rot2::identity())
+    }
+
+    /// The exponential map: the rotation by the angle counterclockwise.
+    pub fn exp(theta: T) -> Self {
+        Self::new(Rot2::new(Rad(theta)))
+    }
+
+    /// The logarithmic map: the angle in `[-π, π]`.
+    pub fn log(self) -> T {
+        self.rotation.angle().0
+    }
+
+    /// The rotation by the angle counterclockwise, the same as
+    /// [`exp`](So2::exp) of the angle in radians.
+    pub fn from_angle(angle: Rad<T>) -> Self {
+        Self::exp(angle.0)
+    }
+
+    /// The angle in `[-π, π]`, the same as [`log`](So2::log) in radians.
+    pub fn angle(self) -> Rad<T> {
+        Rad(self.log())
+    }
+
+    /// The skew-symmetric matrix of the angle, as columns.
+    pub fn hat(theta: T) -> [Vec2D<T>; 2] {
+        [Vec2D::new(T::zero(), theta), Vec2D::new(-theta, T::zero())]
+    }
+
+    /// The angle of the skew-symmetric matrix, the inverse of [`hat`](So2::hat).
+    pub fn vee(m: [Vec2D<T>; 2]) -> T {
+        m[0].y
+    }
+
+    /// The inverse rotation.
+    pub fn inverse(self) -> Self {
+        Self::new(self.rotation.inverse())
+    }
+
+    /// Rotates the vector.
+    #[inline]
+    pub fn rotate(self, v: Vec2D<T>) -> Vec2D<T> {
+        self.rotation.rotate(v)
+    }
+
+    /// The rotation matrix, as columns.
+    pub fn matrix(self) -> [Vec2D<T>; 2] {
+        self.rotation.matrix()
+    }
+
+    /// The adjoint, which is one since rotations of the plane commute.
+    pub fn adjoint(self) -> T {
+        T::one()
+    }
+
+    /// The left Jacobian, which is one since rotations of the plane commute.
+    pub fn left_jacobian(_theta: T) -> T {
+        T::one()
+    }
+
+    /// The inverse of the left Jacobian, which is one.
+    pub fn left_jacobian_inverse(_theta: T) -> T {
+        T::one()
+    }
+}
+
+impl<T: Component> So3<T>
+where
+    for<'a> &'a T: RefComponent<T>,
+{
+    /// The rotation of the rotor.
+    #[inline]
+    pub const fn new(rotor: Rotor3<T>) -> Self {
+        Self { rotor }
+    }
+
+    /// The rotation of no angle.
+    pub fn identity() -> Self {
+        Self::new(Rotor3::identity())
+    }
+
+    /// The exponential map: the rotation by `|φ|` around the axis of `φ`.
+    pub fn exp(phi: Vec3D<T>) -> Self {
+        let theta2 = phi.norm_sqr();
+        let theta = theta2.sqrt();
+        let cos = (theta / (T::one() + T::one())).cos();
+        let k = half_sinc(theta2);
+        Self::new(Rotor3::new(cos, -phi * k))
+    }
+
+    /// The logarithmic map: the rotation vector of the angle in `[0, π]`.
+    pub fn log(self) -> Vec3D<T> {
+        // `R` and `-R` are the same rotation
+        let r = if self.rotor.s < T::zero() {
+            -self.rotor
+        } else {
+            self.rotor
+        };
+        let n = r.b.norm_sqr().sqrt();
+        let k = if n.is_zero() {
+            (T::one() + T::one()) / r.s
+        } else {
+            (T::one() + T::one()) * n.atan2(r.s) / n
+        };
+        -r.b * k
+    }
+
+    /// The skew-symmetric matrix `[φ]×` of the cross product, as columns.
+    pub fn hat(phi: Vec3D<T>) -> [Vec3D<T>; 3] {
+        let o = T::zero();
+        [
+            Vec3D::new(o, phi.z, -phi.y),
+            Vec3D::new(-phi.z, o, phi.x),
+            Vec3D::new(phi.y, -phi.x, o),
+        ]
+    }
+
+    /// The vector of the skew-symmetric matrix, the inverse of [`hat`](So3::hat).
+    pub fn vee(m: [Vec3D<T>; 3]) -> Vec3D<T> {
+        Vec3D::new(m[1].z, m[2].x, m[0].y)
+    }
+
+    /// The inverse rotation.
+    pub fn inverse(self) -> Self {
+        Self::new(self.rotor.reverse())
+    }
+
+    /// Rotates the vector.
+    #[inline]
+    pub fn rotate(self, v: Vec3D<T>) -> Vec3D<T> {
+        self.rotor.rotate(v)
+    }
+
+    /// The rotation matrix, as columns.
+    pub fn matrix(self) -> [Vec3D<T>; 3] {
+        identity().map(|e| self.rotate(e))
+    }
+
+    /// The adjoint `Ad_R = R`, which maps `φ` to `R φ` so that
+    /// `R exp(φ) R⁻¹ = exp(R φ)`.
+    pub fn adjoint(self) -> [Vec3D<T>; 3] {
+        self.matrix()
+    }
+
+    /// The left Jacobian `J_l(φ)` such that
+    /// `exp(φ + δ) ≈ exp(J_l(φ) δ) exp(φ)` for small `δ`.
+    pub fn left_jacobian(phi: Vec3D<T>) -> [Vec3D<T>; 3] {
+        let (a, b) = coefficients(phi.norm_sqr());
+        let p = Self::hat(phi);
+        let pp = mat_mul(&p, p);
+        let e = identity();
+        array::from_fn(|i| e[i] + p[i] * a + pp[i] * b)
+    }
+
+    /// The inverse of the [`left_jacobian`](So3::left_jacobian).
+    pub fn left_jacobian_inverse(phi: Vec3D<T>) -> [Vec3D<T>; 3] {
+        let theta2 = phi.norm_sqr();
+        // (1 - (θ/2) cot(θ/2)) / θ²
+        let c = if is_small(theta2) {
+            series(theta2, COT_REMAINDER)
+        } else {
+            let half = theta2.sqrt() / (T::one() + T::one());
+            (T::one() - half / half.tan()) / theta2
+        };
+        let p = Self::hat(phi);
+        let pp = mat_mul(&p, p);
+        let e = identity();
+        let half = (T::one() + T::one()).recip();
+        array::from_fn(|i| e[i] - p[i] * half + pp[i] * c)
+    }
+}
+
+impl<T: Component> Se2<T>
+where
+    for<'a> &'a T: RefComponent<T>,
+{
+    /// The rotation, then the translation.
+    #[inline]
+    pub fn new(rotation: So2<T>, translation: Vec2D<T>) -> Self {
+        Self {
+            rotation,
+            translation,
+        }
+    }
+
+    /// The motion of no rotation or translation.
+    pub fn identity() -> Self {
+        Self::new(So2::identity(), Vec2D::zero())
+    }
+
+    /// The coefficients `sin θ / θ` and `(1 - cos θ) / θ` of `V(θ)`, where
+    /// `exp(ρ, θ)` translates by `V(θ) ρ`, followed by [`coefficients`].
+    fn v_coefficients(theta: T) -> (T, T, T, T) {
+        let (a, b) = coefficients(theta * theta);
+        (T::one() - theta * theta * b, theta * a, a, b)
+    }
+
+    /// The exponential map of the twist `(ρx, ρy, θ)`.
+    pub fn exp(tau: Vec3D<T>) -> Self {
+        let (sa, cb, _, _) = Self::v_coefficients(tau.z);
+        let translation = Vec2D::new(sa * tau.x - cb * tau.y, cb * tau.x + sa * tau.y);
+        Self::new(So2::exp(tau.z), translation)
+    }
+
+    /// The logarithmic map to the twist `(ρx, ρy, θ)` with `θ` in `[-π, π]`.
+    pub fn log(self) -> Vec3D<T> {
+        let theta = self.rotation.log();
+        let (sa, cb, _, _) = Self::v_coefficients(theta);
+        let t = self.translation;
+        let d = sa * sa + cb * cb;
+        Vec3D::new((sa * t.x + cb * t.y) / d, (sa * t.y - cb * t.x) / d, theta)
+    }
+
+    /// The 3×3 matrix of the twist as an affine map of the linear part
+    /// [`So2::hat`] of the angle and the translation `ρ`.
+    pub fn hat(tau: Vec3D<T>) -> Affine2<T> {
+        Affine2::new(So2::hat(tau.z), Vec2D::new(tau.x, tau.y))
+    }
+
+    /// The twist of the matrix, the inverse of [`hat`](Se2::hat).
+    pub fn vee(m: Affine2<T>) -> Vec3D<T> {
+        Vec3D::new(m.translation.x, m.translation.y, So2::vee(m.linear))
+    }
+
+    /// The inverse motion.
+    pub fn inverse(self) -> Self {
+        let rotation = self.rotation.inverse();
+        Self::new(rotation, -rotation.rotate(self.translation))
+    }
+
+    /// Rotates and translates the point.
+    #[inline]
+    pub fn transform_point(self, p: Vec2D<T>) -> Vec2D<T> {
+        self.rotation.rotate(p) + self.translation
+    }
+
+    /// Rotates the direction, ignoring the translation.
+    #[inline]
+    pub fn transform_vector(self, v: Vec2D<T>) -> Vec2D<T> {
+        self.rotation.rotate(v)
+    }
+
+    /// The homogeneous 3×3 matrix as an affine map.
+    pub fn matrix(self) -> Affine2<T> {
+        Affine2::new(self.rotation.matrix(), self.translation)
+    }
+
+    /// The adjoint, which maps a twist `τ` to the twist of
+    /// `X exp(τ) X⁻¹ = exp(Ad_X τ)`, as columns.
+    pub fn adjoint(self) -> [Vec3D<T>; 3] {
+        let [c0, c1] = self.rotation.matrix();
+        let t = self.translation;
+        let o = T::zero();
+        [
+            Vec3D::new(c0.x, c0.y, o),
+            Vec3D::new(c1.x, c1.y, o),
+            Vec3D::new(t.y, -t.x, T::one()),
+        ]
+    }
+
+    /// The left Jacobian `J_l(τ)` such that
+    /// `exp(τ + δ) ≈ exp(J_l(τ) δ) exp(τ)` for small `δ`, as columns.
+    pub fn left_jacobian(tau: Vec3D<T>) -> [Vec3D<T>; 3] {
+        let theta = tau.z;
+        let (sa, cb, a, b) = Self::v_coefficients(theta);
+        let o = T::zero();
+        [
+            Vec3D::new(sa, cb, o),
+            Vec3D::new(-cb, sa, o),
+            Vec3D::new(
+                theta * b * tau.x + a * tau.y,
+                theta * b * tau.y - a * tau.x,
+                T::one(),
+            ),
+        ]
+    }
+
+    /// The inverse of the [`left_jacobian`](Se2::left_jacobian).
+    pub fn left_jacobian_inverse(tau: Vec3D<T>) -> [Vec3D<T>; 3] {
+        let [m0, _, c] = Self::left_jacobian(tau);
+        // [[M, c], [0, 1]]⁻¹ = [[M⁻¹, -M⁻¹ c], [0, 1]] of M = [[sa, -cb], [cb, sa]]
+        let d = m0.x * m0.x + m0.y * m0.y;
+        let (i0, i1) = (
+            Vec3D::new(m0.x, -m0.y, T::zero()) / d,
+            Vec3D::new(m0.y, m0.x, T::zero()) / d,
+        );
+        let ic = -(i0 * c.x + i1 * c.y);
+        [i0, i1, Vec3D::new(ic.x, ic.y, T::one())]
+    }
+}
+
+impl<T: Component> Se3<T>
+where
+    for<'a> &'a T: RefComponent<T>,
+{
+    /// The rotation, then the translation.
+    #[inline]
+    pub fn new(rotation: So3<T>, translation: Vec3D<T>) -> Self {
+        Self {
+            rotation,
+            translation,
+        }
+    }
+
+    /// The motion of no rotation or translation.
+    pub fn identity() -> Self {
+        Self::new(So3::identity(), Vec3D::zero())
+    }
+
+    /// The exponential map of the twist `(ρ, φ)`.
+    pub fn exp(tau: VecND<T, 6>) -> Self {
+        let (rho, phi) = split(tau);
+        Self::new(So3::exp(phi), mat_vec(&So3::left_jacobian(phi), rho))
+    }
+
+    /// The logarithmic map to the twist `(ρ, φ)` with the angle in `[0, π]`.
+    pub fn log(self) -> VecND<T, 6> {
+        let phi = self.rotation.log();
+        let rho = mat_vec(&So3::left_jacobian_inverse(phi), self.translation);
+        join(rho, phi)
+    }
+
+    /// The 4×4 matrix of the twist as an affine map of the linear part
+    /// [`So3::hat`] of `φ` and the translation `ρ`.
+    pub fn hat(tau: VecND<T, 6>) -> Affine3<T> {
+        let (rho, phi) = split(tau);
+        Affine3::new(So3::hat(phi), rho)
+    }
+
+    /// The twist of the matrix, the inverse of [`hat`](Se3::hat).
+    pub fn vee(m: Affine3<T>) -> VecND<T, 6> {
+        join(m.translation, So3::vee(m.linear))
+    }
+
+    /// The inverse motion.
+    pub fn inverse(self) -> Self {
+        let rotation = self.rotation.inverse();
+        Self::new(rotation, -rotation.rotate(self.translation))
+    }
+
+    /// Rotates and translates the point.
+    #[inline]
+    pub fn transform_point(self, p: Vec3D<T>) -> Vec3D<T> {
+        self.rotation.rotate(p) + self.translation
+    }
+
+    /// Rotates the direction, ignoring the translation.
+    #[inline]
+    pub fn transform_vector(self, v: Vec3D<T>) -> Vec3D<T> {
+        self.rotation.rotate(v)
+    }
+
+    /// The homogeneous 4×4 matrix as an affine map.
+    pub fn matrix(self) -> Affine3<T> {
+        Affine3::new(self.rotation.matrix(), self.translation)
+    }
+
+    /// The adjoint `[[R, [t]× R], [0, R]]`, which maps a twist `τ` to the
+    /// twist of `X exp(τ) X⁻¹ = exp(Ad_X τ)`, as columns.
+    pub fn adjoint(self) -> [VecND<T, 6>; 6] {
+        let r = self.rotation.matrix();
+        let tr = mat_mul(&So3::hat(self.translation), r);
+        upper_blocks(r, tr, r)
+    }
+
+    /// The block `Q(ρ, φ)` of the left Jacobian coupling the rotation into
+    /// the translation.
+    fn q_matrix(rho: Vec3D<T>, phi: Vec3D<T>) -> [Vec3D<T>; 3] {
+        let theta2 = phi.norm_sqr();
+        let (_, b) = coefficients(theta2);
+        // (θ² + 2 cos θ - 2) / 2θ⁴ and (2θ - 3 sin θ + θ cos θ) / 2θ⁵
+        let (d, e) = if is_small(theta2) {
+            (series(theta2, Q_COS), series(theta2, Q_SIN))
+        } else {
+            let theta = theta2.sqrt();
+            let (sin, cos) = theta.sin_cos();
+            let two = T::one() + T::one();
+            let theta4 = theta2 * theta2;
+            (
+                (theta2 + two * cos - two) / (two * theta4),
+                (two * theta - (two + T::one()) * sin + theta * cos) / (two * theta4 * theta),
+            )
+        };
+        let p = So3::hat(phi);
+        let r = So3::hat(rho);
+        let pr = mat_mul(&p, r);
+        let rp = mat_mul(&r, p);
+        let prp = mat_mul(&pr, p);
+        let ppr = mat_mul(&p, pr);
+        let rpp = mat_mul(&rp, p);
+        let prpp = mat_mul(&prp, p);
+        let pprp = mat_mul(&p, prp);
+        let (half, three) = (constant::<T>(0.5), constant::<T>(3.0));
+        array::from_fn(|i| {
+            r[i] * half
+                + (pr[i] + rp[i] + prp[i]) * b
+                + (ppr[i] + rpp[i] - prp[i] * three) * d
+                + (prpp[i] + pprp[i]) * e
+        })
+    }
+
+    /// The left Jacobian `J_l(τ) = [[J_l(φ), Q(ρ, φ)], [0, J_l(φ)]]` such that
+    /// `exp(τ + δ) ≈ exp(J_l(τ) δ) exp(τ)` for small `δ`, as columns.
+    pub fn left_jacobian(tau: VecND<T, 6>) -> [VecND<T, 6>; 6] {
+        let (rho, phi) = split(tau);
+        let j = So3::left_jacobian(phi);
+        upper_blocks(j, Self::q_matrix(rho, phi), j)
+    }
+
+    /// The inverse of the [`left_jacobian`](Se3::left_jacobian),
+    /// `[[J⁻¹, -J⁻¹ Q J⁻¹], [0, J⁻¹]]`.
+    pub fn left_jacobian_inverse(tau: VecND<T, 6>) -> [VecND<T, 6>; 6] {
+        let (rho, phi) = split(tau);
+        let ji = So3::left_jacobian_inverse(phi);
+        let q = mat_mul(&ji, mat_mul(&Self::q_matrix(rho, phi), ji));
+        upper_blocks(ji, q.map(|c| -c), ji)
+    }
+}
+
+macro_rules! impl_lie_group {
+    ($G:ident, $Tangent:ty, $Jacobian:ty) => {
+        impl<T: Component> $G<T>
+        where
+            for<'a> &'a T: RefComponent<T>,
+        {
+            /// The right Jacobian `J_r(τ) = J_l(-τ)` such that
+            /// `exp(τ + δ) ≈ exp(τ) exp(J_r(τ) δ)` for small `δ`.
+            pub fn right_jacobian(tau: $Tangent) -> $Jacobian {
+                Self::left_jacobian(-tau)
+            }
+
+            /// The inverse of the right Jacobian.
+            pub fn right_jacobian_inverse(tau: $Tangent) -> $Jacobian {
+                Self::left_jacobian_inverse(-tau)
+            }
+
+            /// The right plus `X ⊕ τ = X exp(τ)`, e.g. a step by the twist
+            /// in the local frame.
+            pub fn plus(self, tau: $Tangent) -> Self {
+                self * Self::exp(tau)
+            }
+
+            /// The right minus `X ⊖ Y = log(Y⁻¹ X)`, e.g. the error of the
+            /// pose `X` from `Y` in the local frame of `Y`.
+            pub fn minus(self, other: Self) -> $Tangent {
+                (other.inverse() * self).log()
+            }
+        }
+
+        impl<T: Component> MulAssign for $G<T>
+        where
+            for<'a> &'a T: RefComponent<T>,
+        {
+            #[inline]
+            fn mul_assign(&mut self, rhs: Self) {
+                *self = *self * rhs;
+            }
+        }
+    };
+}
+
+impl_lie_group!(So2, T, T);
+impl_lie_group!(So3, Vec3D<T>, [Vec3D<T>; 3]);
+impl_lie_group!(Se2, Vec3D<T>, [Vec3D<T>; 3]);
+impl_lie_group!(Se3, VecND<T, 6>, [VecND<T, 6>; 6]);
+
+impl<T: Component> Mul for So2<T>
+where
+    for<'a> &'a T: RefComponent<T>,
+{
+    type Output = Self;
+
+    /// The composition: `a * b` rotates by `b` and then by `a`.
+    fn mul(self, rhs: Self) -> Self {
+        Self::new(self.rotation * rhs.rotation)
+    }
+}
+
+impl<T: Component> Mul for So3<T>
+where
+    for<'a> &'a T: RefComponent<T>,
+{
+    type Output = Self;
+
+    /// The composition: `a * b` rotates by `b` and then by `a`.
+    fn mul(self, rhs: Self) -> Self {
+        Self::new(self.rotor * rhs.rotor)
+    }
+}
+
+impl<T: Component> Mul for Se2<T>
+where
+    for<'a> &'a T: RefComponent<T>,
+{
+    type Output = Self;
+
+    /// The composition: `a * b` moves by `b` and then by `a`.
+    fn mul(self, rhs: Self) -> Self {
+        Self::new(
+            self.rotation * rhs.rotation,
+            self.transform_point(rhs.translation),
+        )
+    }
+}
+
+impl<T: Component> Mul for Se3<T>
+where
+    for<'a> &'a T: RefComponent<T>,
+{
+    type Output = Self;
+
+    /// The composition: `a * b` moves by `b` and then by `a`.
+    fn mul(self, rhs: Self) -> Self {
+        Self::new(
+            self.rotation * rhs.rotation,
+            self.transform_point(rhs.translation),
+        )
+    }
+}
+
+impl<T> From<Rot2<T>> for So2<T> {
+    #[inline]
+    fn from(rotation: Rot2<T>) -> Self {
+        Self { rotation }
+    }
+}
+
+impl<T> From<So2<T>> for Rot2<T> {
+    #[inline]
+    fn from(r: So2<T>) -> Self {
+        r.rotation
+    }
+}
+
+impl<T> From<Rotor3<T>> for So3<T> {
+    #[inline]
+    fn from(rotor: Rotor3<T>) -> Self {
+        Self { rotor }
+    }
+}
+
+impl<T> From<So3<T>> for Rotor3<T> {
+    #[inline]
+    fn from(r: So3<T>) -> Self {
+        r.rotor
+    }
+}
+
+impl<T> From<Isometry2<T>> for Se2<T> {
+    #[inline]
+    fn from(m: Isometry2<T>) -> Self {
+        Self {
+            rotation: So2::from(m.rotation),
+            translation: m.translation,
+        }
+    }
+}
+
+impl<T> From<Se2<T>> for Isometry2<T> {
+    #[inline]
+    fn from(m: Se2<T>) -> Self {
+        Self {
+            rotation: m.rotation.rotation,
+            translation: m.translation,
+        }
+    }
+}
+
+impl<T> From<Isometry3<T>> for Se3<T> {
+    #[inline]
+    fn from(m: Isometry3<T>) -> Self {
+        Self {
+            rotation: So3 { rotor: m.rotation },
+            translation: m.translation,
+        }
+    }
+}
+
+impl<T> From<Se3<T>> for Isometry3<T> {
+    #[inline]
+    fn from(m: Se3<T>) -> Self {
+        Self {
+            rotation: m.rotation.rotor,
+            translation: m.translation,
+        }
+    }
+}
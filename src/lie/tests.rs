@@ -0,0 +1,280 @@
+use std::f64::consts::PI;
+
+use num_traits::One;
+
+use crate::components::Components;
+use crate::testing::Lcg;
+use crate::*;
+
+impl Lcg {
+    /// A rotation vector of the angle in a random direction.
+    fn phi(&mut self, angle: f64) -> V3D64 {
+        self.vec3().normalize() * angle
+    }
+
+    fn twist(&mut self, angle: f64) -> VecND<f64, 6> {
+        let (rho, phi) = (self.vec3(), self.phi(angle));
+        VecND::from([rho.x, rho.y, rho.z, phi.x, phi.y, phi.z])
+    }
+}
+
+/// Angles around both sides of the threshold of the series, down to zero.
+const ANGLES: [f64; 10] = [
+    0.0,
+    1e-12,
+    1e-8,
+    1e-4,
+    0.324,
+    0.325,
+    0.5,
+    2.0,
+    3.0,
+    PI - 1e-6,
+];
+
+fn apply3(m: [V3D64; 3], v: V3D64) -> V3D64 {
+    m[0] * v.x + m[1] * v.y + m[2] * v.z
+}
+
+fn apply6(m: [VecND<f64, 6>; 6], v: VecND<f64, 6>) -> VecND<f64, 6> {
+    (0..6).map(|i| m[i] * v[i]).sum()
+}
+
+fn basis<V: Components>(i: usize) -> V
+where
+    for<'l> &'l V: VectorRefOps<V::Cmp, V>,
+{
+    let mut e = V::zero();
+    e[i] = V::Cmp::one();
+    e
+}
+
+#[test]
+fn so2() {
+    let mut rng = Lcg(1);
+    for _ in 0..100 {
+        let (a, b) = (PI * 2.0 * rng.next(), PI * 2.0 * rng.next());
+        assert!((So2::exp(a).log() - a).abs() < 1e-15);
+        assert!(((So2::exp(a) * So2::exp(b)).minus(So2::exp(b)) - a).abs() < 1e-14);
+        let v = Vec2D::new(rng.next(), rng.next());
+        assert!(
+            So2::exp(a)
+                .inverse()
+                .rotate(So2::exp(a).rotate(v))
+                .distance(v)
+                < 1e-15
+        );
+        let m = So2::exp(a).matrix();
+        assert_eq!(So2::exp(a).rotate(v), m[0] * v.x + m[1] * v.y);
+        assert_eq!(So2::vee(So2::hat(a)), a);
+    }
+    assert_eq!(So2::<f64>::right_jacobian(1.0), 1.0);
+}
+
+#[test]
+fn so3_exp_and_log() {
+    let mut rng = Lcg(2);
+    for angle in ANGLES {
+        for _ in 0..20 {
+            let phi = rng.phi(angle);
+            let r = So3::exp(phi);
+            assert!((r.rotor.norm() - 1.0).abs() < 1e-15);
+            assert!(r.log().distance(phi) <= 1e-15 * angle.max(1.0), "{angle}");
+            assert!((So3::exp(r.log()).rotor - r.rotor).norm() < 1e-15);
+            // Rodrigues' rotation formula
+            let v = rng.vec3();
+            let k = phi.normalize();
+            let kv = apply3(So3::hat(k), v);
+            let expected = if angle == 0.0 {
+                v
+            } else {
+                v * angle.cos() + kv * angle.sin() + k * (k.dot(v) * (1.0 - angle.cos()))
+            };
+            assert!(r.rotate(v).distance(expected) < 1e-15);
+            assert!(apply3(r.matrix(), v).distance(r.rotate(v)) < 1e-15);
+            assert_eq!(So3::vee(So3::hat(phi)), phi);
+        }
+    }
+    // the shorter of the two rotors
+    let r = So3::exp(Vec3D::new(0.0, 0.0, 1.0));
+    assert!(So3::new(-r.rotor).log().distance(r.log()) < 1e-16);
+    let r = So3::exp(Vec3D::new(0.0, 0.0, 4.0));
+    assert!(r.log().distance(Vec3D::new(0.0, 0.0, 4.0 - 2.0 * PI)) < 1e-15);
+}
+
+#[test]
+fn so3_jacobians() {
+    let mut rng = Lcg(3);
+    let h = 1e-6;
+    for angle in ANGLES {
+        for _ in 0..10 {
+            let phi = rng.phi(angle);
+            let r = So3::exp(phi);
+            let jl = So3::left_jacobian(phi);
+            let jr = So3::right_jacobian(phi);
+            for i in 0..3 {
+                let d = basis::<V3D64>(i) * h;
+                let (p, m) = (So3::exp(phi + d), So3::exp(phi - d));
+                let left = ((p * r.inverse()).log() - (m * r.inverse()).log()) / (2.0 * h);
+                assert!(left.distance(jl[i]) < 1e-9, "{angle}");
+                assert!((p.minus(r) - m.minus(r)).distance(jr[i] * 2.0 * h) < 1e-15);
+            }
+            let v = rng.vec3();
+            let li = So3::left_jacobian_inverse(phi);
+            let ri = So3::right_jacobian_inverse(phi);
+            assert!(apply3(li, apply3(jl, v)).distance(v) < 1e-15);
+            assert!(apply3(ri, apply3(jr, v)).distance(v) < 1e-15);
+            // J_l = Ad_exp(φ) J_r
+            assert!(apply3(r.adjoint(), apply3(jr, v)).distance(apply3(jl, v)) < 1e-15);
+        }
+    }
+}
+
+#[test]
+fn so3_adjoint() {
+    let mut rng = Lcg(4);
+    for _ in 0..50 {
+        let angle = 3.0 * rng.next();
+        let r = So3::exp(rng.phi(angle));
+        let phi = rng.phi(0.5);
+        let lhs = r * So3::exp(phi) * r.inverse();
+        let rhs = So3::exp(apply3(r.adjoint(), phi));
+        assert!(lhs.minus(rhs).norm() < 1e-15);
+    }
+}
+
+#[test]
+fn se2() {
+    let mut rng = Lcg(5);
+    let h = 1e-6;
+    for angle in ANGLES {
+        for _ in 0..10 {
+            let angle = if rng.next() < 0.0 { -angle } else { angle };
+            let tau = Vec3D::new(rng.next(), rng.next(), angle);
+            let x = Se2::exp(tau);
+            assert!(x.log().distance(tau) < 1e-15, "{angle}");
+            assert_eq!(Se2::vee(Se2::hat(tau)), tau);
+
+            let p = Vec2D::new(rng.next(), rng.next());
+            assert!(x.matrix().transform_point(p).distance(x.transform_point(p)) < 1e-15);
+            assert!(
+                Isometry2::from(x)
+                    .transform_point(p)
+                    .distance(x.transform_point(p))
+                    < 1e-15
+            );
+            assert!(
+                x.inverse()
+                    .transform_point(x.transform_point(p))
+                    .distance(p)
+                    < 1e-15
+            );
+
+            let jl = Se2::left_jacobian(tau);
+            let jr = Se2::right_jacobian(tau);
+            for i in 0..3 {
+                let d = basis::<V3D64>(i) * h;
+                let (p, m) = (Se2::exp(tau + d), Se2::exp(tau - d));
+                let left = ((p * x.inverse()).log() - (m * x.inverse()).log()) / (2.0 * h);
+                assert!(left.distance(jl[i]) < 1e-9, "{angle}");
+                let right = (p.minus(x) - m.minus(x)) / (2.0 * h);
+                assert!(right.distance(jr[i]) < 1e-9, "{angle}");
+            }
+            let v = rng.vec3();
+            let li = Se2::left_jacobian_inverse(tau);
+            assert!(apply3(li, apply3(jl, v)).distance(v) < 1e-15);
+            assert!(apply3(Se2::right_jacobian_inverse(tau), apply3(jr, v)).distance(v) < 1e-15);
+            assert!(apply3(x.adjoint(), apply3(jr, v)).distance(apply3(jl, v)) < 1e-15);
+
+            let y = Se2::exp(Vec3D::new(rng.next(), rng.next(), 2.0 * rng.next()));
+            let lhs = y * Se2::exp(v) * y.inverse();
+            assert!(lhs.minus(Se2::exp(apply3(y.adjoint(), v))).norm() < 1e-14);
+        }
+    }
+}
+
+#[test]
+fn se3() {
+    let mut rng = Lcg(6);
+    let h = 1e-6;
+    for angle in ANGLES {
+        for _ in 0..10 {
+            let tau = rng.twist(angle);
+            let x = Se3::exp(tau);
+            assert!(x.log().distance(tau) < 1e-15, "{angle}");
+            assert_eq!(Se3::vee(Se3::hat(tau)), tau);
+
+            let p = rng.vec3();
+            assert!(x.matrix().transform_point(p).distance(x.transform_point(p)) < 1e-15);
+            assert!(
+                Isometry3::from(x)
+                    .transform_point(p)
+                    .distance(x.transform_point(p))
+                    < 1e-15
+            );
+            assert!(
+                x.inverse()
+                    .transform_point(x.transform_point(p))
+                    .distance(p)
+                    < 1e-15
+            );
+
+            let jl = Se3::left_jacobian(tau);
+            let jr = Se3::right_jacobian(tau);
+            for i in 0..6 {
+                let d = basis::<VecND<f64, 6>>(i) * h;
+                let (p, m) = (Se3::exp(tau + d), Se3::exp(tau - d));
+                let left = ((p * x.inverse()).log() - (m * x.inverse()).log()) / (2.0 * h);
+                assert!(left.distance(jl[i]) < 1e-9, "{angle}");
+                let right = (p.minus(x) - m.minus(x)) / (2.0 * h);
+                assert!(right.distance(jr[i]) < 1e-9, "{angle}");
+            }
+            let v = rng.twist(0.5);
+            let li = Se3::left_jacobian_inverse(tau);
+            assert!(apply6(li, apply6(jl, v)).distance(v) < 1e-14);
+            assert!(apply6(Se3::right_jacobian_inverse(tau), apply6(jr, v)).distance(v) < 1e-14);
+            assert!(apply6(x.adjoint(), apply6(jr, v)).distance(apply6(jl, v)) < 1e-14);
+
+            let y = Se3::exp(rng.twist(2.0));
+            let lhs = y * Se3::exp(v) * y.inverse();
+            assert!(lhs.minus(Se3::exp(apply6(y.adjoint(), v))).norm() < 1e-14);
+        }
+    }
+}
+
+#[test]
+fn series_branches_are_continuous() {
+    let rho = Vec3D::new(0.3, -0.7, 0.2);
+    let axis = Vec3D::new(1.0, 2.0, -2.0) / 3.0;
+    // the adjacent angles on both sides of the threshold of `θ²`
+    let threshold = f64::EPSILON.powf(1.0 / 16.0);
+    let mut below = threshold.sqrt();
+    while (axis * below).norm_sqr() >= threshold {
+        below = below.next_down();
+    }
+    let mut above = below;
+    while (axis * above).norm_sqr() < threshold {
+        above = above.next_up();
+    }
+    let twist = |angle: f64| {
+        let phi = axis * angle;
+        VecND::from([rho.x, rho.y, rho.z, phi.x, phi.y, phi.z])
+    };
+    // the block Q of the left Jacobian is the most sensitive to cancellation
+    let (b, a) = (
+        Se3::left_jacobian(twist(below)),
+        Se3::left_jacobian(twist(above)),
+    );
+    for i in 0..6 {
+        assert!(b[i].distance(a[i]) < 4e-15);
+    }
+    let (b, a) = (
+        So3::left_jacobian_inverse(axis * below),
+        So3::left_jacobian_inverse(axis * above),
+    );
+    for i in 0..3 {
+        assert!(b[i].distance(a[i]) < 1e-15);
+    }
+    let (b, a) = (So3::exp(axis * below), So3::exp(axis * above));
+    assert!((b.rotor - a.rotor).norm() < 1e-15);
+}
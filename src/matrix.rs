@@ -0,0 +1,359 @@
+#[cfg(test)]
+mod tests;
+
+use std::array;
+use std::fmt::Debug;
+use std::fmt::Formatter;
+use std::ops::Add;
+use std::ops::AddAssign;
+use std::ops::Div;
+use std::ops::Index;
+use std::ops::IndexMut;
+use std::ops::Mul;
+use std::ops::Neg;
+use std::ops::Sub;
+use std::ops::SubAssign;
+
+use num_traits::One;
+use num_traits::Zero;
+#[cfg(feature = "serde_arrays")]
+use serde::Deserialize;
+#[cfg(feature = "serde_arrays")]
+use serde::Serialize;
+
+use crate::Component;
+use crate::InnerProductSpace;
+use crate::Vec2D;
+use crate::Vec3D;
+use crate::VecND;
+
+/// General-fixed-size matrix of `R` rows and `C` columns.
+///
+/// Stored as the columns, like the `[Vec3D<T>; 3]` matrices of the crate,
+/// and does no heap allocation like [`VecND`].
+/// Elements are accessible by the index `(row, column)`.
+///
+/// See [`lu`](MatrixND::lu), [`cholesky`](MatrixND::cholesky),
+/// [`qr`](MatrixND::qr), [`svd`](MatrixND::svd) and
+/// [`symmetric_eigen`](MatrixND::symmetric_eigen) for the decompositions.
+///
+/// # Examples
+///
+/// ```
+/// use ::ndvec::*;
+///
+/// let a = MatrixND::from_rows([[4.0, 1.0], [2.0, 3.0]]);
+/// let b = VecND::from([1.0, 2.0]);
+/// let x = a.solve(&b).unwrap();
+/// assert!((a * x).distance(b) < 1e-15);
+/// assert_eq!(a[(1, 0)], 2.0);
+/// assert_eq!(a.transpose()[(1, 0)], 1.0);
+/// ```
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde_arrays", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serde_arrays",
+    serde(bound(serialize = "T: Serialize", deserialize = "T: Deserialize<'de>"))
+)]
+pub struct MatrixND<T, const R: usize, const C: usize> {
+    #[cfg_attr(feature = "serde_arrays", serde(with = "serde_arrays"))]
+    cols: [VecND<T, R>; C],
+}
+
+impl<T: Component, const R: usize, const C: usize> MatrixND<T, R, C> {
+    /// The matrix of the columns.
+    #[inline]
+    pub fn from_cols(cols: [VecND<T, R>; C]) -> Self {
+        Self { cols }
+    }
+
+    /// The matrix of the rows.
+    pub fn from_rows(rows: [[T; C]; R]) -> Self {
+        Self::from_fn(|i, j| rows[i][j])
+    }
+
+    /// The matrix of the elements `f(row, column)`.
+    pub fn from_fn<F: FnMut(usize, usize) -> T>(mut f: F) -> Self {
+        Self::from_cols(array::from_fn(|j| VecND::from(array::from_fn(|i| f(i, j)))))
+    }
+
+    /// The columns.
+    #[inline]
+    pub fn cols(self) -> [VecND<T, R>; C] {
+        self.cols
+    }
+
+    /// The rows.
+    pub fn rows(self) -> [[T; C]; R] {
+        array::from_fn(|i| array::from_fn(|j| self.cols[j][i]))
+    }
+
+    /// The column of the index.
+    #[inline]
+    pub fn col(&self, j: usize) -> VecND<T, R> {
+        self.cols[j]
+    }
+
+    /// The row of the index.
+    pub fn row(&self, i: usize) -> VecND<T, C> {
+        VecND::from(array::from_fn(|j| self.cols[j][i]))
+    }
+
+    /// The transpose, whose rows are the columns.
+    pub fn transpose(self) -> MatrixND<T, C, R> {
+        MatrixND::from_fn(|i, j| self.cols[i][j])
+    }
+
+    /// The Frobenius norm, the square root of the sum of the squared
+    /// elements.
+    pub fn frobenius_norm(self) -> T {
+        self.cols
+            .into_iter()
+            .map(|c| c.norm_sqr())
+            .sum::<T>()
+            .sqrt()
+    }
+
+    /// The double contraction `A : B`, the sum of the products of the
+    /// elements.
+    pub fn double_contraction(self, other: Self) -> T {
+        (0..C).map(|j| self.cols[j].dot(other.cols[j])).sum()
+    }
+
+    /// The maximum absolute column sum, the matrix norm induced by the
+    /// L1-norm.
+    pub fn norm1(self) -> T {
+        self.cols
+            .into_iter()
+            .map(|c| (0..R).map(|i| c[i].abs()).sum::<T>())
+            .fold(T::zero(), T::max)
+    }
+}
+
+impl<T: Component, const N: usize> MatrixND<T, N, N> {
+    /// The identity matrix.
+    pub fn identity() -> Self {
+        Self::from_diagonal(VecND::from([T::one(); N]))
+    }
+
+    /// The diagonal matrix of the elements.
+    pub fn from_diagonal(d: VecND<T, N>) -> Self {
+        Self::from_fn(|i, j| if i == j { d[i] } else { T::zero() })
+    }
+
+    /// The diagonal elements.
+    pub fn diagonal(self) -> VecND<T, N> {
+        VecND::from(array::from_fn(|i| self.cols[i][i]))
+    }
+
+    /// The sum of the diagonal elements.
+    pub fn trace(self) -> T {
+        (0..N).map(|i| self.cols[i][i]).sum()
+    }
+
+    /// The determinant by the LU decomposition, zero if singular.
+    pub fn determinant(self) -> T {
+        self.lu().map_or(T::zero(), |lu| lu.determinant())
+    }
+
+    /// The inverse by the LU decomposition, or `None` if singular.
+    pub fn inverse(self) -> Option<Self> {
+        self.lu().map(|lu| lu.inverse())
+    }
+
+    /// The solution `x` of the linear system `A x = b` by the LU
+    /// decomposition, or `None` if singular.
+    ///
+    /// Decompose with [`lu`](MatrixND::lu) once to solve many systems of the
+    /// same matrix.
+    pub fn solve(&self, b: &VecND<T, N>) -> Option<VecND<T, N>> {
+        self.lu().map(|lu| lu.solve(b))
+    }
+
+    /// Checks if the matrix equals its transpose.
+    pub fn is_symmetric(&self) -> bool {
+        (0..N).all(|j| (0..j).all(|i| self.cols[j][i] == self.cols[i][j]))
+    }
+}
+
+impl<T: Component, const R: usize> VecND<T, R> {
+    /// The outer product `a bᵀ`, the matrix of the elements `a_i b_j`.
+    pub fn outer<const C: usize>(self, other: VecND<T, C>) -> MatrixND<T, R, C> {
+        MatrixND::from_cols(array::from_fn(|j| self * other[j]))
+    }
+}
+
+impl<T: Component> Vec2D<T> {
+    /// The outer product `a bᵀ`, the matrix of the elements `a_i b_j`.
+    pub fn outer(self, other: Self) -> MatrixND<T, 2, 2> {
+        VecND::from(self).outer(VecND::from(other))
+    }
+}
+
+impl<T: Component> Vec3D<T> {
+    /// The outer product `a bᵀ`, the matrix of the elements `a_i b_j`.
+    pub fn outer(self, other: Self) -> MatrixND<T, 3, 3> {
+        VecND::from(self).outer(VecND::from(other))
+    }
+}
+
+impl<T: Copy + Debug, const R: usize, const C: usize> Debug for MatrixND<T, R, C> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let rows: [[T; C]; R] = array::from_fn(|i| array::from_fn(|j| self.cols[j][i]));
+        f.debug_tuple("MatrixND").field(&rows).finish()
+    }
+}
+
+impl<T, const R: usize, const C: usize> Index<(usize, usize)> for MatrixND<T, R, C> {
+    type Output = T;
+
+    /// The element of the `(row, column)`.
+    #[inline]
+    fn index(&self, (i, j): (usize, usize)) -> &T {
+        &self.cols[j][i]
+    }
+}
+
+impl<T, const R: usize, const C: usize> IndexMut<(usize, usize)> for MatrixND<T, R, C> {
+    #[inline]
+    fn index_mut(&mut self, (i, j): (usize, usize)) -> &mut T {
+        &mut self.cols[j][i]
+    }
+}
+
+impl<T: Component, const R: usize, const C: usize> Zero for MatrixND<T, R, C> {
+    #[inline]
+    fn zero() -> Self {
+        Self::from_cols([VecND::zero(); C])
+    }
+
+    #[inline]
+    fn is_zero(&self) -> bool {
+        self.cols.iter().all(|c| c.is_zero())
+    }
+}
+
+impl<T: Component, const N: usize> One for MatrixND<T, N, N> {
+    #[inline]
+    fn one() -> Self {
+        Self::identity()
+    }
+}
+
+impl<T: Component, const R: usize, const C: usize> Neg for MatrixND<T, R, C> {
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self {
+        Self::from_cols(self.cols.map(|c| -c))
+    }
+}
+
+impl<T: Component, const R: usize, const C: usize> Add for MatrixND<T, R, C> {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, rhs: Self) -> Self {
+        Self::from_cols(array::from_fn(|j| self.cols[j] + rhs.cols[j]))
+    }
+}
+
+impl<T: Component, const R: usize, const C: usize> Sub for MatrixND<T, R, C> {
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, rhs: Self) -> Self {
+        Self::from_cols(array::from_fn(|j| self.cols[j] - rhs.cols[j]))
+    }
+}
+
+impl<T: Component, const R: usize, const C: usize> AddAssign for MatrixND<T, R, C> {
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl<T: Component, const R: usize, const C: usize> SubAssign for MatrixND<T, R, C> {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl<T: Component, const R: usize, const C: usize> Mul<T> for MatrixND<T, R, C> {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: T) -> Self {
+        Self::from_cols(self.cols.map(|c| c * rhs))
+    }
+}
+
+impl<T: Component, const R: usize, const C: usize> Div<T> for MatrixND<T, R, C> {
+    type Output = Self;
+
+    #[inline]
+    fn div(self, rhs: T) -> Self {
+        Self::from_cols(self.cols.map(|c| c / rhs))
+    }
+}
+
+impl<T: Component, const R: usize, const C: usize> Mul<VecND<T, C>> for MatrixND<T, R, C> {
+    type Output = VecND<T, R>;
+
+    /// The matrix-vector product, the combination of the columns.
+    fn mul(self, rhs: VecND<T, C>) -> VecND<T, R> {
+        (0..C).fold(VecND::zero(), |acc, j| acc + self.cols[j] * rhs[j])
+    }
+}
+
+impl<T: Component, const R: usize, const C: usize, const K: usize> Mul<MatrixND<T, C, K>>
+    for MatrixND<T, R, C>
+{
+    type Output = MatrixND<T, R, K>;
+
+    /// The matrix product.
+    fn mul(self, rhs: MatrixND<T, C, K>) -> MatrixND<T, R, K> {
+        MatrixND::from_cols(rhs.cols.map(|c| self * c))
+    }
+}
+
+impl<T: Component, const R: usize, const C: usize> From<[VecND<T, R>; C]> for MatrixND<T, R, C> {
+    #[inline]
+    fn from(cols: [VecND<T, R>; C]) -> Self {
+        Self::from_cols(cols)
+    }
+}
+
+impl<T: Component> From<[Vec2D<T>; 2]> for MatrixND<T, 2, 2> {
+    /// The matrix of the columns.
+    #[inline]
+    fn from(cols: [Vec2D<T>; 2]) -> Self {
+        Self::from_cols(cols.map(VecND::from))
+    }
+}
+
+impl<T: Component> From<MatrixND<T, 2, 2>> for [Vec2D<T>; 2] {
+    /// The columns.
+    #[inline]
+    fn from(m: MatrixND<T, 2, 2>) -> Self {
+        m.cols.map(Vec2D::from)
+    }
+}
+
+impl<T: Component> From<[Vec3D<T>; 3]> for MatrixND<T, 3, 3> {
+    /// The matrix of the columns.
+    #[inline]
+    fn from(cols: [Vec3D<T>; 3]) -> Self {
+        Self::from_cols(cols.map(VecND::from))
+    }
+}
+
+impl<T: Component> From<MatrixND<T, 3, 3>> for [Vec3D<T>; 3] {
+    /// The columns.
+    #[inline]
+    fn from(m: MatrixND<T, 3, 3>) -> Self {
+        m.cols.map(Vec3D::from)
+    }
+}
@@ -0,0 +1,100 @@
+use crate::*;
+
+#[test]
+fn construction() {
+    let a = MatrixND::from_rows([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+    assert_eq!(a[(0, 2)], 3.0);
+    assert_eq!(a[(1, 0)], 4.0);
+    assert_eq!(a.col(1), VecND::from([2.0, 5.0]));
+    assert_eq!(a.row(1), VecND::from([4.0, 5.0, 6.0]));
+    assert_eq!(a.rows(), [[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+    assert_eq!(MatrixND::from_cols(a.cols()), a);
+    assert_eq!(MatrixND::from_fn(|i, j| (3 * i + j + 1) as f64), a);
+    let mut b = a;
+    b[(1, 0)] = 0.0;
+    assert_eq!(b.rows(), [[1.0, 2.0, 3.0], [0.0, 5.0, 6.0]]);
+    let t = a.transpose();
+    assert_eq!(t.rows(), [[1.0, 4.0], [2.0, 5.0], [3.0, 6.0]]);
+    assert_eq!(t.transpose(), a);
+    assert_eq!(a.frobenius_norm(), 91f64.sqrt());
+    assert_eq!(a.norm1(), 9.0);
+    assert_eq!(
+        format!("{a:?}"),
+        "MatrixND([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]])"
+    );
+}
+
+#[test]
+fn arithmetic() {
+    let a = MatrixND::from_rows([[1.0, 2.0], [3.0, 4.0]]);
+    let b = MatrixND::from_rows([[0.0, 1.0], [1.0, 0.0]]);
+    assert_eq!((a + b).rows(), [[1.0, 3.0], [4.0, 4.0]]);
+    assert_eq!((a - b).rows(), [[1.0, 1.0], [2.0, 4.0]]);
+    assert_eq!((-a).rows(), [[-1.0, -2.0], [-3.0, -4.0]]);
+    assert_eq!((a * 2.0).rows(), [[2.0, 4.0], [6.0, 8.0]]);
+    assert_eq!((a / 2.0).rows(), [[0.5, 1.0], [1.5, 2.0]]);
+    let mut c = a;
+    c += b;
+    c -= b;
+    assert_eq!(c, a);
+    assert_eq!((a * b).rows(), [[2.0, 1.0], [4.0, 3.0]]);
+    assert_eq!(a * VecND::from([1.0, 1.0]), VecND::from([3.0, 7.0]));
+    // the product of the differently-sized matrices
+    let r = MatrixND::from_rows([[1.0, 0.0, 2.0]]);
+    let s = MatrixND::from_rows([[1.0], [2.0], [3.0]]);
+    assert_eq!((r * s).rows(), [[7.0]]);
+    assert_eq!((s * r).rows()[2], [3.0, 0.0, 6.0]);
+    assert!(MatrixND::<f64, 2, 3>::zero().is_zero());
+    assert_eq!(MatrixND::<f64, 2, 2>::identity() * a, a);
+}
+
+#[test]
+fn square() {
+    let a = MatrixND::from_rows([[2.0, 1.0, 0.0], [1.0, 3.0, 1.0], [0.0, 1.0, 4.0]]);
+    assert_eq!(a.trace(), 9.0);
+    assert_eq!(a.diagonal(), VecND::from([2.0, 3.0, 4.0]));
+    assert_eq!(
+        MatrixND::from_diagonal(a.diagonal()).rows(),
+        [[2.0, 0.0, 0.0], [0.0, 3.0, 0.0], [0.0, 0.0, 4.0]]
+    );
+    assert!(a.is_symmetric());
+    assert!(!(a + MatrixND::from_rows([[0.0, 1.0, 0.0], [0.0; 3], [0.0; 3]])).is_symmetric());
+    assert!((a.determinant() - 18.0).abs() < 1e-14);
+    let inv = a.inverse().unwrap();
+    assert!((a * inv - MatrixND::identity()).frobenius_norm() < 1e-15);
+    let b = VecND::from([1.0, -2.0, 3.0]);
+    let x = a.solve(&b).unwrap();
+    assert!((a * x).distance(b) < 1e-14);
+
+    let singular = MatrixND::from_rows([[1.0, 2.0], [2.0, 4.0]]);
+    assert_eq!(singular.determinant(), 0.0);
+    assert!(singular.inverse().is_none());
+    assert!(singular.solve(&VecND::from([1.0, 1.0])).is_none());
+}
+
+#[test]
+fn conversions() {
+    let cols = [
+        Vec3D::new(1.0, 2.0, 3.0),
+        Vec3D::new(4.0, 5.0, 6.0),
+        Vec3D::new(7.0, 8.0, 10.0),
+    ];
+    let m = MatrixND::from(cols);
+    assert_eq!(m[(2, 1)], 6.0);
+    assert_eq!(<[Vec3D<f64>; 3]>::from(m), cols);
+    let v = Vec3D::new(1.0, -1.0, 2.0);
+    let mv = m * VecND::from(v);
+    assert_eq!(
+        Vec3D::from(mv),
+        cols[0] * v.x + cols[1] * v.y + cols[2] * v.z
+    );
+
+    let r = Rot2::new(Rad(0.5)).matrix();
+    let m = MatrixND::from(r);
+    assert_eq!(<[Vec2D<f64>; 2]>::from(m), r);
+    assert!((m.determinant() - 1.0).abs() < 1e-15);
+    assert_eq!(
+        MatrixND::from([VecND::from([1.0, 2.0])]).rows(),
+        [[1.0], [2.0]]
+    );
+}
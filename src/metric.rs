@@ -0,0 +1,254 @@
+#[cfg(test)]
+mod tests;
+
+use num_traits::Float;
+use num_traits::One;
+use num_traits::Zero;
+
+use crate::components::Components;
+use crate::Aabb;
+use crate::Vector;
+use crate::VectorRefOps;
+
+/// Distance function between vectors.
+///
+/// Spatial algorithms can be generic over the metric instead of being fixed
+/// to the euclid distance.
+///
+/// # Examples
+///
+/// ```
+/// use ::ndvec::*;
+///
+/// fn nearest<V: Vector, M: Metric<V>>(metric: &M, points: &[V], p: V) -> Option<V>
+/// where
+///     for<'l> &'l V: VectorRefOps<V::Cmp, V>,
+/// {
+///     points.iter().copied().min_by(|&a, &b| {
+///         metric.distance(a, p).partial_cmp(&metric.distance(b, p)).unwrap()
+///     })
+/// }
+///
+/// let points = [Vec2D::new(3.0, 0.0), Vec2D::new(2.0, 2.0)];
+/// let p = Vec2D::new(0.0, 0.0);
+/// assert_eq!(nearest(&Euclidean, &points, p), Some(Vec2D::new(2.0, 2.0)));
+/// assert_eq!(nearest(&Manhattan, &points, p), Some(Vec2D::new(3.0, 0.0)));
+/// ```
+pub trait Metric<V: Vector>
+where
+    for<'l> &'l V: VectorRefOps<V::Cmp, V>,
+{
+    /// The distance between two vectors.
+    fn distance(&self, a: V, b: V) -> V::Cmp;
+
+    /// A lower bound of the distance from the point to any point in the box,
+    /// which prunes the boxes in spatial searches such as
+    /// [`Orthtree::k_nearest_by`](crate::Orthtree::k_nearest_by).
+    ///
+    /// Defaults to zero, which is valid for any metric but prunes nothing.
+    #[inline]
+    fn distance_to_box(&self, _bounds: &Aabb<V>, _point: V) -> V::Cmp
+    where
+        V: Components,
+    {
+        V::Cmp::zero()
+    }
+}
+
+/// The euclid distance, the L2-norm of the difference.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Euclidean;
+
+impl<V: Vector> Metric<V> for Euclidean
+where
+    for<'l> &'l V: VectorRefOps<V::Cmp, V>,
+{
+    #[inline]
+    fn distance(&self, a: V, b: V) -> V::Cmp {
+        a.distance(b)
+    }
+
+    #[inline]
+    fn distance_to_box(&self, bounds: &Aabb<V>, point: V) -> V::Cmp
+    where
+        V: Components,
+    {
+        self.distance(bounds.clamp(point), point)
+    }
+}
+
+/// The Manhattan (taxicab) distance, the L1-norm of the difference.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Manhattan;
+
+impl<V: Components> Metric<V> for Manhattan
+where
+    for<'l> &'l V: VectorRefOps<V::Cmp, V>,
+{
+    #[inline]
+    fn distance(&self, a: V, b: V) -> V::Cmp {
+        (a - b).norm_l1()
+    }
+
+    #[inline]
+    fn distance_to_box(&self, bounds: &Aabb<V>, point: V) -> V::Cmp
+    where
+        V: Components,
+    {
+        self.distance(bounds.clamp(point), point)
+    }
+}
+
+/// The Chebyshev distance, the L∞-norm of the difference.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Chebyshev;
+
+impl<V: Components> Metric<V> for Chebyshev
+where
+    for<'l> &'l V: VectorRefOps<V::Cmp, V>,
+{
+    #[inline]
+    fn distance(&self, a: V, b: V) -> V::Cmp {
+        (a - b).norm_inf()
+    }
+
+    #[inline]
+    fn distance_to_box(&self, bounds: &Aabb<V>, point: V) -> V::Cmp
+    where
+        V: Components,
+    {
+        self.distance(bounds.clamp(point), point)
+    }
+}
+
+/// The Minkowski distance, the Lp-norm of the difference.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Minkowski<T> {
+    p: T,
+}
+
+impl<T: Float> Minkowski<T> {
+    /// Creates the distance of the Lp-norm.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `p` is less than one or NaN, since it is not a metric.
+    pub fn new(p: T) -> Self {
+        assert!(
+            p >= T::one(),
+            "the order of Minkowski distance must be at least one"
+        );
+        Self { p }
+    }
+
+    /// The order of the norm.
+    pub fn p(&self) -> T {
+        self.p
+    }
+}
+
+impl<V: Components> Metric<V> for Minkowski<V::Cmp>
+where
+    for<'l> &'l V: VectorRefOps<V::Cmp, V>,
+{
+    #[inline]
+    fn distance(&self, a: V, b: V) -> V::Cmp {
+        (a - b).norm_lp(self.p)
+    }
+
+    #[inline]
+    fn distance_to_box(&self, bounds: &Aabb<V>, point: V) -> V::Cmp
+    where
+        V: Components,
+    {
+        self.distance(bounds.clamp(point), point)
+    }
+}
+
+/// The cosine distance `1 - cos θ` of the angle between two vectors, in `[0, 2]`.
+///
+/// This is not a metric in the strict sense: it ignores the lengths and
+/// does not satisfy the triangle inequality.
+/// NaN if either of the vectors is zero.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Cosine;
+
+impl<V: Components> Metric<V> for Cosine
+where
+    for<'l> &'l V: VectorRefOps<V::Cmp, V>,
+{
+    fn distance(&self, a: V, b: V) -> V::Cmp {
+        let one = V::Cmp::one();
+        let cos = a.normalize().dot(b.normalize());
+        one - num_traits::clamp(cos, -one, one)
+    }
+}
+
+/// The Mahalanobis distance `sqrt(dᵀ Σ⁻¹ d)` of a covariance matrix `Σ`.
+///
+/// Holds the Cholesky factor of the covariance and solves a triangular
+/// system for each distance, without the explicit inverse.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Mahalanobis<V> {
+    /// Rows of the lower triangular factor `L` where `Σ = L Lᵀ`.
+    factor: Vec<V>,
+}
+
+impl<V: Components> Mahalanobis<V>
+where
+    for<'l> &'l V: VectorRefOps<V::Cmp, V>,
+{
+    /// Creates the distance from the rows of a covariance matrix.
+    ///
+    /// Only the lower triangle is read, assuming the matrix is symmetric.
+    /// Returns `None` if the matrix is not positive definite.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of rows differs from the dimension.
+    pub fn new(covariance: &[V]) -> Option<Self> {
+        assert_eq!(
+            covariance.len(),
+            V::DIM,
+            "the covariance matrix must be square"
+        );
+        let mut factor = vec![V::zero(); V::DIM];
+        for i in 0..V::DIM {
+            for j in 0..=i {
+                let (li, lj) = (factor[i], factor[j]);
+                let mut s = covariance[i][j];
+                for k in 0..j {
+                    s -= li[k] * lj[k];
+                }
+                if i == j {
+                    if s <= V::Cmp::zero() || s.is_nan() {
+                        return None;
+                    }
+                    factor[i][i] = s.sqrt();
+                } else {
+                    factor[i][j] = s / factor[j][j];
+                }
+            }
+        }
+        Some(Self { factor })
+    }
+}
+
+impl<V: Components> Metric<V> for Mahalanobis<V>
+where
+    for<'l> &'l V: VectorRefOps<V::Cmp, V>,
+{
+    fn distance(&self, a: V, b: V) -> V::Cmp {
+        let d = a - b;
+        // forward substitution of L y = d, then |y|² = dᵀ Σ⁻¹ d
+        let mut y = V::zero();
+        for i in 0..V::DIM {
+            let mut s = d[i];
+            for k in 0..i {
+                s -= self.factor[i][k] * y[k];
+            }
+            y[i] = s / self.factor[i][i];
+        }
+        y.norm()
+    }
+}
@@ -0,0 +1,125 @@
+use crate::testing::Lcg;
+use crate::*;
+
+#[test]
+fn distances() {
+    let a = Vec3D::new(1f64, 2f64, 3f64);
+    let b = Vec3D::new(4f64, -2f64, 3f64);
+    assert_eq!(Euclidean.distance(a, b), 5f64);
+    assert_eq!(Manhattan.distance(a, b), 7f64);
+    assert_eq!(Chebyshev.distance(a, b), 4f64);
+    assert_eq!(Minkowski::new(1f64).distance(a, b), 7f64);
+    assert_eq!(Minkowski::new(2f64).distance(a, b), 5f64);
+    assert_eq!(Minkowski::new(f64::INFINITY).distance(a, b), 4f64);
+    let d = Minkowski::new(3f64).distance(a, b);
+    assert!((d - 91f64.cbrt()).abs() < 1e-15);
+    assert_eq!(Minkowski::new(3f64).p(), 3f64);
+}
+
+#[test]
+#[should_panic]
+fn minkowski_of_order_below_one() {
+    Minkowski::new(0.5f32);
+}
+
+#[test]
+fn cosine() {
+    let a = Vec2D::new(2f64, 0f64);
+    assert_eq!(Cosine.distance(a, Vec2D::new(5f64, 0f64)), 0f64);
+    assert_eq!(Cosine.distance(a, Vec2D::new(0f64, 3f64)), 1f64);
+    assert_eq!(Cosine.distance(a, Vec2D::new(-1e300f64, 0f64)), 2f64);
+    let d = Cosine.distance(a, Vec2D::new(1f64, 1f64));
+    assert!((d - (1f64 - 0.5f64.sqrt())).abs() < 1e-15);
+    assert!(Cosine.distance(a, Vec2D::zero()).is_nan());
+}
+
+#[test]
+fn cosine_of_fixed_point() {
+    let a = Vec2D::new(Fixed64::from_int(3), Fixed64::from_int(4));
+    let b = Vec2D::new(Fixed64::from_int(4), Fixed64::from_int(3));
+    let tolerance = Fixed64::DELTA * Fixed64::from_int(4);
+    assert!((Cosine.distance(a, b) - Fixed64::from_f64(0.04)).abs() <= tolerance);
+    assert!(Cosine.distance(a, a * Fixed64::from_int(7)).abs() <= tolerance);
+    let a = Vec3D::new(
+        Fixed32::from_int(2),
+        Fixed32::from_int(-1),
+        Fixed32::from_int(2),
+    );
+    let d = Cosine.distance(a, -a);
+    assert!((d - Fixed32::from_int(2)).abs() <= Fixed32::DELTA * Fixed32::from_int(4));
+}
+
+#[test]
+fn mahalanobis() {
+    // diagonal covariance scales each axis by its standard deviation
+    let m = Mahalanobis::new(&[Vec2D::new(4f64, 0f64), Vec2D::new(0f64, 9f64)]).unwrap();
+    let d = m.distance(Vec2D::new(2f64, 3f64), Vec2D::new(0f64, 0f64));
+    assert!((d - 2f64.sqrt()).abs() < 1e-15);
+
+    // compared with the explicit inverse of a correlated covariance
+    let cov = [
+        Vec3D::new(4f64, 1f64, 0.5f64),
+        Vec3D::new(1f64, 3f64, -0.5f64),
+        Vec3D::new(0.5f64, -0.5f64, 2f64),
+    ];
+    let m = Mahalanobis::new(&cov).unwrap();
+    let (a, b, c) = (cov[0], cov[1], cov[2]);
+    let cofactor = [b.cross(c), c.cross(a), a.cross(b)];
+    let det = a.dot(cofactor[0]);
+    let mut rng = Lcg(5);
+    for _ in 0..100 {
+        let (x, y) = (rng.vec3(), rng.vec3());
+        let d = x - y;
+        let inv_d = Vec3D::new(cofactor[0].dot(d), cofactor[1].dot(d), cofactor[2].dot(d)) / det;
+        let expected = d.dot(inv_d).sqrt();
+        assert!((m.distance(x, y) - expected).abs() < 1e-14);
+        assert!((m.distance(y, x) - expected).abs() < 1e-14);
+    }
+    assert_eq!(m.distance(cov[0], cov[0]), 0f64);
+
+    let singular = [Vec2D::new(1f64, 2f64), Vec2D::new(2f64, 4f64)];
+    assert_eq!(Mahalanobis::new(&singular), None);
+    assert_eq!(Mahalanobis::new(&[VecND::from([f64::NAN])]), None);
+}
+
+#[test]
+fn triangle_inequality() {
+    let minkowski = Minkowski::new(1.5f64);
+    let metrics: [&dyn Metric<V3D64>; 4] = [&Euclidean, &Manhattan, &Chebyshev, &minkowski];
+    let mut rng = Lcg(1);
+    for _ in 0..1000 {
+        let (a, b, c) = (rng.vec3(), rng.vec3(), rng.vec3());
+        for m in metrics {
+            assert!(m.distance(a, c) <= m.distance(a, b) + m.distance(b, c) + 1e-15);
+            assert_eq!(m.distance(a, b), m.distance(b, a));
+        }
+    }
+}
+
+#[test]
+fn distance_to_box() {
+    let bounds = Aabb::new(Vec2D::new(0f64, 0f64), Vec2D::new(1f64, 2f64));
+    let p = Vec2D::new(4f64, -4f64);
+    assert_eq!(Euclidean.distance_to_box(&bounds, p), 5f64);
+    assert_eq!(Manhattan.distance_to_box(&bounds, p), 7f64);
+    assert_eq!(Chebyshev.distance_to_box(&bounds, p), 4f64);
+    assert_eq!(Minkowski::new(1f64).distance_to_box(&bounds, p), 7f64);
+    assert_eq!(
+        Chebyshev.distance_to_box(&bounds, Vec2D::new(0.5, 1.0)),
+        0f64
+    );
+    // no bound is known of the angle
+    assert_eq!(Cosine.distance_to_box(&bounds, p), 0f64);
+
+    // a lower bound of the distances from the points in the box
+    let minkowski = Minkowski::new(1.5f64);
+    let metrics: [&dyn Metric<V3D64>; 4] = [&Euclidean, &Manhattan, &Chebyshev, &minkowski];
+    let mut rng = Lcg(2);
+    for _ in 0..1000 {
+        let bounds = Aabb::from_corners(rng.vec3(), rng.vec3());
+        let (p, q) = (rng.vec3(), bounds.clamp(rng.vec3() * 2.0));
+        for m in metrics {
+            assert!(m.distance_to_box(&bounds, p) <= m.distance(p, q));
+        }
+    }
+}
@@ -0,0 +1,683 @@
+#[cfg(test)]
+mod tests;
+
+use std::error::Error;
+use std::fmt::Display;
+
+use num_traits::Float;
+use num_traits::One;
+use num_traits::Zero;
+
+use crate::components::Components;
+use crate::Vector;
+use crate::VectorRefOps;
+
+/// Embedded explicit Runge-Kutta pair with a continuous extension.
+///
+/// The coefficients follow the usual Butcher tableau notation.
+/// The last stage must be the first same as last (FSAL) evaluation at the end of the step.
+pub trait EmbeddedRk {
+    /// The order of the propagated solution.
+    const ORDER: u32;
+
+    /// The order of the embedded error estimator.
+    const ERROR_ORDER: u32;
+
+    /// The nodes `c_i` of the stages, except the FSAL one.
+    const C: &'static [f64];
+
+    /// The lower triangular coefficients `a_ij` of the stages.
+    const A: &'static [&'static [f64]];
+
+    /// The weights `b_i` of the propagated solution.
+    const B: &'static [f64];
+
+    /// The differences of the weights from the embedded solution, including the FSAL stage.
+    const E: &'static [f64];
+
+    /// The coefficients of the dense output polynomials without constant terms,
+    /// one row for each stage including the FSAL one.
+    const P: &'static [&'static [f64]];
+}
+
+/// Dormand-Prince 5(4) method with the 4th-order continuous extension of Shampine.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct DormandPrince5;
+
+impl EmbeddedRk for DormandPrince5 {
+    const ORDER: u32 = 5;
+    const ERROR_ORDER: u32 = 4;
+    const C: &'static [f64] = &[0.0, 1.0 / 5.0, 3.0 / 10.0, 4.0 / 5.0, 8.0 / 9.0, 1.0];
+    const A: &'static [&'static [f64]] = &[
+        &[],
+        &[1.0 / 5.0],
+        &[3.0 / 40.0, 9.0 / 40.0],
+        &[44.0 / 45.0, -56.0 / 15.0, 32.0 / 9.0],
+        &[
+            19372.0 / 6561.0,
+            -25360.0 / 2187.0,
+            64448.0 / 6561.0,
+            -212.0 / 729.0,
+        ],
+        &[
+            9017.0 / 3168.0,
+            -355.0 / 33.0,
+            46732.0 / 5247.0,
+            49.0 / 176.0,
+            -5103.0 / 18656.0,
+        ],
+    ];
+    const B: &'static [f64] = &[
+        35.0 / 384.0,
+        0.0,
+        500.0 / 1113.0,
+        125.0 / 192.0,
+        -2187.0 / 6784.0,
+        11.0 / 84.0,
+    ];
+    const E: &'static [f64] = &[
+        -71.0 / 57600.0,
+        0.0,
+        71.0 / 16695.0,
+        -71.0 / 1920.0,
+        17253.0 / 339200.0,
+        -22.0 / 525.0,
+        1.0 / 40.0,
+    ];
+    const P: &'static [&'static [f64]] = &[
+        &[
+            1.0,
+            -8048581381.0 / 2820520608.0,
+            8663915743.0 / 2820520608.0,
+            -12715105075.0 / 11282082432.0,
+        ],
+        &[0.0, 0.0, 0.0, 0.0],
+        &[
+            0.0,
+            131558114200.0 / 32700410799.0,
+            -68118460800.0 / 10900136933.0,
+            87487479700.0 / 32700410799.0,
+        ],
+        &[
+            0.0,
+            -1754552775.0 / 470086768.0,
+            14199869525.0 / 1410260304.0,
+            -10690763975.0 / 1880347072.0,
+        ],
+        &[
+            0.0,
+            127303824393.0 / 49829197408.0,
+            -318862633887.0 / 49829197408.0,
+            701980252875.0 / 199316789632.0,
+        ],
+        &[
+            0.0,
+            -282668133.0 / 205662961.0,
+            2019193451.0 / 616988883.0,
+            -1453857185.0 / 822651844.0,
+        ],
+        &[
+            0.0,
+            40617522.0 / 29380423.0,
+            -110615467.0 / 29380423.0,
+            69997945.0 / 29380423.0,
+        ],
+    ];
+}
+
+/// Bogacki-Shampine 3(2) method with the cubic Hermite continuous extension.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct BogackiShampine3;
+
+impl EmbeddedRk for BogackiShampine3 {
+    const ORDER: u32 = 3;
+    const ERROR_ORDER: u32 = 2;
+    const C: &'static [f64] = &[0.0, 1.0 / 2.0, 3.0 / 4.0];
+    const A: &'static [&'static [f64]] = &[&[], &[1.0 / 2.0], &[0.0, 3.0 / 4.0]];
+    const B: &'static [f64] = &[2.0 / 9.0, 1.0 / 3.0, 4.0 / 9.0];
+    const E: &'static [f64] = &[5.0 / 72.0, -1.0 / 12.0, -1.0 / 9.0, 1.0 / 8.0];
+    const P: &'static [&'static [f64]] = &[
+        &[1.0, -4.0 / 3.0, 5.0 / 9.0],
+        &[0.0, 1.0, -2.0 / 3.0],
+        &[0.0, 4.0 / 3.0, -8.0 / 9.0],
+        &[0.0, -1.0, 1.0],
+    ];
+}
+
+/// Failure of [`AdaptiveSolver`], with the time reached.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum OdeError<T> {
+    /// The step size became too small for the time to advance.
+    StepSizeTooSmall {
+        /// The time reached.
+        t: T,
+    },
+    /// The number of steps exceeded the limit.
+    MaxStepsExceeded {
+        /// The time reached.
+        t: T,
+    },
+    /// The initial state or its derivative contained non-finite components.
+    NonFinite {
+        /// The time reached.
+        t: T,
+    },
+}
+
+impl<T: Display> Display for OdeError<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::StepSizeTooSmall { t } => write!(f, "step size became too small at t = {t}"),
+            Self::MaxStepsExceeded { t } => write!(f, "too many steps before t = {t}"),
+            Self::NonFinite { t } => write!(f, "non-finite initial state at t = {t}"),
+        }
+    }
+}
+
+impl<T: std::fmt::Debug + Display> Error for OdeError<T> {}
+
+/// Direction of the zero crossing detected by [`OdeEvent`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Crossing {
+    /// Both directions.
+    #[default]
+    Any,
+    /// From negative to non-negative.
+    Rising,
+    /// From positive to non-positive.
+    Falling,
+}
+
+type EventFunction<'a, T, V> = Box<dyn FnMut(T, &V) -> T + 'a>;
+
+/// Event located at a zero of a scalar function `g(t, y)` during integration.
+pub struct OdeEvent<'a, V: Vector>
+where
+    for<'l> &'l V: VectorRefOps<V::Cmp, V>,
+{
+    function: EventFunction<'a, V::Cmp, V>,
+    crossing: Crossing,
+    terminal: bool,
+}
+
+impl<'a, V: Vector> OdeEvent<'a, V>
+where
+    for<'l> &'l V: VectorRefOps<V::Cmp, V>,
+{
+    /// Creates a non-terminal event detecting crossings in both directions.
+    pub fn new<G: FnMut(V::Cmp, &V) -> V::Cmp + 'a>(function: G) -> Self {
+        Self {
+            function: Box::new(function),
+            crossing: Crossing::Any,
+            terminal: false,
+        }
+    }
+
+    /// Restricts the direction of the crossing.
+    pub fn crossing(mut self, crossing: Crossing) -> Self {
+        self.crossing = crossing;
+        self
+    }
+
+    /// Stops the integration at the first occurrence of the event.
+    pub fn terminal(mut self) -> Self {
+        self.terminal = true;
+        self
+    }
+
+    fn triggers(&self, g0: V::Cmp, g1: V::Cmp) -> bool {
+        let zero = V::Cmp::zero();
+        let rising = g0 < zero && g1 >= zero;
+        let falling = g0 > zero && g1 <= zero;
+        match self.crossing {
+            Crossing::Any => rising || falling,
+            Crossing::Rising => rising,
+            Crossing::Falling => falling,
+        }
+    }
+}
+
+/// Occurrence of an [`OdeEvent`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct EventHit<T, V> {
+    /// The index of the event in the given slice.
+    pub index: usize,
+    /// The time of the occurrence.
+    pub t: T,
+    /// The state at the occurrence.
+    pub y: V,
+}
+
+struct Segment<T, V> {
+    t: T,
+    h: T,
+    y: V,
+    k: Vec<V>,
+}
+
+/// Result of [`AdaptiveSolver`] with the accepted steps and a dense output.
+pub struct OdeSolution<V: Vector>
+where
+    for<'l> &'l V: VectorRefOps<V::Cmp, V>,
+{
+    /// The times of the accepted steps, starting from the initial time.
+    pub t: Vec<V::Cmp>,
+    /// The states at [`t`](Self::t).
+    pub y: Vec<V>,
+    /// The occurrences of the events in chronological order.
+    pub events: Vec<EventHit<V::Cmp, V>>,
+    /// Whether the integration was stopped by a terminal event.
+    pub terminated: bool,
+    /// The number of accepted steps.
+    pub accepted: usize,
+    /// The number of rejected steps.
+    pub rejected: usize,
+    /// The number of evaluations of the derivative.
+    pub evaluations: usize,
+    segments: Vec<Segment<V::Cmp, V>>,
+    dense: &'static [&'static [f64]],
+}
+
+impl<V: Components> OdeSolution<V>
+where
+    for<'l> &'l V: VectorRefOps<V::Cmp, V>,
+{
+    /// The final time and state.
+    pub fn last(&self) -> (V::Cmp, V) {
+        let last = self.t.len() - 1;
+        (self.t[last], self.y[last])
+    }
+
+    /// The interpolated state at time `t`, or `None` outside of the integrated interval.
+    pub fn at(&self, t: V::Cmp) -> Option<V> {
+        let t0 = self.t[0];
+        let (t1, y1) = self.last();
+        if t == t1 {
+            return Some(y1);
+        }
+        let forward = t1 >= t0;
+        let inside = if forward {
+            t0 <= t && t <= t1
+        } else {
+            t1 <= t && t <= t0
+        };
+        if !inside {
+            return None;
+        }
+        let at = self
+            .segments
+            .partition_point(|s| if forward { s.t <= t } else { s.t >= t });
+        let segment = &self.segments[at.saturating_sub(1)];
+        Some(interpolate(
+            self.dense,
+            segment,
+            (t - segment.t) / segment.h,
+        ))
+    }
+}
+
+#[inline]
+fn cast<T: Float>(c: f64) -> T {
+    T::from(c).expect("coefficients must be representable")
+}
+
+fn interpolate<V: Vector>(p: &[&[f64]], segment: &Segment<V::Cmp, V>, theta: V::Cmp) -> V
+where
+    for<'l> &'l V: VectorRefOps<V::Cmp, V>,
+{
+    let mut y = segment.y;
+    for (row, k) in p.iter().zip(&segment.k) {
+        let mut q = V::Cmp::zero();
+        let mut power = theta;
+        for &c in row.iter() {
+            q += cast::<V::Cmp>(c) * power;
+            power *= theta;
+        }
+        y += k * (q * segment.h);
+    }
+    y
+}
+
+/// Adaptive solver of `y' = f(t, y)` using an [`EmbeddedRk`] pair.
+///
+/// The local error `e` of each step is measured by the RMS norm of the
+/// components `e_i / (atol_i + rtol_i |y_i|)`, using the larger of `|y_i|`
+/// at the start and the end of the step,
+/// and the step is accepted when it does not exceed one.
+///
+/// # Examples
+///
+/// ```
+/// use ::ndvec::*;
+///
+/// // y' = -y
+/// let solver = AdaptiveSolver::new(DormandPrince5, 1e-10, 1e-12);
+/// let solution = solver
+///     .solve(|_, y: &V2D64| -y, 0.0, Vec2D::new(1.0, 2.0), 1.0)
+///     .unwrap();
+/// let exact = Vec2D::new(1.0, 2.0) * (-1f64).exp();
+/// assert!(solution.last().1.distance(exact) < 1e-9);
+/// assert!(solution.at(0.5).unwrap().distance(exact * 0.5f64.exp()) < 1e-9);
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct AdaptiveSolver<M, V: Vector>
+where
+    for<'l> &'l V: VectorRefOps<V::Cmp, V>,
+{
+    method: M,
+    rtol: V,
+    atol: V,
+    initial_step: Option<V::Cmp>,
+    max_step: V::Cmp,
+    max_steps: usize,
+}
+
+impl<M: EmbeddedRk, V: Components> AdaptiveSolver<M, V>
+where
+    for<'l> &'l V: VectorRefOps<V::Cmp, V>,
+{
+    /// The default limit of the number of steps.
+    pub const DEFAULT_MAX_STEPS: usize = 100_000;
+
+    /// Creates a solver with the same tolerances for all components.
+    pub fn new(method: M, rtol: V::Cmp, atol: V::Cmp) -> Self {
+        let mut r = V::zero();
+        let mut a = V::zero();
+        for i in 0..V::DIM {
+            r[i] = rtol;
+            a[i] = atol;
+        }
+        Self::with_tolerances(method, r, a)
+    }
+
+    /// Creates a solver with the relative and absolute tolerances of each component.
+    pub fn with_tolerances(method: M, rtol: V, atol: V) -> Self {
+        Self {
+            method,
+            rtol,
+            atol,
+            initial_step: None,
+            max_step: V::Cmp::infinity(),
+            max_steps: Self::DEFAULT_MAX_STEPS,
+        }
+    }
+
+    /// The embedded Runge-Kutta pair.
+    #[inline]
+    pub fn method(&self) -> &M {
+        &self.method
+    }
+
+    /// Uses the step size for the first step instead of estimating it.
+    pub fn initial_step(mut self, h: V::Cmp) -> Self {
+        self.initial_step = Some(h.abs());
+        self
+    }
+
+    /// Limits the absolute step size.
+    pub fn max_step(mut self, h: V::Cmp) -> Self {
+        self.max_step = h.abs();
+        self
+    }
+
+    /// Limits the number of steps, including rejected ones.
+    pub fn max_steps(mut self, n: usize) -> Self {
+        self.max_steps = n;
+        self
+    }
+
+    /// Integrates from `(t0, y0)` until `t_end`, which may be before `t0`.
+    pub fn solve<F>(
+        &self,
+        f: F,
+        t0: V::Cmp,
+        y0: V,
+        t_end: V::Cmp,
+    ) -> Result<OdeSolution<V>, OdeError<V::Cmp>>
+    where
+        F: FnMut(V::Cmp, &V) -> V,
+    {
+        self.solve_with_events(f, t0, y0, t_end, &mut [])
+    }
+
+    /// Integrates from `(t0, y0)` until `t_end` or a terminal event, locating the events.
+    pub fn solve_with_events<F>(
+        &self,
+        mut f: F,
+        t0: V::Cmp,
+        y0: V,
+        t_end: V::Cmp,
+        events: &mut [OdeEvent<'_, V>],
+    ) -> Result<OdeSolution<V>, OdeError<V::Cmp>>
+    where
+        F: FnMut(V::Cmp, &V) -> V,
+    {
+        let zero = V::Cmp::zero();
+        let one = V::Cmp::one();
+        let direction = if t_end >= t0 { one } else { -one };
+        let exponent = -one / cast::<V::Cmp>(f64::from(M::ERROR_ORDER + 1));
+        let safety = cast::<V::Cmp>(0.9);
+        let min_factor = cast::<V::Cmp>(0.2);
+        let max_factor = cast::<V::Cmp>(10.0);
+
+        let mut solution = OdeSolution {
+            t: vec![t0],
+            y: vec![y0],
+            events: Vec::new(),
+            terminated: false,
+            accepted: 0,
+            rejected: 0,
+            evaluations: 1,
+            segments: Vec::new(),
+            dense: M::P,
+        };
+        let mut t = t0;
+        let mut y = y0;
+        let mut dy = f(t, &y);
+        if !y.is_finite() || !dy.is_finite() {
+            return Err(OdeError::NonFinite { t });
+        }
+        let mut g: Vec<V::Cmp> = events.iter_mut().map(|e| (e.function)(t, &y)).collect();
+
+        let mut h = match self.initial_step {
+            Some(h) => h,
+            None => {
+                solution.evaluations += 1;
+                self.initial_step_size(&mut f, t, &y, &dy, direction)
+            }
+        };
+
+        let stages = M::B.len();
+        let mut k = vec![V::zero(); stages + 1];
+        let mut steps = 0;
+        while (t_end - t) * direction > zero {
+            h = h.min(self.max_step).min((t_end - t).abs());
+            let min_step = cast::<V::Cmp>(10.0) * V::Cmp::epsilon() * t.abs().max(one);
+            let mut error = V::Cmp::infinity();
+            let mut y_new = y;
+            let mut t_new = t;
+            while error > one {
+                if steps >= self.max_steps {
+                    return Err(OdeError::MaxStepsExceeded { t });
+                }
+                // the final step clamped to the end may be as short as the rounding of `t`
+                if h < min_step && h != (t_end - t).abs() {
+                    return Err(OdeError::StepSizeTooSmall { t });
+                }
+                steps += 1;
+                let hs = h * direction;
+                t_new = if h == (t_end - t).abs() {
+                    t_end
+                } else {
+                    t + hs
+                };
+                k[0] = dy;
+                for s in 1..stages {
+                    let mut ys = y;
+                    for (a, ks) in M::A[s].iter().zip(&k) {
+                        ys += ks * (cast::<V::Cmp>(*a) * hs);
+                    }
+                    k[s] = f(t + cast::<V::Cmp>(M::C[s]) * hs, &ys);
+                }
+                y_new = y;
+                for (b, ks) in M::B.iter().zip(&k) {
+                    y_new += ks * (cast::<V::Cmp>(*b) * hs);
+                }
+                k[stages] = f(t_new, &y_new);
+                solution.evaluations += stages;
+
+                let mut e = V::zero();
+                for (c, ks) in M::E.iter().zip(&k) {
+                    e += ks * (cast::<V::Cmp>(*c) * hs);
+                }
+                error = self.error_norm(e, &y, &y_new);
+                if !error.is_finite() || !k[stages].is_finite() {
+                    error = V::Cmp::infinity();
+                }
+                if error > one {
+                    solution.rejected += 1;
+                    h *= (safety * error.powf(exponent)).max(min_factor);
+                }
+            }
+            solution.accepted += 1;
+            let segment = Segment {
+                t,
+                h: t_new - t,
+                y,
+                k: k.clone(),
+            };
+
+            let mut hits = Vec::new();
+            for (index, event) in events.iter_mut().enumerate() {
+                let g1 = (event.function)(t_new, &y_new);
+                if event.triggers(g[index], g1) {
+                    let theta = locate(&mut event.function, M::P, &segment, g[index], g1);
+                    let te = t + theta * segment.h;
+                    hits.push((index, te, interpolate(M::P, &segment, theta)));
+                }
+                g[index] = g1;
+            }
+            hits.sort_by(|a, b| {
+                ((a.1 - t) * direction)
+                    .partial_cmp(&((b.1 - t) * direction))
+                    .unwrap_or(std::cmp::Ordering::Equal)
+            });
+            let stop = hits.iter().position(|&(index, ..)| events[index].terminal);
+            if let Some(stop) = stop {
+                hits.truncate(stop + 1);
+            }
+            solution
+                .events
+                .extend(hits.iter().map(|&(index, t, y)| EventHit { index, t, y }));
+            solution.segments.push(segment);
+
+            if let Some(&(_, te, ye)) = stop.map(|s| &hits[s]) {
+                solution.t.push(te);
+                solution.y.push(ye);
+                solution.terminated = true;
+                break;
+            }
+
+            t = t_new;
+            y = y_new;
+            dy = k[stages];
+            solution.t.push(t);
+            solution.y.push(y);
+
+            let factor = if error.is_zero() {
+                max_factor
+            } else {
+                (safety * error.powf(exponent))
+                    .min(max_factor)
+                    .max(min_factor)
+            };
+            h *= factor;
+        }
+        Ok(solution)
+    }
+
+    fn error_norm(&self, e: V, y0: &V, y1: &V) -> V::Cmp {
+        let mut scaled = e;
+        for i in 0..V::DIM {
+            scaled[i] /= self.atol[i] + self.rtol[i] * y0[i].abs().max(y1[i].abs());
+        }
+        scaled.norm() / cast::<V::Cmp>(V::DIM as f64).sqrt()
+    }
+
+    /// Estimates the first step size as in Hairer, Nørsett and Wanner.
+    fn initial_step_size(
+        &self,
+        f: &mut impl FnMut(V::Cmp, &V) -> V,
+        t: V::Cmp,
+        y: &V,
+        dy: &V,
+        direction: V::Cmp,
+    ) -> V::Cmp {
+        let small = cast::<V::Cmp>(1e-5);
+        let tiny = cast::<V::Cmp>(1e-6);
+        let hundredth = cast::<V::Cmp>(0.01);
+        let d0 = self.error_norm(*y, y, y);
+        let d1 = self.error_norm(*dy, y, y);
+        let h0 = if d0 < small || d1 < small {
+            tiny
+        } else {
+            hundredth * d0 / d1
+        }
+        .min(self.max_step);
+        let y1 = y + dy * (h0 * direction);
+        let dy1 = f(t + h0 * direction, &y1);
+        let d2 = self.error_norm(dy1 - dy, y, y) / h0;
+        let h1 = if d1.max(d2) <= cast::<V::Cmp>(1e-15) {
+            tiny.max(h0 * cast::<V::Cmp>(1e-3))
+        } else {
+            (hundredth / d1.max(d2)).powf(V::Cmp::one() / cast::<V::Cmp>(f64::from(M::ORDER + 1)))
+        };
+        (h0 * cast::<V::Cmp>(100.0)).min(h1).min(self.max_step)
+    }
+}
+
+/// Locates the zero of the event function in the step by the Illinois method,
+/// returning the fraction of the step.
+fn locate<V: Vector>(
+    g: &mut dyn FnMut(V::Cmp, &V) -> V::Cmp,
+    p: &[&[f64]],
+    segment: &Segment<V::Cmp, V>,
+    mut g0: V::Cmp,
+    mut g1: V::Cmp,
+) -> V::Cmp
+where
+    for<'l> &'l V: VectorRefOps<V::Cmp, V>,
+{
+    let zero = V::Cmp::zero();
+    let half = cast::<V::Cmp>(0.5);
+    let mut a = zero;
+    let mut b = V::Cmp::one();
+    let mut side = 0i8;
+    for _ in 0..100 {
+        if g1 == zero || b - a <= V::Cmp::epsilon() {
+            break;
+        }
+        let c = (a * g1 - b * g0) / (g1 - g0);
+        let c = if c > a && c < b { c } else { (a + b) * half };
+        let gc = g(segment.t + c * segment.h, &interpolate(p, segment, c));
+        if gc == zero {
+            return c;
+        }
+        if (gc < zero) == (g1 < zero) {
+            b = c;
+            g1 = gc;
+            if side == -1 {
+                g0 *= half;
+            }
+            side = -1;
+        } else {
+            a = c;
+            g0 = gc;
+            if side == 1 {
+                g1 *= half;
+            }
+            side = 1;
+        }
+    }
+    b
+}
@@ -0,0 +1,216 @@
+use std::f64::consts::PI;
+
+use crate::*;
+
+fn oscillator(_: f64, y: &VecND<f64, 2>) -> VecND<f64, 2> {
+    VecND::from([y[1], -y[0]])
+}
+
+#[test]
+fn exponential_decay() {
+    fn test<M: EmbeddedRk>(method: M, tolerance: f64) {
+        let solution = AdaptiveSolver::new(method, 1e-8, 1e-10)
+            .solve(|_, y: &V3D64| -y, 0.0, Vec3D::new(1.0, -2.0, 3.0), 2.0)
+            .unwrap();
+        let (t, y) = solution.last();
+        assert_eq!(t, 2.0);
+        let exact = Vec3D::new(1.0, -2.0, 3.0) * (-2f64).exp();
+        assert!(y.distance(exact) < tolerance, "{}", y.distance(exact));
+        assert_eq!(solution.t.len(), solution.accepted + 1);
+    }
+    test(DormandPrince5, 1e-8);
+    test(BogackiShampine3, 1e-7);
+}
+
+#[test]
+fn tolerance_controls_error() {
+    fn error<M: EmbeddedRk>(method: M, tol: f64) -> (f64, usize) {
+        let solution = AdaptiveSolver::new(method, tol, tol)
+            .solve(oscillator, 0.0, VecND::from([1.0, 0.0]), 10.0)
+            .unwrap();
+        let exact = VecND::from([10f64.cos(), -10f64.sin()]);
+        (solution.last().1.distance(exact), solution.accepted)
+    }
+    let (loose, loose_steps) = error(DormandPrince5, 1e-5);
+    let (tight, tight_steps) = error(DormandPrince5, 1e-10);
+    assert!(tight < 1e-8, "{tight}");
+    assert!(tight < loose * 1e-3);
+    assert!(tight_steps > loose_steps);
+
+    let (loose, _) = error(BogackiShampine3, 1e-4);
+    let (tight, _) = error(BogackiShampine3, 1e-8);
+    assert!(tight < 1e-6, "{tight}");
+    assert!(tight < loose * 1e-2);
+}
+
+#[test]
+fn fewer_steps_with_higher_order() {
+    let steps = |solution: OdeSolution<VecND<f64, 2>>| solution.accepted;
+    let dp = AdaptiveSolver::new(DormandPrince5, 1e-9, 1e-9)
+        .solve(oscillator, 0.0, VecND::from([1.0, 0.0]), 10.0)
+        .unwrap();
+    let bs = AdaptiveSolver::new(BogackiShampine3, 1e-9, 1e-9)
+        .solve(oscillator, 0.0, VecND::from([1.0, 0.0]), 10.0)
+        .unwrap();
+    assert!(steps(dp) * 3 < steps(bs));
+}
+
+#[test]
+fn dense_output() {
+    fn test<M: EmbeddedRk>(method: M, tolerance: f64) {
+        let solution = AdaptiveSolver::new(method, 1e-10, 1e-10)
+            .solve(oscillator, 0.0, VecND::from([1.0, 0.0]), 2.0 * PI)
+            .unwrap();
+        for i in 0..=100 {
+            let t = 2.0 * PI * i as f64 / 100.0;
+            let y = solution.at(t).unwrap();
+            let exact = VecND::from([t.cos(), -t.sin()]);
+            assert!(y.distance(exact) < tolerance, "{t}: {y:?} {exact:?}");
+        }
+        assert_eq!(solution.at(-0.1), None);
+        assert_eq!(solution.at(7.0), None);
+    }
+    test(DormandPrince5, 1e-8);
+    test(BogackiShampine3, 1e-7);
+}
+
+#[test]
+fn backward() {
+    let solution = AdaptiveSolver::new(DormandPrince5, 1e-10, 1e-12)
+        .solve(|_, y: &V2D64| -y, 1.0, Vec2D::new(1.0, 1.0), -1.0)
+        .unwrap();
+    let (t, y) = solution.last();
+    assert_eq!(t, -1.0);
+    assert!(y.distance(Vec2D::new(1.0, 1.0) * 2f64.exp()) < 1e-8);
+    assert!(
+        solution
+            .at(0.0)
+            .unwrap()
+            .distance(Vec2D::new(1.0, 1.0) * 1f64.exp())
+            < 1e-8
+    );
+}
+
+#[test]
+fn per_component_tolerances() {
+    let f = |_, y: &V2D64| Vec2D::new(y.y, -y.x);
+    let solution = AdaptiveSolver::with_tolerances(
+        DormandPrince5,
+        Vec2D::new(1e-12, 1.0),
+        Vec2D::new(1e-12, 1.0),
+    )
+    .solve(f, 0.0, Vec2D::new(1.0, 0.0), 5.0)
+    .unwrap();
+    let uniform = AdaptiveSolver::new(DormandPrince5, 1.0, 1.0)
+        .solve(f, 0.0, Vec2D::new(1.0, 0.0), 5.0)
+        .unwrap();
+    assert!(solution.accepted > 4 * uniform.accepted);
+    assert!((solution.last().1.x - 5f64.cos()).abs() < 1e-9);
+}
+
+#[test]
+fn events() {
+    // falling ball with bounces, y = (height, velocity)
+    let g = 9.81;
+    let mut events = [
+        OdeEvent::new(|_, y: &V2D64| y.x)
+            .crossing(Crossing::Falling)
+            .terminal(),
+        OdeEvent::new(|_, y: &V2D64| y.y),
+    ];
+    let solution = AdaptiveSolver::new(DormandPrince5, 1e-10, 1e-10)
+        .solve_with_events(
+            |_, y: &V2D64| Vec2D::new(y.y, -g),
+            0.0,
+            Vec2D::new(10.0, 5.0),
+            100.0,
+            &mut events,
+        )
+        .unwrap();
+    assert!(solution.terminated);
+    assert_eq!(solution.events.len(), 2);
+
+    let apex = solution.events[0];
+    assert_eq!(apex.index, 1);
+    assert!((apex.t - 5.0 / g).abs() < 1e-9);
+    assert!((apex.y.x - (10.0 + 25.0 / (2.0 * g))).abs() < 1e-9);
+
+    let ground = solution.events[1];
+    assert_eq!(ground.index, 0);
+    let expected = (5.0 + (25.0 + 2.0 * g * 10.0f64).sqrt()) / g;
+    assert!((ground.t - expected).abs() < 1e-9);
+    assert!(ground.y.x.abs() < 1e-9);
+    assert_eq!(solution.last(), (ground.t, ground.y));
+}
+
+#[test]
+fn rising_events_only() {
+    let mut events = [OdeEvent::new(|_, y: &VecND<f64, 2>| y[0]).crossing(Crossing::Rising)];
+    let solution = AdaptiveSolver::new(DormandPrince5, 1e-10, 1e-10)
+        .solve_with_events(
+            oscillator,
+            0.0,
+            VecND::from([1.0, 0.0]),
+            4.0 * PI,
+            &mut events,
+        )
+        .unwrap();
+    assert!(!solution.terminated);
+    let times: Vec<_> = solution.events.iter().map(|e| e.t).collect();
+    assert_eq!(times.len(), 2);
+    assert!((times[0] - 1.5 * PI).abs() < 1e-8);
+    assert!((times[1] - 3.5 * PI).abs() < 1e-8);
+}
+
+#[test]
+fn errors() {
+    let blow_up = AdaptiveSolver::new(DormandPrince5, 1e-6, 1e-6).solve(
+        |_, y: &Vec1D<f64>| Vec1D::new(y.x * y.x),
+        0.0,
+        Vec1D::new(1.0),
+        2.0,
+    );
+    assert!(matches!(
+        blow_up,
+        Err(OdeError::StepSizeTooSmall { t } | OdeError::MaxStepsExceeded { t }) if (t - 1.0).abs() < 1e-2
+    ));
+
+    let limited = AdaptiveSolver::new(DormandPrince5, 1e-12, 1e-12)
+        .max_steps(5)
+        .solve(oscillator, 0.0, VecND::from([1.0, 0.0]), 100.0);
+    assert!(matches!(limited, Err(OdeError::MaxStepsExceeded { .. })));
+
+    let nan = AdaptiveSolver::new(DormandPrince5, 1e-6, 1e-6).solve(
+        |_, y: &V2D64| *y,
+        0.0,
+        Vec2D::new(f64::NAN, 0.0),
+        1.0,
+    );
+    assert_eq!(nan.err(), Some(OdeError::NonFinite { t: 0.0 }));
+}
+
+#[test]
+fn step_limits() {
+    let solution = AdaptiveSolver::new(DormandPrince5, 1e-3, 1e-3)
+        .initial_step(0.01)
+        .max_step(0.1)
+        .solve(oscillator, 0.0, VecND::from([1.0, 0.0]), 1.0)
+        .unwrap();
+    assert!((solution.t[1] - 0.01).abs() < 1e-15);
+    assert!(solution.t.windows(2).all(|w| w[1] - w[0] <= 0.1 + 1e-15));
+}
+
+#[test]
+fn last_step_shorter_than_rounding() {
+    // ten steps of 0.1 fall short of 1.0 by the rounding
+    let solver = AdaptiveSolver::new(DormandPrince5, 1e-3, 1e-3).max_step(0.1);
+    let solution = solver
+        .solve(|_, y: &V2D64| -y, 0.0, Vec2D::new(1.0, 2.0), 1.0)
+        .unwrap();
+    assert_eq!(solution.last().0, 1.0);
+    assert!(solution.t.windows(2).all(|w| w[1] - w[0] <= 0.1 + 1e-15));
+    let solution = solver
+        .solve(|_, y: &V2D64| *y, 1.0, Vec2D::new(1.0, 2.0), 0.0)
+        .unwrap();
+    assert_eq!(solution.last().0, 0.0);
+}
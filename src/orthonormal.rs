@@ -0,0 +1,128 @@
+#[cfg(test)]
+mod tests;
+
+use std::error::Error;
+use std::fmt::Display;
+
+use num_traits::Float;
+use num_traits::NumCast;
+use num_traits::One;
+use num_traits::Zero;
+
+use crate::components::Components;
+use crate::Component;
+use crate::RefComponent;
+use crate::Vec3D;
+use crate::VectorRefOps;
+
+/// Failure of [`gram_schmidt`], the index of the first vector which is zero,
+/// not finite, or in the span of the preceding ones up to the rounding.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RankDeficient {
+    /// The index of the dependent vector, the rank of the preceding ones.
+    pub index: usize,
+}
+
+impl Display for RankDeficient {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "vector {} is linearly dependent", self.index)
+    }
+}
+
+impl Error for RankDeficient {}
+
+impl<T: Component> Vec3D<T>
+where
+    for<'a> &'a T: RefComponent<T>,
+{
+    /// Two unit vectors `(b1, b2)` such that `(b1, b2, self)` is a
+    /// right-handed orthonormal basis, e.g. the tangent frame of a normal.
+    ///
+    /// The vector is expected to be of the unit length. Branchless and
+    /// continuous except at `z = 0`, by Duff et al., "Building an
+    /// Orthonormal Basis, Revisited" (2017).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ::ndvec::*;
+    ///
+    /// let n = Vec3D::new(1f64, 2.0, 2.0) / 3.0;
+    /// let (b1, b2) = n.orthonormal_basis();
+    /// assert!(b1.dot(n).abs() < 1e-15 && b2.dot(n).abs() < 1e-15);
+    /// assert!(b1.dot(b2).abs() < 1e-15);
+    /// assert!((b1.norm() - 1.0).abs() < 1e-15);
+    /// ```
+    pub fn orthonormal_basis(self) -> (Self, Self) {
+        let l = T::one();
+        let sign = self.z.signum();
+        let a = -(sign + self.z).recip();
+        let b = self.x * self.y * a;
+        (
+            Self::new(l + sign * self.x * self.x * a, sign * b, -sign * self.x),
+            Self::new(b, sign + self.y * self.y * a, -self.y),
+        )
+    }
+}
+
+/// Orthonormalizes the vectors in place by the modified Gram-Schmidt
+/// process, each vector made orthogonal to the preceding ones and then
+/// normalized.
+///
+/// A vector is orthogonalized once more if it has lost most of its norm, so
+/// that the result is orthonormal to the rounding even if the vectors are
+/// nearly dependent.
+///
+/// # Errors
+///
+/// [`RankDeficient`] if a vector is zero, not finite, or in the span of the
+/// preceding ones up to the rounding, rather than producing NaN. The
+/// vectors before its index are orthonormalized, and the others unchanged.
+///
+/// # Examples
+///
+/// ```
+/// use ::ndvec::*;
+///
+/// let mut vs = [
+///     VecND::from([1f64, 1.0, 0.0, 0.0]),
+///     VecND::from([1.0, 0.0, 1.0, 0.0]),
+/// ];
+/// gram_schmidt(&mut vs).unwrap();
+/// assert!(vs[0].dot(vs[1]).abs() < 1e-15);
+///
+/// let mut vs = [Vec2D::new(1.0, 2.0), Vec2D::new(2.0, 4.0)];
+/// assert_eq!(gram_schmidt(&mut vs), Err(RankDeficient { index: 1 }));
+/// ```
+pub fn gram_schmidt<V: Components>(vectors: &mut [V]) -> Result<(), RankDeficient>
+where
+    for<'l> &'l V: VectorRefOps<V::Cmp, V>,
+{
+    let half = (V::Cmp::one() + V::Cmp::one()).recip();
+    let dim = <V::Cmp as NumCast>::from(V::DIM).expect("the dimension must be representable");
+    for k in 0..vectors.len() {
+        let (done, rest) = vectors.split_at_mut(k);
+        let mut v = rest[0];
+        let norm = v.norm_stable();
+        if !v.is_finite() || norm.is_zero() {
+            return Err(RankDeficient { index: k });
+        }
+        for q in done.iter() {
+            v -= *q * q.dot(v);
+        }
+        let mut residual = v.norm_stable();
+        // twice is enough to be orthogonal to the rounding
+        if residual < norm * half.sqrt() {
+            for q in done.iter() {
+                v -= *q * q.dot(v);
+            }
+            residual = v.norm_stable();
+        }
+        // only the rounding of the projections is left
+        if residual <= V::Cmp::epsilon() * dim * norm {
+            return Err(RankDeficient { index: k });
+        }
+        rest[0] = v / residual;
+    }
+    Ok(())
+}
@@ -0,0 +1,141 @@
+use crate::testing::Lcg;
+use crate::*;
+
+/// The largest deviation of the Gram matrix from the identity.
+fn orthonormality<V: Vector<Cmp = f64>>(vs: &[V]) -> f64
+where
+    for<'l> &'l V: VectorRefOps<f64, V>,
+{
+    let mut e = 0f64;
+    for (i, a) in vs.iter().enumerate() {
+        for (j, b) in vs.iter().enumerate() {
+            let d = if i == j { 1.0 } else { 0.0 };
+            e = e.max((a.dot(*b) - d).abs());
+        }
+    }
+    e
+}
+
+#[test]
+fn orthonormal_basis() {
+    let mut rng = Lcg(1);
+    let axes = [
+        Vec3D::new(1.0, 0.0, 0.0),
+        Vec3D::new(0.0, 1.0, 0.0),
+        Vec3D::new(0.0, 0.0, 1.0),
+        Vec3D::new(0.0, 0.0, -1.0),
+        Vec3D::new(0.0, 1.0, -0.0),
+        Vec3D::new(1.0, 0.0, -1e-300).normalize(),
+        Vec3D::new(1e-9, 0.0, -1.0).normalize(),
+    ];
+    let randoms = (0..1000).map(|_| rng.vec3().normalize());
+    for n in axes.into_iter().chain(randoms) {
+        let (b1, b2) = n.orthonormal_basis();
+        assert!(orthonormality(&[b1, b2, n]) < 1e-15, "{n:?}: {b1:?} {b2:?}");
+        // right-handed
+        assert!(b1.cross(b2).distance(n) < 1e-15, "{n:?}");
+        assert!(b1.is_finite() && b2.is_finite());
+    }
+    let (b1, b2) = Vec3D::new(0.0, 0.0, 1.0).orthonormal_basis();
+    assert_eq!(
+        (b1, b2),
+        (Vec3D::new(1.0, 0.0, 0.0), Vec3D::new(0.0, 1.0, 0.0))
+    );
+    let (b1, b2) = Vec3D::new(0f32, 0.6, -0.8).orthonormal_basis();
+    assert!(b1.dot(b2).abs() < 1e-7);
+    assert!((b1.norm() - 1.0).abs() < 1e-7 && (b2.norm() - 1.0).abs() < 1e-7);
+}
+
+#[test]
+fn gram_schmidt_orthonormalizes() {
+    let mut rng = Lcg(2);
+    for _ in 0..100 {
+        let original: [VecND<f64, 6>; 4] = std::array::from_fn(|_| rng.vector());
+        let mut vs = original;
+        assert_eq!(gram_schmidt(&mut vs), Ok(()));
+        assert!(orthonormality(&vs) < 1e-15);
+        // the same nested spans with the positive diagonal of R
+        for k in 0..4 {
+            assert!(vs[k].dot(original[k]) > 0.0);
+            for v in &vs[k + 1..] {
+                assert!(v.dot(original[k]).abs() < 1e-15);
+            }
+        }
+
+        let mut vs: [V3D64; 3] = std::array::from_fn(|_| rng.vec3());
+        gram_schmidt(&mut vs).unwrap();
+        assert!(orthonormality(&vs) < 1e-15);
+    }
+    let mut empty: [V2D64; 0] = [];
+    assert_eq!(gram_schmidt(&mut empty), Ok(()));
+    let mut vs = vec![Vec2D::new(3.0, 4.0)];
+    gram_schmidt(&mut vs).unwrap();
+    assert_eq!(vs[0], Vec2D::new(0.6, 0.8));
+}
+
+#[test]
+fn nearly_dependent_vectors() {
+    let mut rng = Lcg(3);
+    for k in 1..12 {
+        // nearly parallel, still orthonormal to the rounding
+        let a: VecND<f64, 5> = rng.vector();
+        let mut vs = [a, a + rng.vector() * 10f64.powi(-k), rng.vector()];
+        gram_schmidt(&mut vs).unwrap();
+        assert!(orthonormality(&vs) < 1e-15, "{k}");
+    }
+    // tiny and huge vectors are not mistaken for zero
+    let mut vs = [Vec2D::new(1e-300, 0.0), Vec2D::new(1e300, 1e300)];
+    gram_schmidt(&mut vs).unwrap();
+    assert_eq!(vs, [Vec2D::new(1.0, 0.0), Vec2D::new(0.0, 1.0)]);
+}
+
+#[test]
+fn gram_schmidt_of_fixed_point() {
+    let q = Fixed64::from_int;
+    let mut vs = [Vec3D::new(q(1), q(0), q(0)), Vec3D::new(q(0), q(2), q(0))];
+    assert_eq!(gram_schmidt(&mut vs), Ok(()));
+    assert_eq!(
+        vs,
+        [Vec3D::new(q(1), q(0), q(0)), Vec3D::new(q(0), q(1), q(0))]
+    );
+
+    let mut vs = [Vec2D::new(q(3), q(4)), Vec2D::new(q(10), q(0))];
+    assert_eq!(gram_schmidt(&mut vs), Ok(()));
+    let tolerance = Fixed64::DELTA * q(8);
+    assert!(
+        vs[0].distance(Vec2D::new(Fixed64::from_f64(0.6), Fixed64::from_f64(0.8))) <= tolerance
+    );
+    assert!(
+        vs[1].distance(Vec2D::new(Fixed64::from_f64(0.8), Fixed64::from_f64(-0.6))) <= tolerance
+    );
+
+    let q = Fixed32::from_int;
+    let mut vs = [Vec2D::new(q(1), q(2)), Vec2D::new(q(2), q(4))];
+    assert_eq!(gram_schmidt(&mut vs), Err(RankDeficient { index: 1 }));
+}
+
+#[test]
+fn rank_deficiency() {
+    let mut rng = Lcg(4);
+    let (a, b): (VecND<f64, 4>, VecND<f64, 4>) = (rng.vector(), rng.vector());
+    let c = rng.vector();
+    let original = [a, b, a * 2.0 - b * 3.0, c];
+    let mut vs = original;
+    let e = gram_schmidt(&mut vs);
+    assert_eq!(e, Err(RankDeficient { index: 2 }));
+    assert_eq!(e.unwrap_err().to_string(), "vector 2 is linearly dependent");
+    assert!(orthonormality(&vs[..2]) < 1e-15);
+    assert_eq!(&vs[2..], &original[2..]);
+
+    // more vectors than the dimension
+    let mut vs: [V3D64; 4] = std::array::from_fn(|_| rng.vec3());
+    assert_eq!(gram_schmidt(&mut vs), Err(RankDeficient { index: 3 }));
+    assert!(vs.iter().all(|v| v.is_finite()));
+
+    let mut vs = [Vec3D::new(0.0, 0.0, 0.0), Vec3D::new(1.0, 0.0, 0.0)];
+    assert_eq!(gram_schmidt(&mut vs), Err(RankDeficient { index: 0 }));
+    let mut vs = [Vec3D::new(1.0, 0.0, 0.0), Vec3D::new(f64::NAN, 0.0, 0.0)];
+    assert_eq!(gram_schmidt(&mut vs), Err(RankDeficient { index: 1 }));
+    let mut vs = [Vec3D::new(f64::INFINITY, 0.0, 0.0)];
+    assert_eq!(gram_schmidt(&mut vs), Err(RankDeficient { index: 0 }));
+}
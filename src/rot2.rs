@@ -0,0 +1,165 @@
+#[cfg(test)]
+mod tests;
+
+use std::ops::Mul;
+use std::ops::MulAssign;
+
+#[cfg(feature = "serde")]
+use serde::Deserialize;
+#[cfg(feature = "serde")]
+use serde::Serialize;
+
+use crate::Component;
+use crate::Rad;
+use crate::RefComponent;
+use crate::Vec2D;
+use crate::Vector;
+
+/// Rotation of the plane as the unit complex number `cos θ + i sin θ`.
+///
+/// Cheaper than [`Vec2D::rotate`] for rotating many vectors, since the
+/// sine and the cosine are computed once.
+///
+/// # Examples
+///
+/// ```
+/// use ::ndvec::*;
+///
+/// let r = Rot2::new(Deg(90.0).into());
+/// let v = r.rotate(Vec2D::new(1.0, 0.0));
+/// assert!(v.distance(Vec2D::new(0.0, 1.0)) < 1e-15);
+/// let half = Rot2::identity().slerp(r * r, 0.5);
+/// assert!((half.angle() - Rad(std::f64::consts::FRAC_PI_2)).abs() < Rad(1e-15));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Rot2<T> {
+    complex: Vec2D<T>,
+}
+
+impl<T: Component> Rot2<T>
+where
+    for<'a> &'a T: RefComponent<T>,
+{
+    /// The rotation by the angle counterclockwise.
+    pub fn new(angle: Rad<T>) -> Self {
+        Self {
+            complex: Vec2D::from_angle(angle),
+        }
+    }
+
+    /// The rotation of no angle.
+    pub fn identity() -> Self {
+        Self {
+            complex: Vec2D::new(T::one(), T::zero()),
+        }
+    }
+
+    /// The rotation by the argument of the complex number, which need not
+    /// be normalized.
+    ///
+    /// Components are NaN if the complex number is zero.
+    pub fn from_complex(z: Vec2D<T>) -> Self {
+        Self {
+            complex: z.normalize(),
+        }
+    }
+
+    /// The rotation which takes the direction of `from` to the direction
+    /// of `to`.
+    ///
+    /// Components are NaN if either is zero.
+    pub fn from_vectors(from: Vec2D<T>, to: Vec2D<T>) -> Self {
+        Self::from_complex(to.complex_mul(from.complex_conj()))
+    }
+
+    /// The angle in `[-π, π]`.
+    pub fn angle(self) -> Rad<T> {
+        self.complex.angle()
+    }
+
+    /// The cosine of the angle.
+    #[inline]
+    pub fn cos(self) -> T {
+        self.complex.x
+    }
+
+    /// The sine of the angle.
+    #[inline]
+    pub fn sin(self) -> T {
+        self.complex.y
+    }
+
+    /// The unit complex number `(cos θ, sin θ)`.
+    #[inline]
+    pub fn to_complex(self) -> Vec2D<T> {
+        self.complex
+    }
+
+    /// The inverse rotation.
+    #[inline]
+    pub fn inverse(self) -> Self {
+        Self {
+            complex: self.complex.complex_conj(),
+        }
+    }
+
+    /// Rotates the vector.
+    #[inline]
+    pub fn rotate(self, v: Vec2D<T>) -> Vec2D<T> {
+        self.complex.complex_mul(v)
+    }
+
+    /// The rotation matrix, as columns.
+    pub fn matrix(self) -> [Vec2D<T>; 2] {
+        [self.complex, self.complex.perp()]
+    }
+
+    /// The rotation at the fraction `t` of the way from `self` to `other`
+    /// along the shorter arc, with the constant angular velocity.
+    pub fn slerp(self, other: Self, t: T) -> Self {
+        let d = (self.inverse() * other).angle();
+        self * Self::new(d * t)
+    }
+
+    /// The rotation scaled to the unit complex number to remove
+    /// accumulated rounding.
+    pub fn normalize(self) -> Self {
+        Self::from_complex(self.complex)
+    }
+}
+
+impl<T: Component> Mul for Rot2<T>
+where
+    for<'a> &'a T: RefComponent<T>,
+{
+    type Output = Self;
+
+    /// The composition, the rotation by the sum of the angles.
+    #[inline]
+    fn mul(self, rhs: Self) -> Self {
+        Self {
+            complex: self.complex.complex_mul(rhs.complex),
+        }
+    }
+}
+
+impl<T: Component> MulAssign for Rot2<T>
+where
+    for<'a> &'a T: RefComponent<T>,
+{
+    #[inline]
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+impl<T: Component> From<Rad<T>> for Rot2<T>
+where
+    for<'a> &'a T: RefComponent<T>,
+{
+    #[inline]
+    fn from(angle: Rad<T>) -> Self {
+        Self::new(angle)
+    }
+}
@@ -0,0 +1,84 @@
+use std::f64::consts::PI;
+
+use crate::testing::Lcg;
+use crate::*;
+
+impl Lcg {
+    fn angle(&mut self) -> Rad<f64> {
+        Rad(2.0 * PI * self.next())
+    }
+}
+
+#[test]
+fn rotations() {
+    let mut rng = Lcg(1);
+    for _ in 0..100 {
+        let (a, b, v) = (rng.angle(), rng.angle(), rng.vec2());
+        let (ra, rb) = (Rot2::new(a), Rot2::new(b));
+        assert!((ra.angle() - a).abs() < Rad(1e-15));
+        assert!(ra.rotate(v).distance(v.rotate(a)) < 1e-15);
+        assert!((ra.rotate(v).norm() - v.norm()).abs() < 1e-15);
+        // composition adds the angles
+        let ab = ra * rb;
+        assert!(ab.rotate(v).distance(Rot2::new(a + b).rotate(v)) < 1e-15);
+        assert!(ab.rotate(v).distance(ra.rotate(rb.rotate(v))) < 1e-15);
+        let mut c = ra;
+        c *= rb;
+        assert_eq!(c, ab);
+        assert!(ra.inverse().rotate(ra.rotate(v)).distance(v) < 1e-15);
+        let m = ra.matrix();
+        assert!((m[0] * v.x + m[1] * v.y).distance(ra.rotate(v)) < 1e-15);
+        assert_eq!(ra.to_complex(), Vec2D::new(ra.cos(), ra.sin()));
+        // the direction of one vector to another
+        let w = rng.vec2();
+        let r = Rot2::from_vectors(v, w);
+        assert!(r.rotate(v).normalize().distance(w.normalize()) < 1e-15);
+        assert!(
+            Rot2::from_complex(v * 3.0)
+                .rotate(Vec2D::new(v.norm(), 0.0))
+                .distance(v)
+                < 1e-15
+        );
+    }
+    assert_eq!(Rot2::<f64>::identity().angle(), Rad(0.0));
+    assert_eq!(Rot2::from(Rad(0.0)), Rot2::identity());
+    assert!(Rot2::from_complex(Vec2D::new(0.0, 0.0)).cos().is_nan());
+}
+
+#[test]
+fn slerp_and_normalize() {
+    let mut rng = Lcg(2);
+    for _ in 0..100 {
+        let (a, b) = (rng.angle(), rng.angle());
+        let (ra, rb) = (Rot2::new(a), Rot2::new(b));
+        assert!(ra.slerp(rb, 0.0).angle().delta(a).abs() < Rad(1e-15));
+        assert!(ra.slerp(rb, 1.0).angle().delta(b).abs() < Rad(1e-14));
+        // the halfway rotation along the shorter arc
+        let m = ra.slerp(rb, 0.5).angle();
+        assert!((m.delta(a).abs() - m.delta(b).abs()).abs() < Rad(1e-14));
+        assert!(m.delta(a).abs() <= Rad(PI / 2.0 + 1e-14));
+    }
+    // across the cut at ±π
+    let m = Rot2::new(Rad(3.0)).slerp(Rot2::new(Rad(-3.0)), 0.5);
+    assert!(m.angle().abs().delta(Rad(PI)).abs() < Rad(1e-15));
+
+    let r = Rot2::new(Rad(0.1));
+    let mut q = Rot2::identity();
+    for _ in 0..10000 {
+        q *= r;
+    }
+    let n = q.normalize();
+    assert!((n.to_complex().norm() - 1.0).abs() < 1e-15);
+    assert!(n.angle().delta(Rad(1000.0)).abs() < Rad(1e-11));
+}
+
+#[test]
+fn conversions() {
+    let r = Rot2::new(Rad(1.0));
+    assert_eq!(So2::from(r).rotation, r);
+    assert_eq!(Rot2::from(So2::exp(1.0)), r);
+    let m = Isometry2::from_rotation(r);
+    assert_eq!(m.rotation, r);
+    assert_eq!(m.translation, Vec2D::new(0.0, 0.0));
+    assert_eq!(Isometry2::from_angle(Rad(1.0)), m);
+}
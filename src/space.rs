@@ -0,0 +1,75 @@
+#[cfg(test)]
+mod tests;
+
+use num_traits::Zero;
+
+use crate::Component;
+use crate::VectorAssignOps;
+use crate::VectorOps;
+
+/// Vector space: vectors can be added, negated and multiplied by scalars.
+///
+/// This, [`InnerProductSpace`], [`NormedSpace`] and [`MetricSpace`] are the
+/// structures that [`Vector`](crate::Vector) is made of. Generic algorithms can be written against the weakest
+/// structure they need, so that custom spaces such as Minkowski spacetime
+/// or spaces of functions can reuse them.
+///
+/// # Examples
+///
+/// ```
+/// use ::ndvec::*;
+///
+/// /// Orthogonal projection of `v` onto the line spanned by `u`.
+/// fn project<S: InnerProductSpace>(v: S, u: S) -> S {
+///     let k = v.dot(u.clone()) / u.clone().norm_sqr();
+///     u * k
+/// }
+///
+/// assert_eq!(
+///     project(Vec2D::new(2.0, 3.0), Vec2D::new(1.0, 0.0)),
+///     Vec2D::new(2.0, 0.0)
+/// );
+/// ```
+pub trait VectorSpace:
+    Clone + Zero + VectorOps<Self::Scalar, Self, Self> + VectorAssignOps<Self::Scalar, Self>
+{
+    /// Scalar type of the space.
+    type Scalar: Component;
+}
+
+/// Vector space with an inner product.
+///
+/// The product may be indefinite, e.g. the Minkowski inner product of
+/// spacetime, in which case [`norm_sqr`](InnerProductSpace::norm_sqr) may
+/// be negative.
+pub trait InnerProductSpace: VectorSpace {
+    /// The inner product with another vector.
+    fn dot(self, rhs: Self) -> Self::Scalar;
+
+    /// The inner product with itself, the square of the induced norm.
+    #[inline]
+    fn norm_sqr(self) -> Self::Scalar {
+        self.clone().dot(self)
+    }
+}
+
+/// Vector space with a norm.
+pub trait NormedSpace: VectorSpace {
+    /// The norm.
+    fn norm(self) -> Self::Scalar;
+}
+
+/// Space with a distance between its points.
+///
+/// Implemented for every [`NormedSpace`] by the norm of the difference.
+pub trait MetricSpace: VectorSpace {
+    /// The distance from the another point.
+    fn distance(self, rhs: Self) -> Self::Scalar;
+}
+
+impl<S: NormedSpace> MetricSpace for S {
+    #[inline]
+    fn distance(self, rhs: Self) -> Self::Scalar {
+        (self - rhs).norm()
+    }
+}
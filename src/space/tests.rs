@@ -0,0 +1,194 @@
+use std::ops::Add;
+use std::ops::AddAssign;
+use std::ops::Div;
+use std::ops::DivAssign;
+use std::ops::Mul;
+use std::ops::MulAssign;
+use std::ops::Neg;
+use std::ops::Sub;
+use std::ops::SubAssign;
+
+use num_traits::One;
+use num_traits::Zero;
+
+use crate::*;
+
+macro_rules! impl_ops {
+    ($S:ident) => {
+        impl Neg for $S {
+            type Output = Self;
+
+            fn neg(self) -> Self {
+                Self(self.0.map(|c| -c))
+            }
+        }
+
+        impl Add for $S {
+            type Output = Self;
+
+            fn add(mut self, rhs: Self) -> Self {
+                self += rhs;
+                self
+            }
+        }
+
+        impl Sub for $S {
+            type Output = Self;
+
+            fn sub(mut self, rhs: Self) -> Self {
+                self -= rhs;
+                self
+            }
+        }
+
+        impl Mul<f64> for $S {
+            type Output = Self;
+
+            fn mul(self, rhs: f64) -> Self {
+                Self(self.0.map(|c| c * rhs))
+            }
+        }
+
+        impl Div<f64> for $S {
+            type Output = Self;
+
+            fn div(self, rhs: f64) -> Self {
+                Self(self.0.map(|c| c / rhs))
+            }
+        }
+
+        impl AddAssign for $S {
+            fn add_assign(&mut self, rhs: Self) {
+                self.0.iter_mut().zip(rhs.0).for_each(|(a, b)| *a += b);
+            }
+        }
+
+        impl SubAssign for $S {
+            fn sub_assign(&mut self, rhs: Self) {
+                self.0.iter_mut().zip(rhs.0).for_each(|(a, b)| *a -= b);
+            }
+        }
+
+        impl MulAssign<f64> for $S {
+            fn mul_assign(&mut self, rhs: f64) {
+                *self = *self * rhs;
+            }
+        }
+
+        impl DivAssign<f64> for $S {
+            fn div_assign(&mut self, rhs: f64) {
+                *self = *self / rhs;
+            }
+        }
+
+        impl Zero for $S {
+            fn zero() -> Self {
+                Self(Default::default())
+            }
+
+            fn is_zero(&self) -> bool {
+                self.0.iter().all(|c| c.is_zero())
+            }
+        }
+
+        impl VectorSpace for $S {
+            type Scalar = f64;
+        }
+    };
+}
+
+/// Polynomials of degree below 3 with the inner product `∫ p q dx` on `[0, 1]`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Poly([f64; 3]);
+
+impl_ops!(Poly);
+
+impl InnerProductSpace for Poly {
+    fn dot(self, rhs: Self) -> f64 {
+        let mut s = 0.0;
+        for (i, a) in self.0.into_iter().enumerate() {
+            for (j, b) in rhs.0.into_iter().enumerate() {
+                s += a * b / (i + j + 1) as f64;
+            }
+        }
+        s
+    }
+}
+
+impl NormedSpace for Poly {
+    fn norm(self) -> f64 {
+        self.norm_sqr().sqrt()
+    }
+}
+
+/// Events of 1+1 dimensional spacetime with the signature `(+, -)`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Event([f64; 2]);
+
+impl_ops!(Event);
+
+impl InnerProductSpace for Event {
+    fn dot(self, rhs: Self) -> f64 {
+        self.0[0] * rhs.0[0] - self.0[1] * rhs.0[1]
+    }
+}
+
+fn project<S: InnerProductSpace>(v: S, u: S) -> S {
+    let k = v.dot(u.clone()) / u.clone().norm_sqr();
+    u * k
+}
+
+fn midpoint<S: VectorSpace>(a: S, b: S) -> S {
+    (a + b) / (S::Scalar::one() + S::Scalar::one())
+}
+
+fn diameter<S: MetricSpace + Copy>(points: &[S]) -> S::Scalar {
+    let mut d = S::Scalar::zero();
+    for &p in points {
+        for &q in points {
+            d = d.max(p.distance(q));
+        }
+    }
+    d
+}
+
+#[test]
+fn vectors_are_spaces() {
+    let a = Vec3D::new(1.0, 2.0, 2.0);
+    let b = Vec3D::new(3.0, 0.0, 0.0);
+    assert_eq!(midpoint(a, b), Vec3D::new(2.0, 1.0, 1.0));
+    assert_eq!(project(a, b), Vec3D::new(1.0, 0.0, 0.0));
+    assert_eq!(InnerProductSpace::dot(a, b), a.dot(b));
+    assert_eq!(NormedSpace::norm(a), 3.0);
+    assert_eq!(diameter(&[a, b, Vec3D::zero()]), a.distance(b));
+    let v = VecND::from([3.0f32, 4.0]);
+    assert_eq!(diameter(&[v, -v]), 10.0);
+}
+
+#[test]
+fn function_space() {
+    let one = Poly([1.0, 0.0, 0.0]);
+    let x = Poly([0.0, 1.0, 0.0]);
+    assert_eq!(one.norm(), 1.0);
+    assert!((x.norm_sqr() - 1.0 / 3.0).abs() < 1e-15);
+    // x - 1/2 is orthogonal to the constants
+    let p = x - project(x, one);
+    assert_eq!(p, Poly([-0.5, 1.0, 0.0]));
+    assert!(p.dot(one).abs() < 1e-15);
+    assert!((one.distance(x) - (1.0f64 / 3.0).sqrt()).abs() < 1e-15);
+    assert_eq!(midpoint(one, x), Poly([0.5, 0.5, 0.0]));
+}
+
+#[test]
+fn spacetime() {
+    let timelike = Event([2.0, 1.0]);
+    let spacelike = Event([1.0, 2.0]);
+    let lightlike = Event([1.0, 1.0]);
+    assert_eq!(timelike.norm_sqr(), 3.0);
+    assert_eq!(spacelike.norm_sqr(), -3.0);
+    assert_eq!(lightlike.norm_sqr(), 0.0);
+    assert_eq!(midpoint(timelike, spacelike), Event([1.5, 1.5]));
+    // the projection onto a timelike direction keeps the inner product
+    let p = project(spacelike, timelike);
+    assert!((p.dot(timelike) - spacelike.dot(timelike)).abs() < 1e-15);
+}
@@ -0,0 +1,262 @@
+#[cfg(test)]
+mod tests;
+
+use std::iter::FromIterator;
+use std::ops::AddAssign;
+
+use num_traits::Float;
+use num_traits::Zero;
+
+use crate::components::Components;
+use crate::Vector;
+use crate::VectorRefOps;
+
+/// Summation algorithm of [`VectorSum::sum_with`].
+///
+/// With `n` terms and the unit roundoff `u`, the error is bounded by
+/// about `n·u·Σ|x|` for [`Naive`](Summation::Naive),
+/// `log2(n)·u·Σ|x|` for [`Pairwise`](Summation::Pairwise) and
+/// `2u·Σ|x|` for the compensated ones, in addition to the final rounding.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Summation {
+    /// Plain left-to-right `+`, same as [`Sum`](std::iter::Sum).
+    Naive,
+    /// Kahan's compensated summation.
+    Kahan,
+    /// Neumaier's improvement of Kahan's summation, also correct
+    /// when a term is larger than the running sum; see [`CompensatedSum`].
+    #[default]
+    Neumaier,
+    /// Pairwise (cascade) summation without allocation proportional to `n`.
+    Pairwise,
+}
+
+/// Error-free transformation `a + b = s + e` where `s = fl(a + b)` (TwoSum).
+#[inline]
+fn two_sum<T: Float>(a: T, b: T) -> (T, T) {
+    let s = a + b;
+    let bb = s - a;
+    (s, (a - (s - bb)) + (b - bb))
+}
+
+/// Error-free transformation `a + b = s + e` by Neumaier's branch on the magnitudes.
+#[inline]
+fn fast_two_sum<T: Float>(a: T, b: T) -> (T, T) {
+    let s = a + b;
+    if a.abs() >= b.abs() {
+        (s, (a - s) + b)
+    } else {
+        (s, (b - s) + a)
+    }
+}
+
+/// Error-free transformation `a * b = p + e` where `p = fl(a * b)` (TwoProduct by FMA).
+#[inline]
+fn two_product<T: Float>(a: T, b: T) -> (T, T) {
+    let p = a * b;
+    (p, a.mul_add(b, -p))
+}
+
+/// Dot product by the algorithm Dot2 of Ogita, Rump and Oishi.
+pub(crate) fn dot2<V: Components>(a: &V, b: &V) -> V::Cmp
+where
+    for<'l> &'l V: VectorRefOps<V::Cmp, V>,
+{
+    let mut p = V::Cmp::zero();
+    let mut s = V::Cmp::zero();
+    for i in 0..V::DIM {
+        let (h, r) = two_product(a[i], b[i]);
+        let (q, e) = two_sum(p, h);
+        p = q;
+        s += e + r;
+    }
+    p + s
+}
+
+/// Accumulator of vectors with Neumaier's compensated summation.
+///
+/// The pair of the sum and the compensation is renormalized after every
+/// term, so that the compensation stays below an ulp of the sum and does
+/// not drift even over millions of `f32` terms.
+/// The result is as accurate as if the sum was computed in twice the
+/// working precision and then rounded.
+///
+/// # Examples
+///
+/// ```
+/// use ::ndvec::*;
+///
+/// let mut acc = CompensatedSum::new();
+/// acc += Vec2D::new(1e8f32, 1.0);
+/// for _ in 0..1000 {
+///     acc.add(Vec2D::new(1.0, 1e-8));
+/// }
+/// acc += Vec2D::new(-1e8, 0.0);
+/// assert_eq!(acc.value(), Vec2D::new(1000.0, 1.00001));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct CompensatedSum<V> {
+    sum: V,
+    comp: V,
+}
+
+impl<V: Components> CompensatedSum<V>
+where
+    for<'l> &'l V: VectorRefOps<V::Cmp, V>,
+{
+    /// Creates an empty accumulator.
+    pub fn new() -> Self {
+        Self {
+            sum: V::zero(),
+            comp: V::zero(),
+        }
+    }
+
+    /// Adds a vector.
+    pub fn add(&mut self, v: V) {
+        for i in 0..V::DIM {
+            let (t, e) = fast_two_sum(self.sum[i], v[i]);
+            let (s, c) = fast_two_sum(t, self.comp[i] + e);
+            self.sum[i] = s;
+            self.comp[i] = c;
+        }
+    }
+
+    /// The compensated sum of the vectors added so far.
+    pub fn value(&self) -> V {
+        self.sum + self.comp
+    }
+}
+
+impl<V: Components> Default for CompensatedSum<V>
+where
+    for<'l> &'l V: VectorRefOps<V::Cmp, V>,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<V: Components> AddAssign<V> for CompensatedSum<V>
+where
+    for<'l> &'l V: VectorRefOps<V::Cmp, V>,
+{
+    fn add_assign(&mut self, rhs: V) {
+        self.add(rhs);
+    }
+}
+
+impl<V: Components> Extend<V> for CompensatedSum<V>
+where
+    for<'l> &'l V: VectorRefOps<V::Cmp, V>,
+{
+    fn extend<I: IntoIterator<Item = V>>(&mut self, iter: I) {
+        for v in iter {
+            self.add(v);
+        }
+    }
+}
+
+impl<V: Components> FromIterator<V> for CompensatedSum<V>
+where
+    for<'l> &'l V: VectorRefOps<V::Cmp, V>,
+{
+    fn from_iter<I: IntoIterator<Item = V>>(iter: I) -> Self {
+        let mut acc = Self::new();
+        acc.extend(iter);
+        acc
+    }
+}
+
+fn sum_kahan<V: Components, I: Iterator<Item = V>>(iter: I) -> V
+where
+    for<'l> &'l V: VectorRefOps<V::Cmp, V>,
+{
+    let mut sum = V::zero();
+    let mut comp = V::zero();
+    for v in iter {
+        for i in 0..V::DIM {
+            let y = v[i] - comp[i];
+            let t = sum[i] + y;
+            comp[i] = (t - sum[i]) - y;
+            sum[i] = t;
+        }
+    }
+    sum
+}
+
+/// Number of terms summed naively at the leaves of the pairwise summation.
+const PAIRWISE_BLOCK: usize = 8;
+
+fn sum_pairwise<V: Vector, I: Iterator<Item = V>>(iter: I) -> V
+where
+    for<'l> &'l V: VectorRefOps<V::Cmp, V>,
+{
+    // partial sums of 2^level blocks, merged like a binary counter
+    let mut stack: Vec<(V, u32)> = Vec::new();
+    let mut block = V::zero();
+    let mut count = 0;
+    for v in iter {
+        block += v;
+        count += 1;
+        if count == PAIRWISE_BLOCK {
+            let mut level = 0;
+            while let Some(&(s, l)) = stack.last() {
+                if l != level {
+                    break;
+                }
+                stack.pop();
+                block = s + block;
+                level += 1;
+            }
+            stack.push((block, level));
+            block = V::zero();
+            count = 0;
+        }
+    }
+    stack.into_iter().rev().fold(block, |acc, (s, _)| s + acc)
+}
+
+/// Accurate summation of iterators of vectors.
+///
+/// # Examples
+///
+/// ```
+/// use ::ndvec::*;
+///
+/// let terms = vec![Vec3D::new(0.1f32, 0.2, 0.3); 1_000_000];
+/// let naive: V3D32 = terms.iter().copied().sum();
+/// let accurate = terms.iter().copied().sum_compensated();
+/// let exact = Vec3D::new(100000.0, 200000.0, 300000.0);
+/// assert!(accurate.distance(exact) < 0.1);
+/// assert!(naive.distance(exact) > 100.0);
+/// ```
+pub trait VectorSum<V: Components>: Iterator<Item = V> + Sized
+where
+    for<'l> &'l V: VectorRefOps<V::Cmp, V>,
+{
+    /// Sums the vectors with the given algorithm.
+    fn sum_with(self, method: Summation) -> V {
+        match method {
+            Summation::Naive => self.sum(),
+            Summation::Kahan => sum_kahan(self),
+            Summation::Neumaier => self.sum_compensated(),
+            Summation::Pairwise => sum_pairwise(self),
+        }
+    }
+
+    /// Sums the vectors with Neumaier's compensated summation.
+    fn sum_compensated(self) -> V {
+        self.collect::<CompensatedSum<V>>().value()
+    }
+
+    /// Sums the vectors with pairwise summation.
+    fn sum_pairwise(self) -> V {
+        sum_pairwise(self)
+    }
+}
+
+impl<V: Components, I: Iterator<Item = V>> VectorSum<V> for I where
+    for<'l> &'l V: VectorRefOps<V::Cmp, V>
+{
+}
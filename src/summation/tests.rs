@@ -0,0 +1,112 @@
+use crate::testing::Lcg;
+use crate::*;
+
+/// Random terms of mixed signs and magnitudes, with their sums in `f64`.
+fn terms(n: usize, seed: u64) -> (Vec<V3D32>, V3D64, V3D64) {
+    let mut rng = Lcg(seed);
+    let mut v = Vec::with_capacity(n);
+    let mut exact = V3D64::zero();
+    let mut abs = V3D64::zero();
+    for _ in 0..n {
+        let mut c = [0f32; 3];
+        for c in &mut c {
+            *c = ((rng.unit() - 0.3) * 10f64.powf(4.0 * rng.unit())) as f32;
+        }
+        let t = Vec3D::new(c[0], c[1], c[2]);
+        let t64 = Vec3D::new(t.x as f64, t.y as f64, t.z as f64);
+        exact += t64;
+        abs += Vec3D::new(t64.x.abs(), t64.y.abs(), t64.z.abs());
+        v.push(t);
+    }
+    (v, exact, abs)
+}
+
+/// Asserts `|sum - exact| <= k·u·Σ|x| + u·|exact|` componentwise.
+fn assert_bound(sum: V3D32, exact: V3D64, abs: V3D64, k: f64) {
+    let u = f32::EPSILON as f64 / 2.0;
+    for i in 0..3 {
+        let err = (sum[i] as f64 - exact[i]).abs();
+        let bound = k * u * abs[i] + u * exact[i].abs();
+        assert!(err <= bound, "{} {} {}", i, err, bound);
+    }
+}
+
+#[test]
+fn error_bounds_of_summations() {
+    for (n, seed) in [(1, 1), (7, 2), (100, 3), (12345, 4), (1_000_000, 5)] {
+        let (v, exact, abs) = terms(n, seed);
+        let it = || v.iter().copied();
+        assert_bound(it().sum_compensated(), exact, abs, 2.0);
+        assert_bound(it().sum_with(Summation::Neumaier), exact, abs, 2.0);
+        assert_bound(it().sum_with(Summation::Kahan), exact, abs, 2.0);
+        let depth = (n as f64).log2().ceil() + 8.0;
+        assert_bound(it().sum_pairwise(), exact, abs, depth);
+        let naive: V3D32 = it().sum();
+        assert_eq!(it().sum_with(Summation::Naive), naive);
+        assert_bound(naive, exact, abs, n as f64);
+    }
+}
+
+#[test]
+fn naive_summation_drifts() {
+    let v = || std::iter::repeat_n(Vec3D::new(0.1f32, 1.0, -0.01), 1 << 24);
+    let exact = Vec3D::new(0.1f32 as f64, 1.0, -0.01f32 as f64) * (1 << 24) as f64;
+    let error = |s: V3D32| (Vec3D::new(s.x as f64, s.y as f64, s.z as f64) - exact).norm();
+    let naive: V3D32 = v().sum();
+    assert!(error(naive) > 1e5);
+    assert!(error(v().sum_compensated()) < 1.0);
+    assert!(error(v().sum_with(Summation::Kahan)) < 1.0);
+    assert!(error(v().sum_pairwise()) < 1.0);
+}
+
+#[test]
+fn neumaier_handles_large_terms() {
+    let v = [1.0, 1e100, 1.0, -1e100].map(|x| VecND::from([x, -x]));
+    assert_eq!(v.into_iter().sum_compensated(), VecND::from([2.0, -2.0]));
+    // Kahan loses the small terms when a term exceeds the running sum
+    assert_ne!(
+        v.into_iter().sum_with(Summation::Kahan),
+        VecND::from([2.0, -2.0])
+    );
+}
+
+#[test]
+fn accumulator() {
+    let mut acc = CompensatedSum::default();
+    assert_eq!(acc.value(), Vec2D::new(0.0, 0.0));
+    acc += Vec2D::new(1.0, 2.0);
+    acc.extend([Vec2D::new(1e-20, 0.0), Vec2D::new(-1.0, -2.0)]);
+    assert_eq!(acc.value(), Vec2D::new(1e-20, 0.0));
+    let acc: CompensatedSum<V1D64> = (0..10).map(|_| Vec1D::new(0.1)).collect();
+    assert_eq!(acc.value(), Vec1D::new(1.0));
+    assert_eq!(std::iter::empty::<V2D64>().sum_pairwise(), Vec2D::zero());
+}
+
+#[test]
+fn accurate_dot() {
+    let a = VecND::from([1e16, 1.0, -1e16, 1e-3]);
+    let b = VecND::from([1.0; 4]);
+    assert_eq!(a.dot(b), 1e-3);
+    assert_eq!(a.dot_accurate(b), 1.001);
+
+    // products and sums of f32 are exact in f64 for these ranges
+    let mut rng = Lcg(9);
+    for _ in 0..1000 {
+        let mut c = [0f32; 6];
+        for c in &mut c {
+            *c = ((rng.unit() - 0.5) * 1e3) as f32;
+        }
+        let x = Vec3D::new(c[0], c[1], c[2]);
+        let y = Vec3D::new(c[3], c[4], -(c[0] * c[3] + c[1] * c[4]) / c[2]);
+        let exact: f64 = (0..3).map(|i| x[i] as f64 * y[i] as f64).sum();
+        let abs: f64 = (0..3).map(|i| (x[i] as f64 * y[i] as f64).abs()).sum();
+        let u = f32::EPSILON as f64 / 2.0;
+        let err = (x.dot_accurate(y) as f64 - exact).abs();
+        assert!(
+            err <= u * exact.abs() + 16.0 * u * u * abs,
+            "{} {}",
+            err,
+            exact
+        );
+    }
+}
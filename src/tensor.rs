@@ -0,0 +1,419 @@
+#[cfg(test)]
+mod tests;
+
+use std::ops::Add;
+use std::ops::AddAssign;
+use std::ops::Div;
+use std::ops::Mul;
+use std::ops::Neg;
+use std::ops::Sub;
+use std::ops::SubAssign;
+
+use num_traits::Zero;
+#[cfg(feature = "serde")]
+use serde::Deserialize;
+#[cfg(feature = "serde")]
+use serde::Serialize;
+
+use crate::Component;
+use crate::InnerProductSpace;
+use crate::MatrixND;
+use crate::RefComponent;
+use crate::SymmetricEigen3;
+use crate::Vec3D;
+
+/// Symmetric rank-2 tensor of the 3D space, e.g. a stress, a strain, an
+/// inertia or a covariance tensor, as the six independent components.
+///
+/// # Examples
+///
+/// ```
+/// use ::ndvec::*;
+///
+/// // the uniaxial tension along x
+/// let stress = SymmetricTensor3::from_diagonal(Vec3D::new(3f64, 0.0, 0.0));
+/// assert_eq!(stress.trace(), 3.0);
+/// assert_eq!(stress.deviatoric().diagonal(), Vec3D::new(2.0, -1.0, -1.0));
+/// assert!((stress.von_mises() - 3.0).abs() < 1e-15);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SymmetricTensor3<T> {
+    pub xx: T,
+    pub yy: T,
+    pub zz: T,
+    pub yz: T,
+    pub xz: T,
+    pub xy: T,
+}
+
+/// Antisymmetric rank-2 tensor of the 3D space, e.g. a spin or a rotation
+/// rate, as its axial vector `w` with `W v = w × v`.
+///
+/// # Examples
+///
+/// ```
+/// use ::ndvec::*;
+///
+/// let spin = AntisymmetricTensor3::new(Vec3D::new(0f64, 0.0, 1.0));
+/// assert_eq!(spin * Vec3D::new(1.0, 0.0, 0.0), Vec3D::new(0.0, 1.0, 0.0));
+/// assert_eq!(spin.to_matrix()[(1, 0)], 1.0);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct AntisymmetricTensor3<T> {
+    /// The axial vector.
+    pub axial: Vec3D<T>,
+}
+
+impl<T: Component> SymmetricTensor3<T>
+where
+    for<'a> &'a T: RefComponent<T>,
+{
+    #[inline]
+    pub fn new(xx: T, yy: T, zz: T, yz: T, xz: T, xy: T) -> Self {
+        Self {
+            xx,
+            yy,
+            zz,
+            yz,
+            xz,
+            xy,
+        }
+    }
+
+    /// The identity tensor.
+    pub fn identity() -> Self {
+        Self::from_diagonal(Vec3D::new(T::one(), T::one(), T::one()))
+    }
+
+    /// The diagonal tensor of the components.
+    pub fn from_diagonal(d: Vec3D<T>) -> Self {
+        let o = T::zero();
+        Self::new(d.x, d.y, d.z, o, o, o)
+    }
+
+    /// The symmetric part `(a bᵀ + b aᵀ) / 2` of the outer product, `a aᵀ`
+    /// if the same.
+    pub fn from_outer(a: Vec3D<T>, b: Vec3D<T>) -> Self {
+        let half = (T::one() + T::one()).recip();
+        Self::new(
+            a.x * b.x,
+            a.y * b.y,
+            a.z * b.z,
+            (a.y * b.z + a.z * b.y) * half,
+            (a.x * b.z + a.z * b.x) * half,
+            (a.x * b.y + a.y * b.x) * half,
+        )
+    }
+
+    /// The symmetric part `(A + Aᵀ) / 2` of the matrix.
+    pub fn from_matrix(m: MatrixND<T, 3, 3>) -> Self {
+        let half = (T::one() + T::one()).recip();
+        Self::new(
+            m[(0, 0)],
+            m[(1, 1)],
+            m[(2, 2)],
+            (m[(1, 2)] + m[(2, 1)]) * half,
+            (m[(0, 2)] + m[(2, 0)]) * half,
+            (m[(0, 1)] + m[(1, 0)]) * half,
+        )
+    }
+
+    /// The matrix of the components.
+    pub fn to_matrix(self) -> MatrixND<T, 3, 3> {
+        MatrixND::from_rows([
+            [self.xx, self.xy, self.xz],
+            [self.xy, self.yy, self.yz],
+            [self.xz, self.yz, self.zz],
+        ])
+    }
+
+    /// The diagonal components.
+    #[inline]
+    pub fn diagonal(self) -> Vec3D<T> {
+        Vec3D::new(self.xx, self.yy, self.zz)
+    }
+
+    /// The sum of the diagonal components, the first invariant.
+    #[inline]
+    pub fn trace(self) -> T {
+        self.xx + self.yy + self.zz
+    }
+
+    /// The double contraction `A : B`, the sum of the products of the
+    /// components.
+    pub fn double_contraction(self, other: Self) -> T {
+        let off = self.yz * other.yz + self.xz * other.xz + self.xy * other.xy;
+        self.diagonal().dot(other.diagonal()) + off + off
+    }
+
+    /// The Frobenius norm `√(A : A)`.
+    pub fn norm(self) -> T {
+        self.double_contraction(self).sqrt()
+    }
+
+    /// The determinant, the third invariant.
+    pub fn determinant(self) -> T {
+        let Self {
+            xx,
+            yy,
+            zz,
+            yz,
+            xz,
+            xy,
+        } = self;
+        let two = T::one() + T::one();
+        xx * yy * zz + two * xy * yz * xz - xx * yz * yz - yy * xz * xz - zz * xy * xy
+    }
+
+    /// The spherical part `tr(A) / 3 I`, e.g. the hydrostatic stress.
+    pub fn spherical(self) -> Self {
+        let three = T::one() + T::one() + T::one();
+        Self::identity() * (self.trace() / three)
+    }
+
+    /// The deviatoric part `A - tr(A) / 3 I` of no trace.
+    pub fn deviatoric(self) -> Self {
+        self - self.spherical()
+    }
+
+    /// The principal invariants `(I₁, I₂, I₃)`, the coefficients of the
+    /// characteristic polynomial `λ³ - I₁ λ² + I₂ λ - I₃`.
+    pub fn invariants(self) -> (T, T, T) {
+        let Self {
+            xx,
+            yy,
+            zz,
+            yz,
+            xz,
+            xy,
+        } = self;
+        let i2 = xx * yy + yy * zz + zz * xx - yz * yz - xz * xz - xy * xy;
+        (self.trace(), i2, self.determinant())
+    }
+
+    /// The invariants `(J₂, J₃)` of the deviatoric part `s`, `J₂ = s : s / 2`
+    /// and `J₃ = det s`.
+    pub fn deviatoric_invariants(self) -> (T, T) {
+        let s = self.deviatoric();
+        let half = (T::one() + T::one()).recip();
+        (s.double_contraction(s) * half, s.determinant())
+    }
+
+    /// The von Mises equivalent stress `√(3 J₂)`.
+    pub fn von_mises(self) -> T {
+        let three = T::one() + T::one() + T::one();
+        (three * self.deviatoric_invariants().0).sqrt()
+    }
+
+    /// The principal values in the ascending order and the principal axes.
+    pub fn eigen(self) -> SymmetricEigen3<T> {
+        SymmetricEigen3::new(self.to_matrix().into())
+    }
+}
+
+impl<T: Component> AntisymmetricTensor3<T>
+where
+    for<'a> &'a T: RefComponent<T>,
+{
+    /// The tensor of the axial vector.
+    #[inline]
+    pub fn new(axial: Vec3D<T>) -> Self {
+        Self { axial }
+    }
+
+    /// The antisymmetric part `(a bᵀ - b aᵀ) / 2` of the outer product.
+    pub fn from_outer(a: Vec3D<T>, b: Vec3D<T>) -> Self {
+        let half = (T::one() + T::one()).recip();
+        Self::new(b.cross(a) * half)
+    }
+
+    /// The antisymmetric part `(A - Aᵀ) / 2` of the matrix.
+    pub fn from_matrix(m: MatrixND<T, 3, 3>) -> Self {
+        let half = (T::one() + T::one()).recip();
+        Self::new(Vec3D::new(
+            (m[(2, 1)] - m[(1, 2)]) * half,
+            (m[(0, 2)] - m[(2, 0)]) * half,
+            (m[(1, 0)] - m[(0, 1)]) * half,
+        ))
+    }
+
+    /// The matrix of the components, the cross product matrix of the axial
+    /// vector.
+    pub fn to_matrix(self) -> MatrixND<T, 3, 3> {
+        let Vec3D { x, y, z } = self.axial;
+        let o = T::zero();
+        MatrixND::from_rows([[o, -z, y], [z, o, -x], [-y, x, o]])
+    }
+
+    /// The double contraction `W : V`, twice the dot product of the axial
+    /// vectors.
+    pub fn double_contraction(self, other: Self) -> T {
+        let d = self.axial.dot(other.axial);
+        d + d
+    }
+
+    /// The Frobenius norm `√(W : W)`.
+    pub fn norm(self) -> T {
+        self.double_contraction(self).sqrt()
+    }
+}
+
+impl<T: Component> Mul<Vec3D<T>> for SymmetricTensor3<T>
+where
+    for<'a> &'a T: RefComponent<T>,
+{
+    type Output = Vec3D<T>;
+
+    /// The contraction `A v`.
+    fn mul(self, v: Vec3D<T>) -> Vec3D<T> {
+        Vec3D::new(
+            self.xx * v.x + self.xy * v.y + self.xz * v.z,
+            self.xy * v.x + self.yy * v.y + self.yz * v.z,
+            self.xz * v.x + self.yz * v.y + self.zz * v.z,
+        )
+    }
+}
+
+impl<T: Component> Mul<Vec3D<T>> for AntisymmetricTensor3<T>
+where
+    for<'a> &'a T: RefComponent<T>,
+{
+    type Output = Vec3D<T>;
+
+    /// The contraction `W v = w × v`.
+    #[inline]
+    fn mul(self, v: Vec3D<T>) -> Vec3D<T> {
+        self.axial.cross(v)
+    }
+}
+
+impl<T: Component> From<SymmetricTensor3<T>> for MatrixND<T, 3, 3>
+where
+    for<'a> &'a T: RefComponent<T>,
+{
+    #[inline]
+    fn from(a: SymmetricTensor3<T>) -> Self {
+        a.to_matrix()
+    }
+}
+
+impl<T: Component> From<AntisymmetricTensor3<T>> for MatrixND<T, 3, 3>
+where
+    for<'a> &'a T: RefComponent<T>,
+{
+    #[inline]
+    fn from(w: AntisymmetricTensor3<T>) -> Self {
+        w.to_matrix()
+    }
+}
+
+macro_rules! impl_tensor_ops {
+    ($S:ident { $($f:ident),* }) => {
+        impl<T: Component> Zero for $S<T>
+        where
+            for<'a> &'a T: RefComponent<T>,
+        {
+            #[inline]
+            fn zero() -> Self {
+                Self { $($f: Zero::zero()),* }
+            }
+
+            #[inline]
+            fn is_zero(&self) -> bool {
+                true $(&& self.$f.is_zero())*
+            }
+        }
+
+        impl<T: Component> Neg for $S<T>
+        where
+            for<'a> &'a T: RefComponent<T>,
+        {
+            type Output = Self;
+
+            #[inline]
+            fn neg(self) -> Self {
+                Self { $($f: -self.$f),* }
+            }
+        }
+
+        impl<T: Component> Add for $S<T>
+        where
+            for<'a> &'a T: RefComponent<T>,
+        {
+            type Output = Self;
+
+            #[inline]
+            fn add(self, rhs: Self) -> Self {
+                Self { $($f: self.$f + rhs.$f),* }
+            }
+        }
+
+        impl<T: Component> Sub for $S<T>
+        where
+            for<'a> &'a T: RefComponent<T>,
+        {
+            type Output = Self;
+
+            #[inline]
+            fn sub(self, rhs: Self) -> Self {
+                Self { $($f: self.$f - rhs.$f),* }
+            }
+        }
+
+        impl<T: Component> Mul<T> for $S<T>
+        where
+            for<'a> &'a T: RefComponent<T>,
+        {
+            type Output = Self;
+
+            #[inline]
+            fn mul(self, rhs: T) -> Self {
+                Self { $($f: self.$f * rhs),* }
+            }
+        }
+
+        impl<T: Component> Div<T> for $S<T>
+        where
+            for<'a> &'a T: RefComponent<T>,
+        {
+            type Output = Self;
+
+            #[inline]
+            fn div(self, rhs: T) -> Self {
+                Self { $($f: self.$f / rhs),* }
+            }
+        }
+
+        impl<T: Component> AddAssign for $S<T>
+        where
+            for<'a> &'a T: RefComponent<T>,
+        {
+            #[inline]
+            fn add_assign(&mut self, rhs: Self) {
+                *self = *self + rhs;
+            }
+        }
+
+        impl<T: Component> SubAssign for $S<T>
+        where
+            for<'a> &'a T: RefComponent<T>,
+        {
+            #[inline]
+            fn sub_assign(&mut self, rhs: Self) {
+                *self = *self - rhs;
+            }
+        }
+    };
+}
+
+impl_tensor_ops!(SymmetricTensor3 {
+    xx,
+    yy,
+    zz,
+    yz,
+    xz,
+    xy
+});
+impl_tensor_ops!(AntisymmetricTensor3 { axial });
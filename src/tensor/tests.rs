@@ -0,0 +1,139 @@
+use crate::testing::Lcg;
+use crate::*;
+
+impl Lcg {
+    fn symmetric(&mut self) -> SymmetricTensor3<f64> {
+        let [xx, yy, zz, yz, xz, xy] = [(); 6].map(|_| self.next());
+        SymmetricTensor3::new(xx, yy, zz, yz, xz, xy)
+    }
+}
+
+fn distance(a: MatrixND<f64, 3, 3>, b: MatrixND<f64, 3, 3>) -> f64 {
+    (a - b).frobenius_norm()
+}
+
+#[test]
+fn outer_products() {
+    let (a, b) = (Vec3D::new(1.0, 2.0, 3.0), Vec3D::new(4.0, 5.0, 6.0));
+    let m = a.outer(b);
+    assert_eq!(m.rows()[1], [8.0, 10.0, 12.0]);
+    assert_eq!(m, VecND::from(a).outer(VecND::from(b)));
+    assert_eq!(m.transpose(), b.outer(a));
+    assert_eq!(m.trace(), a.dot(b));
+    // (a bᵀ) c = a (b · c)
+    let c = Vec3D::new(-1.0, 0.5, 2.0);
+    assert_eq!(Vec3D::from(m * VecND::from(c)), a * b.dot(c));
+    assert_eq!(
+        Vec2D::new(1.0, 2.0).outer(Vec2D::new(3.0, 4.0)).rows(),
+        [[3.0, 4.0], [6.0, 8.0]]
+    );
+    let wide = VecND::from([1.0, 2.0]).outer(VecND::from([1.0, 0.0, -1.0]));
+    assert_eq!(wide.rows(), [[1.0, 0.0, -1.0], [2.0, 0.0, -2.0]]);
+    assert_eq!(m.double_contraction(MatrixND::identity()), m.trace());
+    assert_eq!(m.double_contraction(m), m.frobenius_norm().powi(2));
+}
+
+#[test]
+fn symmetric_and_antisymmetric_parts() {
+    let mut rng = Lcg(1);
+    for _ in 0..100 {
+        let (a, b) = (rng.vec3(), rng.vec3());
+        let m = a.outer(b);
+        let s = SymmetricTensor3::from_outer(a, b);
+        let w = AntisymmetricTensor3::from_outer(a, b);
+        assert!(distance(s.to_matrix() + w.to_matrix(), m) < 1e-15);
+        assert_eq!(SymmetricTensor3::from_matrix(m), s);
+        assert!(AntisymmetricTensor3::from_matrix(m).axial.distance(w.axial) < 1e-15);
+        assert!(s.to_matrix().is_symmetric());
+        assert_eq!(w.to_matrix().transpose(), -w.to_matrix());
+        assert_eq!(SymmetricTensor3::from_outer(a, a).to_matrix(), a.outer(a));
+        assert!(AntisymmetricTensor3::from_outer(a, a).is_zero());
+
+        let v = rng.vec3();
+        let sv = Vec3D::from(s.to_matrix() * VecND::from(v));
+        assert!((s * v).distance(sv) < 1e-15);
+        let wv = Vec3D::from(w.to_matrix() * VecND::from(v));
+        assert!((w * v).distance(wv) < 1e-15);
+        assert_eq!(MatrixND::from(w), So3::hat(w.axial).into());
+        assert_eq!(MatrixND::from(s), s.to_matrix());
+
+        // the symmetric and the antisymmetric tensors are orthogonal
+        assert!(s.to_matrix().double_contraction(w.to_matrix()).abs() < 1e-15);
+        let (t, u) = (rng.symmetric(), AntisymmetricTensor3::new(rng.vec3()));
+        let st = s.double_contraction(t);
+        assert!((st - s.to_matrix().double_contraction(t.to_matrix())).abs() < 1e-15);
+        let wu = w.double_contraction(u);
+        assert!((wu - w.to_matrix().double_contraction(u.to_matrix())).abs() < 1e-15);
+        assert!((s.norm() - s.to_matrix().frobenius_norm()).abs() < 1e-15);
+        assert!((w.norm() - w.to_matrix().frobenius_norm()).abs() < 1e-15);
+    }
+}
+
+#[test]
+fn invariants() {
+    let mut rng = Lcg(2);
+    for _ in 0..100 {
+        let s = rng.symmetric();
+        let (i1, i2, i3) = s.invariants();
+        assert_eq!(i1, s.trace());
+        assert!((i3 - s.to_matrix().determinant()).abs() < 1e-15);
+        // the principal values are the roots of the characteristic polynomial
+        let e = s.eigen();
+        let Vec3D { x, y, z } = e.values;
+        assert!((i1 - (x + y + z)).abs() < 1e-15);
+        assert!((i2 - (x * y + y * z + z * x)).abs() < 1e-15);
+        assert!((i3 - x * y * z).abs() < 1e-15);
+        for (l, v) in [x, y, z].into_iter().zip(e.vectors) {
+            assert!((s * v).distance(v * l) < 1e-15);
+        }
+
+        let d = s.deviatoric();
+        assert!(d.trace().abs() < 1e-15);
+        assert!(distance((d + s.spherical()).to_matrix(), s.to_matrix()) < 1e-15);
+        assert!(d.double_contraction(s.spherical()).abs() < 1e-15);
+        let (j2, j3) = s.deviatoric_invariants();
+        // J₂ = I₁² / 3 - I₂, J₃ = 2 I₁³ / 27 - I₁ I₂ / 3 + I₃
+        assert!((j2 - (i1 * i1 / 3.0 - i2)).abs() < 1e-15);
+        assert!((j3 - (2.0 * i1.powi(3) / 27.0 - i1 * i2 / 3.0 + i3)).abs() < 1e-15);
+        // unchanged by the pressure
+        let p = s + SymmetricTensor3::identity() * rng.next();
+        assert!((p.von_mises() - s.von_mises()).abs() < 1e-15);
+    }
+    // the pure shear τ
+    let shear = SymmetricTensor3::new(0.0, 0.0, 0.0, 0.0, 0.0, 2.0);
+    assert_eq!(shear.deviatoric_invariants(), (4.0, 0.0));
+    assert!((shear.von_mises() - 2.0 * 3f64.sqrt()).abs() < 1e-15);
+    assert_eq!(SymmetricTensor3::identity().invariants(), (3.0, 3.0, 1.0));
+}
+
+#[test]
+fn arithmetic() {
+    let s = SymmetricTensor3::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0);
+    let t = SymmetricTensor3::from_diagonal(Vec3D::new(1.0, 1.0, 1.0));
+    assert_eq!(t, SymmetricTensor3::identity());
+    assert_eq!(s + t, SymmetricTensor3::new(2.0, 3.0, 4.0, 4.0, 5.0, 6.0));
+    assert_eq!(s - s, SymmetricTensor3::zero());
+    assert_eq!(
+        -s * 2.0,
+        SymmetricTensor3::new(-2.0, -4.0, -6.0, -8.0, -10.0, -12.0)
+    );
+    assert_eq!(s / 2.0 * 2.0, s);
+    let mut u = s;
+    u += t;
+    u -= s;
+    assert_eq!(u, t);
+    assert_eq!(s.diagonal(), Vec3D::new(1.0, 2.0, 3.0));
+    assert!(!s.is_zero());
+
+    let w = AntisymmetricTensor3::new(Vec3D::new(1.0, 2.0, 3.0));
+    let v = AntisymmetricTensor3::new(Vec3D::new(0.0, 1.0, 0.0));
+    assert_eq!((w + v).axial, Vec3D::new(1.0, 3.0, 3.0));
+    assert_eq!((w - v).axial, Vec3D::new(1.0, 1.0, 3.0));
+    assert_eq!((-w * 2.0 / 4.0).axial, Vec3D::new(-0.5, -1.0, -1.5));
+    let mut x = w;
+    x += v;
+    x -= w;
+    assert_eq!(x, v);
+    assert_eq!(w.double_contraction(v), 4.0);
+    assert!(AntisymmetricTensor3::<f64>::zero().is_zero());
+}
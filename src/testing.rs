@@ -0,0 +1,51 @@
+//! Fixtures shared by the unit tests.
+
+use crate::MatrixND;
+use crate::Vec2D;
+use crate::Vec3D;
+use crate::VecND;
+use crate::V2D64;
+use crate::V3D64;
+
+/// Deterministic pseudo-random numbers from a 64-bit linear congruential
+/// generator seeded with the field.
+///
+/// Test modules add their own sampling methods in local `impl` blocks.
+pub(crate) struct Lcg(pub(crate) u64);
+
+impl Lcg {
+    /// The next 53 random bits.
+    pub(crate) fn bits(&mut self) -> u64 {
+        self.0 = self
+            .0
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        self.0 >> 11
+    }
+
+    /// Uniform in `[0, 1)`.
+    pub(crate) fn unit(&mut self) -> f64 {
+        self.bits() as f64 / (1u64 << 53) as f64
+    }
+
+    /// Uniform in `[-0.5, 0.5)`.
+    pub(crate) fn next(&mut self) -> f64 {
+        self.unit() - 0.5
+    }
+
+    pub(crate) fn vec2(&mut self) -> V2D64 {
+        Vec2D::new(self.next(), self.next())
+    }
+
+    pub(crate) fn vec3(&mut self) -> V3D64 {
+        Vec3D::new(self.next(), self.next(), self.next())
+    }
+
+    pub(crate) fn vector<const N: usize>(&mut self) -> VecND<f64, N> {
+        VecND::from(std::array::from_fn(|_| self.next()))
+    }
+
+    pub(crate) fn matrix<const R: usize, const C: usize>(&mut self) -> MatrixND<f64, R, C> {
+        MatrixND::from_fn(|_, _| self.next())
+    }
+}
@@ -0,0 +1,572 @@
+#[cfg(test)]
+mod tests;
+
+use std::ops::Mul;
+use std::ops::MulAssign;
+
+use num_traits::Float;
+use num_traits::One;
+use num_traits::Zero;
+#[cfg(feature = "serde")]
+use serde::Deserialize;
+#[cfg(feature = "serde")]
+use serde::Serialize;
+
+use crate::components::Components;
+use crate::Component;
+use crate::Rad;
+use crate::RefComponent;
+use crate::Rot2;
+use crate::Rotor3;
+use crate::Vec2D;
+use crate::Vec3D;
+use crate::VectorRefOps;
+
+/// Rigid motion of the plane: a rotation followed by a translation.
+///
+/// Points are rotated and translated by
+/// [`transform_point`](Isometry2::transform_point), while directions are
+/// only rotated by [`transform_vector`](Isometry2::transform_vector).
+///
+/// # Examples
+///
+/// ```
+/// use ::ndvec::*;
+///
+/// let m = Isometry2::new(Deg(90.0).into(), Vec2D::new(1.0, 0.0));
+/// let p = m.transform_point(Vec2D::new(1.0, 0.0));
+/// assert!(p.distance(Vec2D::new(1.0, 1.0)) < 1e-15);
+/// let v = m.transform_vector(Vec2D::new(1.0, 0.0));
+/// assert!(v.distance(Vec2D::new(0.0, 1.0)) < 1e-15);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Isometry2<T> {
+    /// The rotation.
+    pub rotation: Rot2<T>,
+    /// The translation applied after the rotation.
+    pub translation: Vec2D<T>,
+}
+
+/// Rigid motion of the space: a rotation followed by a translation.
+///
+/// # Examples
+///
+/// ```
+/// use std::f64::consts::FRAC_PI_2;
+/// use ::ndvec::*;
+///
+/// let r = Rotor3::from_axis_angle(Vec3D::new(0.0, 0.0, 1.0), Rad(FRAC_PI_2));
+/// let m = Isometry3::new(r, Vec3D::new(0.0, 0.0, 1.0));
+/// let p = m.transform_point(Vec3D::new(1.0, 0.0, 0.0));
+/// assert!(p.distance(Vec3D::new(0.0, 1.0, 1.0)) < 1e-15);
+/// assert!(m.inverse().transform_point(p).distance(Vec3D::new(1.0, 0.0, 0.0)) < 1e-15);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Isometry3<T> {
+    /// The rotation, expected to be of the unit norm.
+    pub rotation: Rotor3<T>,
+    /// The translation applied after the rotation.
+    pub translation: Vec3D<T>,
+}
+
+/// Rigid motion of the plane with a uniform scale: `p ↦ R(s p) + t`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Similarity2<T> {
+    /// The rotation and the translation applied after the scale.
+    pub isometry: Isometry2<T>,
+    /// The uniform scale, applied first.
+    pub scale: T,
+}
+
+/// Rigid motion of the space with a uniform scale: `p ↦ R(s p) + t`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Similarity3<T> {
+    /// The rotation and the translation applied after the scale.
+    pub isometry: Isometry3<T>,
+    /// The uniform scale, applied first.
+    pub scale: T,
+}
+
+/// Affine map of the plane: `p ↦ A p + t` of any linear map `A`.
+///
+/// # Examples
+///
+/// ```
+/// use ::ndvec::*;
+///
+/// // shear along the x-axis
+/// let m = Affine2::new(
+///     [Vec2D::new(1.0, 0.0), Vec2D::new(1.0, 1.0)],
+///     Vec2D::new(0.0, 2.0),
+/// );
+/// assert_eq!(m.transform_point(Vec2D::new(1.0, 1.0)), Vec2D::new(2.0, 3.0));
+/// assert_eq!(m.transform_vector(Vec2D::new(1.0, 1.0)), Vec2D::new(2.0, 1.0));
+/// let p = m.inverse().unwrap().transform_point(Vec2D::new(2.0, 3.0));
+/// assert_eq!(p, Vec2D::new(1.0, 1.0));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Affine2<T> {
+    /// Columns of the linear part, i.e. the images of the basis vectors.
+    pub linear: [Vec2D<T>; 2],
+    /// The translation applied after the linear part.
+    pub translation: Vec2D<T>,
+}
+
+/// Affine map of the space: `p ↦ A p + t` of any linear map `A`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Affine3<T> {
+    /// Columns of the linear part, i.e. the images of the basis vectors.
+    pub linear: [Vec3D<T>; 3],
+    /// The translation applied after the linear part.
+    pub translation: Vec3D<T>,
+}
+
+impl<T: Component> Isometry2<T>
+where
+    for<'a> &'a T: RefComponent<T>,
+{
+    /// The rotation by the angle counterclockwise, then the translation.
+    pub fn new(angle: Rad<T>, translation: Vec2D<T>) -> Self {
+        Self {
+            rotation: Rot2::new(angle),
+            translation,
+        }
+    }
+
+    /// The isometry of no motion.
+    pub fn identity() -> Self {
+        Self::new(Rad::zero(), Vec2D::zero())
+    }
+
+    /// The translation only.
+    pub fn from_translation(translation: Vec2D<T>) -> Self {
+        Self::new(Rad::zero(), translation)
+    }
+
+    /// The rotation around the origin only.
+    pub fn from_angle(angle: Rad<T>) -> Self {
+        Self::new(angle, Vec2D::zero())
+    }
+
+    /// The rotation around the origin only.
+    pub fn from_rotation(rotation: Rot2<T>) -> Self {
+        Self {
+            rotation,
+            translation: Vec2D::zero(),
+        }
+    }
+
+    /// The rotation angle in `[-π, π]`.
+    pub fn angle(self) -> Rad<T> {
+        self.rotation.angle()
+    }
+
+    /// Rotates and translates the point.
+    #[inline]
+    pub fn transform_point(self, p: Vec2D<T>) -> Vec2D<T> {
+        self.transform_vector(p) + self.translation
+    }
+
+    /// Rotates the direction, ignoring the translation.
+    #[inline]
+    pub fn transform_vector(self, v: Vec2D<T>) -> Vec2D<T> {
+        self.rotation.rotate(v)
+    }
+
+    /// The inverse motion, which undoes this one.
+    pub fn inverse(self) -> Self {
+        let rotation = self.rotation.inverse();
+        Self {
+            rotation,
+            translation: -rotation.rotate(self.translation),
+        }
+    }
+
+    /// Interpolation by the fraction `t` from `self` to `other`: the angle
+    /// is interpolated along the shorter arc and the translation linearly.
+    pub fn interpolate(self, other: Self, t: T) -> Self {
+        Self {
+            rotation: self.rotation.slerp(other.rotation, t),
+            translation: self.translation + (other.translation - self.translation) * t,
+        }
+    }
+}
+
+impl<T: Component> Isometry3<T>
+where
+    for<'a> &'a T: RefComponent<T>,
+{
+    /// The rotation by the rotor, then the translation.
+    #[inline]
+    pub fn new(rotation: Rotor3<T>, translation: Vec3D<T>) -> Self {
+        Self {
+            rotation,
+            translation,
+        }
+    }
+
+    /// The isometry of no motion.
+    pub fn identity() -> Self {
+        Self::new(Rotor3::identity(), Vec3D::zero())
+    }
+
+    /// The translation only.
+    pub fn from_translation(translation: Vec3D<T>) -> Self {
+        Self::new(Rotor3::identity(), translation)
+    }
+
+    /// The rotation around the origin only.
+    pub fn from_rotation(rotation: Rotor3<T>) -> Self {
+        Self::new(rotation, Vec3D::zero())
+    }
+
+    /// Rotates and translates the point.
+    #[inline]
+    pub fn transform_point(self, p: Vec3D<T>) -> Vec3D<T> {
+        self.rotation.rotate(p) + self.translation
+    }
+
+    /// Rotates the direction, ignoring the translation.
+    #[inline]
+    pub fn transform_vector(self, v: Vec3D<T>) -> Vec3D<T> {
+        self.rotation.rotate(v)
+    }
+
+    /// The inverse motion, which undoes this one.
+    pub fn inverse(self) -> Self {
+        let rotation = self.rotation.reverse();
+        Self::new(rotation, -rotation.rotate(self.translation))
+    }
+
+    /// Interpolation by the fraction `t` from `self` to `other`: the rotation
+    /// is interpolated by [`slerp`](Rotor3::slerp) and the translation linearly.
+    pub fn interpolate(self, other: Self, t: T) -> Self {
+        Self::new(
+            self.rotation.slerp(other.rotation, t),
+            self.translation + (other.translation - self.translation) * t,
+        )
+    }
+}
+
+impl<T: Component> Mul for Isometry2<T>
+where
+    for<'a> &'a T: RefComponent<T>,
+{
+    type Output = Self;
+
+    /// The composition: `a * b` moves by `b` and then by `a`.
+    fn mul(self, rhs: Self) -> Self {
+        Self {
+            rotation: self.rotation * rhs.rotation,
+            translation: self.transform_point(rhs.translation),
+        }
+    }
+}
+
+impl<T: Component> Mul for Isometry3<T>
+where
+    for<'a> &'a T: RefComponent<T>,
+{
+    type Output = Self;
+
+    /// The composition: `a * b` moves by `b` and then by `a`.
+    fn mul(self, rhs: Self) -> Self {
+        Self::new(
+            self.rotation * rhs.rotation,
+            self.transform_point(rhs.translation),
+        )
+    }
+}
+
+macro_rules! impl_similarity {
+    ($S:ident, $I:ident, $V:ident) => {
+        impl<T: Component> $S<T>
+        where
+            for<'a> &'a T: RefComponent<T>,
+        {
+            /// The scale, then the isometry.
+            #[inline]
+            pub fn new(isometry: $I<T>, scale: T) -> Self {
+                Self { isometry, scale }
+            }
+
+            /// The similarity of no motion.
+            pub fn identity() -> Self {
+                Self::new($I::identity(), T::one())
+            }
+
+            /// The uniform scale around the origin only.
+            pub fn from_scale(scale: T) -> Self {
+                Self::new($I::identity(), scale)
+            }
+
+            /// Scales, rotates and translates the point.
+            #[inline]
+            pub fn transform_point(self, p: $V<T>) -> $V<T> {
+                self.isometry.transform_point(p * self.scale)
+            }
+
+            /// Scales and rotates the direction, ignoring the translation.
+            #[inline]
+            pub fn transform_vector(self, v: $V<T>) -> $V<T> {
+                self.isometry.transform_vector(v * self.scale)
+            }
+
+            /// The inverse similarity, which undoes this one.
+            ///
+            /// Components are infinite or NaN if the scale is zero.
+            pub fn inverse(self) -> Self {
+                let mut isometry = self.isometry.inverse();
+                isometry.translation /= self.scale;
+                Self::new(isometry, self.scale.recip())
+            }
+
+            /// Interpolation by the fraction `t` from `self` to `other`: the
+            /// isometry as by its `interpolate` and the scale geometrically.
+            pub fn interpolate(self, other: Self, t: T) -> Self {
+                Self::new(
+                    self.isometry.interpolate(other.isometry, t),
+                    self.scale * (other.scale / self.scale).powf(t),
+                )
+            }
+        }
+
+        impl<T: Component> Mul for $S<T>
+        where
+            for<'a> &'a T: RefComponent<T>,
+        {
+            type Output = Self;
+
+            /// The composition: `a * b` transforms by `b` and then by `a`.
+            fn mul(self, rhs: Self) -> Self {
+                let mut isometry = self.isometry * rhs.isometry;
+                isometry.translation = self.transform_point(rhs.isometry.translation);
+                Self::new(isometry, self.scale * rhs.scale)
+            }
+        }
+
+        impl<T: Component> From<$I<T>> for $S<T>
+        where
+            for<'a> &'a T: RefComponent<T>,
+        {
+            #[inline]
+            fn from(isometry: $I<T>) -> Self {
+                Self::new(isometry, T::one())
+            }
+        }
+    };
+}
+
+impl_similarity!(Similarity2, Isometry2, Vec2D);
+impl_similarity!(Similarity3, Isometry3, Vec3D);
+
+/// The inverse of a square matrix by Gauss-Jordan elimination with partial
+/// pivoting, or `None` if it is singular.
+///
+/// Rows in and rows out, or equivalently columns in and columns out,
+/// since the inverse of the transpose is the transpose of the inverse.
+fn inverse_matrix<V: Components, const N: usize>(m: [V; N]) -> Option<[V; N]>
+where
+    for<'l> &'l V: VectorRefOps<V::Cmp, V>,
+{
+    let mut a = m;
+    let mut inv = [V::zero(); N];
+    for (i, row) in inv.iter_mut().enumerate() {
+        row[i] = V::Cmp::one();
+    }
+    for k in 0..N {
+        // NaN is taken as the largest so that it becomes the pivot and fails
+        let p = (k..N)
+            .max_by(|&i, &j| {
+                let (x, y) = (a[i][k].abs(), a[j][k].abs());
+                x.partial_cmp(&y)
+                    .unwrap_or_else(|| x.is_nan().cmp(&y.is_nan()))
+            })
+            .unwrap_or(k);
+        let pivot = a[p][k];
+        if pivot.is_zero() || !pivot.is_finite() {
+            return None;
+        }
+        a.swap(k, p);
+        inv.swap(k, p);
+        a[k] /= pivot;
+        inv[k] /= pivot;
+        let (ak, ik) = (a[k], inv[k]);
+        for i in (0..N).filter(|&i| i != k) {
+            let f = a[i][k];
+            a[i] -= ak * f;
+            inv[i] -= ik * f;
+        }
+    }
+    Some(inv)
+}
+
+macro_rules! impl_affine {
+    ($A:ident, $S:ident, $I:ident, $V:ident, $N:literal) => {
+        impl<T: Component> $A<T>
+        where
+            for<'a> &'a T: RefComponent<T>,
+        {
+            /// The linear map of the columns, then the translation.
+            #[inline]
+            pub fn new(linear: [$V<T>; $N], translation: $V<T>) -> Self {
+                Self {
+                    linear,
+                    translation,
+                }
+            }
+
+            /// The identity map.
+            pub fn identity() -> Self {
+                Self::from_scale($V::from([T::one(); $N]))
+            }
+
+            /// The translation only.
+            pub fn from_translation(translation: $V<T>) -> Self {
+                let mut m = Self::identity();
+                m.translation = translation;
+                m
+            }
+
+            /// The scale of each axis by the component, which may differ.
+            pub fn from_scale(scale: $V<T>) -> Self {
+                let mut linear = [$V::zero(); $N];
+                for (i, column) in linear.iter_mut().enumerate() {
+                    column[i] = scale[i];
+                }
+                Self::new(linear, $V::zero())
+            }
+
+            /// Transforms the point by the linear part and the translation.
+            #[inline]
+            pub fn transform_point(self, p: $V<T>) -> $V<T> {
+                self.transform_vector(p) + self.translation
+            }
+
+            /// Transforms the direction by the linear part only.
+            #[inline]
+            pub fn transform_vector(self, v: $V<T>) -> $V<T> {
+                (0..$N).map(|i| self.linear[i] * v[i]).sum()
+            }
+
+            /// The inverse map, or `None` if the linear part is singular.
+            pub fn inverse(self) -> Option<Self> {
+                let mut m = Self::new(inverse_matrix::<$V<T>, $N>(self.linear)?, $V::zero());
+                m.translation = -m.transform_vector(self.translation);
+                Some(m)
+            }
+
+            /// Linear interpolation of the linear part and the translation by
+            /// the fraction `t` from `self` to `other`.
+            ///
+            /// Unlike the interpolation of isometries, the maps in between are
+            /// not rigid even if both ends are.
+            pub fn interpolate(self, other: Self, t: T) -> Self {
+                let mut linear = self.linear;
+                for (a, b) in linear.iter_mut().zip(other.linear) {
+                    *a += (b - *a) * t;
+                }
+                Self::new(
+                    linear,
+                    self.translation + (other.translation - self.translation) * t,
+                )
+            }
+        }
+
+        impl<T: Component> Mul for $A<T>
+        where
+            for<'a> &'a T: RefComponent<T>,
+        {
+            type Output = Self;
+
+            /// The composition: `a * b` maps by `b` and then by `a`.
+            fn mul(self, rhs: Self) -> Self {
+                Self::new(
+                    rhs.linear.map(|c| self.transform_vector(c)),
+                    self.transform_point(rhs.translation),
+                )
+            }
+        }
+
+        impl<T: Component> From<$S<T>> for $A<T>
+        where
+            for<'a> &'a T: RefComponent<T>,
+        {
+            fn from(s: $S<T>) -> Self {
+                let linear = Self::identity().linear.map(|e| s.transform_vector(e));
+                Self::new(linear, s.isometry.translation)
+            }
+        }
+
+        impl<T: Component> From<$I<T>> for $A<T>
+        where
+            for<'a> &'a T: RefComponent<T>,
+        {
+            #[inline]
+            fn from(isometry: $I<T>) -> Self {
+                $S::from(isometry).into()
+            }
+        }
+    };
+}
+
+impl_affine!(Affine2, Similarity2, Isometry2, Vec2D, 2);
+impl_affine!(Affine3, Similarity3, Isometry3, Vec3D, 3);
+
+impl<T: Component> Affine2<T>
+where
+    for<'a> &'a T: RefComponent<T>,
+{
+    /// The determinant of the linear part, the ratio of the areas.
+    ///
+    /// Negative if the map reverses the orientation.
+    pub fn determinant(self) -> T {
+        let [a, b] = self.linear;
+        a.x * b.y - a.y * b.x
+    }
+}
+
+impl<T: Component> Affine3<T>
+where
+    for<'a> &'a T: RefComponent<T>,
+{
+    /// The determinant of the linear part, the ratio of the volumes.
+    ///
+    /// Negative if the map reverses the orientation.
+    pub fn determinant(self) -> T {
+        let [a, b, c] = self.linear;
+        a.x * (b.y * c.z - b.z * c.y) - a.y * (b.x * c.z - b.z * c.x)
+            + a.z * (b.x * c.y - b.y * c.x)
+    }
+}
+
+macro_rules! impl_mul_assign {
+    ($($M:ident),+) => {
+        $(
+            impl<T: Component> MulAssign for $M<T>
+            where
+                for<'a> &'a T: RefComponent<T>,
+            {
+                #[inline]
+                fn mul_assign(&mut self, rhs: Self) {
+                    *self = *self * rhs;
+                }
+            }
+        )+
+    };
+}
+
+impl_mul_assign!(
+    Isometry2,
+    Isometry3,
+    Similarity2,
+    Similarity3,
+    Affine2,
+    Affine3
+);
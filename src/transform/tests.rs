@@ -0,0 +1,285 @@
+use std::f64::consts::PI;
+
+use crate::testing::Lcg;
+use crate::*;
+
+impl Lcg {
+    /// A linear map near the identity, far from singular.
+    fn linear3(&mut self) -> [V3D64; 3] {
+        let e = Affine3::<f64>::identity().linear;
+        e.map(|c| c + self.vec3())
+    }
+
+    fn iso2(&mut self) -> Isometry2<f64> {
+        Isometry2::new(Rad(2.0 * PI * self.next()), self.vec2())
+    }
+
+    fn iso3(&mut self) -> Isometry3<f64> {
+        let r = Rotor3::from_axis_angle(self.vec3(), Rad(2.0 * PI * self.next()));
+        Isometry3::new(r, self.vec3())
+    }
+}
+
+#[test]
+fn isometries_of_plane() {
+    let mut rng = Lcg(1);
+    for _ in 0..100 {
+        let (a, b, p, v) = (rng.iso2(), rng.iso2(), rng.vec2(), rng.vec2());
+        assert!(
+            (a * b)
+                .transform_point(p)
+                .distance(a.transform_point(b.transform_point(p)))
+                < 1e-15
+        );
+        assert!(
+            a.inverse()
+                .transform_point(a.transform_point(p))
+                .distance(p)
+                < 1e-15
+        );
+        assert!((a * a.inverse()).translation.norm() < 1e-15);
+        assert!(
+            (a.transform_point(p).distance(a.transform_point(v)) - p.distance(v)).abs() < 1e-15
+        );
+        assert!(
+            a.transform_vector(v)
+                .distance(a.transform_point(v) - a.translation)
+                < 1e-15
+        );
+
+        assert_eq!(
+            a.interpolate(b, 0.0).transform_point(p),
+            a.transform_point(p)
+        );
+        assert!(
+            a.interpolate(b, 1.0)
+                .transform_point(p)
+                .distance(b.transform_point(p))
+                < 1e-15
+        );
+    }
+    // the shorter arc across ±π
+    let a = Isometry2::new(Rad(3.0), Vec2D::new(0.0, 0.0));
+    let b = Isometry2::new(Rad(-3.0), Vec2D::new(2.0, 4.0));
+    let m = a.interpolate(b, 0.5);
+    assert!((m.angle().0.abs() - PI).abs() < 1e-15);
+    assert_eq!(m.translation, Vec2D::new(1.0, 2.0));
+    assert!(
+        (Isometry2::from_angle(Rad(1.0)) * Isometry2::from_angle(Rad(2.0)))
+            .angle()
+            .0
+            - 3.0
+            < 1e-15
+    );
+}
+
+#[test]
+fn isometries_of_space() {
+    let mut rng = Lcg(2);
+    for _ in 0..100 {
+        let (a, b, p, v) = (rng.iso3(), rng.iso3(), rng.vec3(), rng.vec3());
+        assert!(
+            (a * b)
+                .transform_point(p)
+                .distance(a.transform_point(b.transform_point(p)))
+                < 1e-15
+        );
+        assert!(
+            a.inverse()
+                .transform_point(a.transform_point(p))
+                .distance(p)
+                < 1e-15
+        );
+        assert!(
+            (a.transform_point(p).distance(a.transform_point(v)) - p.distance(v)).abs() < 1e-15
+        );
+        assert!(
+            a.transform_vector(v)
+                .distance(a.transform_point(v) - a.translation)
+                < 1e-15
+        );
+
+        assert_eq!(
+            a.interpolate(b, 0.0).transform_point(p),
+            a.transform_point(p)
+        );
+        assert!(
+            a.interpolate(b, 1.0)
+                .transform_point(p)
+                .distance(b.transform_point(p))
+                < 1e-15
+        );
+        let m = a.interpolate(b, 0.5);
+        assert!(
+            m.translation
+                .distance((a.translation + b.translation) / 2.0)
+                < 1e-16
+        );
+        // halfway: the same rotation from `a` to `m` as from `m` to `b`
+        let (d1, d2) = (
+            (m * a.inverse()).rotation.rotate(v),
+            (b * m.inverse()).rotation.rotate(v),
+        );
+        assert!(d1.distance(d2) < 1e-14);
+    }
+    let mut m = Isometry3::from_translation(Vec3D::new(1.0, 2.0, 3.0));
+    m *= Isometry3::identity();
+    assert_eq!(
+        m.transform_vector(Vec3D::new(1.0, 0.0, 0.0)),
+        Vec3D::new(1.0, 0.0, 0.0)
+    );
+}
+
+#[test]
+fn similarities() {
+    let mut rng = Lcg(3);
+    for _ in 0..100 {
+        let a = Similarity3::new(rng.iso3(), rng.next() + 1.0);
+        let b = Similarity3::new(rng.iso3(), rng.next() + 1.0);
+        let (p, v) = (rng.vec3(), rng.vec3());
+        assert!(
+            (a * b)
+                .transform_point(p)
+                .distance(a.transform_point(b.transform_point(p)))
+                < 1e-15
+        );
+        assert!(
+            a.inverse()
+                .transform_point(a.transform_point(p))
+                .distance(p)
+                < 1e-15
+        );
+        assert!(
+            (a.transform_point(p).distance(a.transform_point(v)) - a.scale * p.distance(v)).abs()
+                < 1e-15
+        );
+        assert!(
+            a.transform_vector(v)
+                .distance(a.transform_point(v) - a.isometry.translation)
+                < 1e-15
+        );
+
+        let m = a.interpolate(b, 0.5);
+        assert!((m.scale - (a.scale * b.scale).sqrt()).abs() < 1e-15);
+        assert!(
+            a.interpolate(b, 1.0)
+                .transform_point(p)
+                .distance(b.transform_point(p))
+                < 1e-15
+        );
+
+        let a = Similarity2::new(rng.iso2(), rng.next() + 1.0);
+        let b = Similarity2::new(rng.iso2(), rng.next() + 1.0);
+        let p = rng.vec2();
+        assert!(
+            (a * b)
+                .transform_point(p)
+                .distance(a.transform_point(b.transform_point(p)))
+                < 1e-15
+        );
+        assert!(
+            a.inverse()
+                .transform_point(a.transform_point(p))
+                .distance(p)
+                < 1e-15
+        );
+    }
+    let iso = Isometry2::new(Rad(1.0), Vec2D::new(1.0, 2.0));
+    assert_eq!(
+        Similarity2::from(iso).transform_point(Vec2D::new(3.0, 4.0)),
+        iso.transform_point(Vec2D::new(3.0, 4.0))
+    );
+    assert_eq!(
+        Similarity2::from_scale(2.0).transform_point(Vec2D::new(3.0, 4.0)),
+        Vec2D::new(6.0, 8.0)
+    );
+}
+
+#[test]
+fn affine_maps() {
+    let mut rng = Lcg(4);
+    for _ in 0..100 {
+        let a = Affine3::new(rng.linear3(), rng.vec3());
+        let b = Affine3::new(rng.linear3(), rng.vec3());
+        let (p, v) = (rng.vec3(), rng.vec3());
+        assert!(
+            (a * b)
+                .transform_point(p)
+                .distance(a.transform_point(b.transform_point(p)))
+                < 1e-14
+        );
+        assert!(((a * b).determinant() - a.determinant() * b.determinant()).abs() < 1e-14);
+        assert!(
+            a.transform_vector(v)
+                .distance(a.transform_point(v) - a.translation)
+                < 1e-15
+        );
+        let inv = a.inverse().unwrap();
+        assert!(inv.transform_point(a.transform_point(p)).distance(p) < 1e-14);
+        assert!((inv.determinant() * a.determinant() - 1.0).abs() < 1e-14);
+
+        let s = Similarity3::new(rng.iso3(), rng.next() + 1.0);
+        let m = Affine3::from(s);
+        assert!(m.transform_point(p).distance(s.transform_point(p)) < 1e-15);
+        assert!((m.determinant() - s.scale.powi(3)).abs() < 1e-14);
+        let m = Affine3::from(s.isometry);
+        assert!(m.transform_point(p).distance(s.isometry.transform_point(p)) < 1e-15);
+
+        let a = Affine2::new(
+            [
+                Vec2D::new(1.0, 0.0) + rng.vec2(),
+                Vec2D::new(0.0, 1.0) + rng.vec2(),
+            ],
+            rng.vec2(),
+        );
+        let p = rng.vec2();
+        let inv = a.inverse().unwrap();
+        assert!(inv.transform_point(a.transform_point(p)).distance(p) < 1e-14);
+        let s = Similarity2::new(rng.iso2(), rng.next() + 1.0);
+        let m = Affine2::from(s);
+        assert!(m.transform_point(p).distance(s.transform_point(p)) < 1e-15);
+        assert!((m.determinant() - s.scale * s.scale).abs() < 1e-15);
+    }
+    // reflection
+    let m = Affine2::from_scale(Vec2D::new(-1.0, 1.0));
+    assert_eq!(m.determinant(), -1.0);
+    assert_eq!(
+        m.transform_point(Vec2D::new(2.0, 3.0)),
+        Vec2D::new(-2.0, 3.0)
+    );
+    // singular
+    let m = Affine2::new(
+        [Vec2D::new(1.0, 2.0), Vec2D::new(2.0, 4.0)],
+        Vec2D::new(0.0, 0.0),
+    );
+    assert_eq!(m.inverse(), None);
+    assert_eq!(
+        Affine3::<f64>::from_scale(Vec3D::new(1.0, 0.0, 1.0)).inverse(),
+        None
+    );
+    // not finite
+    let m = Affine2::new(
+        [Vec2D::new(f64::NAN, 0.0), Vec2D::new(0.0, 1.0)],
+        Vec2D::new(0.0, 0.0),
+    );
+    assert_eq!(m.inverse(), None);
+    let mut m = Affine3::<f64>::identity();
+    m.linear[2].y = f64::NAN;
+    assert_eq!(m.inverse(), None);
+    m.linear[2].y = f64::INFINITY;
+    assert_eq!(m.inverse(), None);
+
+    let a = Affine2::identity();
+    let b = Affine2::new(
+        [Vec2D::new(2.0, 0.0), Vec2D::new(0.0, 4.0)],
+        Vec2D::new(2.0, 2.0),
+    );
+    let m = a.interpolate(b, 0.5);
+    assert_eq!(m.linear, [Vec2D::new(1.5, 0.0), Vec2D::new(0.0, 2.5)]);
+    assert_eq!(m.translation, Vec2D::new(1.0, 1.0));
+    assert_eq!(
+        Affine3::from_translation(Vec3D::new(1.0, 2.0, 3.0))
+            .transform_point(Vec3D::new(1.0, 1.0, 1.0)),
+        Vec3D::new(2.0, 3.0, 4.0)
+    );
+}
@@ -0,0 +1,715 @@
+#[cfg(test)]
+mod tests;
+
+use std::cmp::Ordering;
+
+use num_traits::Float;
+
+use crate::components::Components;
+use crate::Aabb;
+use crate::Metric;
+use crate::Vec2D;
+use crate::Vec3D;
+use crate::VectorRefOps;
+
+/// Summary of the items in a subtree of [`Orthtree`], such as the total mass
+/// and the centre of mass.
+///
+/// Every node keeps its own summary up to date on insertion, removal,
+/// relocation and replacement of items.
+pub trait Aggregate<V, D> {
+    /// The summary of no items.
+    fn empty() -> Self;
+
+    /// Accumulates an item.
+    fn add(&mut self, position: &V, data: &D);
+
+    /// Accumulates the summary of another disjoint set of items.
+    fn merge(&mut self, other: &Self);
+}
+
+impl<V, D> Aggregate<V, D> for () {
+    #[inline]
+    fn empty() -> Self {}
+
+    #[inline]
+    fn add(&mut self, _: &V, _: &D) {}
+
+    #[inline]
+    fn merge(&mut self, _: &Self) {}
+}
+
+/// Handle of an item stored in [`Orthtree`].
+///
+/// The handle of a removed item may be reused by later insertions.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ItemId(usize);
+
+/// [`Orthtree`] keyed by [`Vec2D`].
+pub type QuadTree<T, D, A = ()> = Orthtree<Vec2D<T>, D, A>;
+
+/// [`Orthtree`] keyed by [`Vec3D`].
+pub type Octree<T, D, A = ()> = Orthtree<Vec3D<T>, D, A>;
+
+struct Entry<V, D> {
+    position: V,
+    data: D,
+}
+
+#[derive(Clone, Copy)]
+struct Config {
+    leaf_capacity: usize,
+    max_depth: usize,
+}
+
+struct Node<V, A> {
+    bounds: Aabb<V>,
+    len: usize,
+    aggregate: A,
+    kind: Kind<V, A>,
+}
+
+enum Kind<V, A> {
+    Leaf(Vec<usize>),
+    Branch(Box<[Node<V, A>]>),
+}
+
+/// Hierarchical subdivision of space with items placed at points,
+/// i.e. a quadtree for 2-dimensional vectors and an octree for 3-dimensional ones.
+///
+/// A leaf is split into `2^DIM` children at its center when it holds more
+/// items than the leaf capacity, and a branch collapses back into a leaf when
+/// its subtree no longer exceeds that capacity.
+/// The bounds grow automatically when an item is placed outside of them.
+///
+/// Each node maintains an [`Aggregate`] of its subtree.
+///
+/// # Examples
+///
+/// ```
+/// use ::ndvec::*;
+///
+/// #[derive(Debug, PartialEq)]
+/// struct Mass {
+///     total: f64,
+///     moment: V2D64,
+/// }
+///
+/// impl Aggregate<V2D64, f64> for Mass {
+///     fn empty() -> Self {
+///         Mass { total: 0.0, moment: Vec2D::new(0.0, 0.0) }
+///     }
+///
+///     fn add(&mut self, position: &V2D64, mass: &f64) {
+///         self.total += mass;
+///         self.moment += position * mass;
+///     }
+///
+///     fn merge(&mut self, other: &Self) {
+///         self.total += other.total;
+///         self.moment += other.moment;
+///     }
+/// }
+///
+/// let bounds = Aabb::new(Vec2D::new(0.0, 0.0), Vec2D::new(8.0, 8.0));
+/// let mut tree = QuadTree::<f64, f64, Mass>::new(bounds);
+/// let a = tree.insert(Vec2D::new(1.0, 1.0), 1.0);
+/// let b = tree.insert(Vec2D::new(5.0, 3.0), 3.0);
+/// assert_eq!(tree.aggregate().moment / tree.aggregate().total, Vec2D::new(4.0, 2.5));
+///
+/// tree.relocate(a, Vec2D::new(5.0, 7.0));
+/// assert_eq!(tree.aggregate().moment / tree.aggregate().total, Vec2D::new(5.0, 4.0));
+/// assert_eq!(tree.nearest(Vec2D::new(4.0, 4.0)), Some(b));
+/// ```
+pub struct Orthtree<V, D, A = ()> {
+    root: Node<V, A>,
+    entries: Vec<Option<Entry<V, D>>>,
+    vacant: Vec<usize>,
+    config: Config,
+}
+
+impl<V: Components, D, A: Aggregate<V, D>> Orthtree<V, D, A>
+where
+    for<'l> &'l V: VectorRefOps<V::Cmp, V>,
+{
+    /// The default number of items which a leaf holds before being split.
+    pub const DEFAULT_LEAF_CAPACITY: usize = 8;
+
+    /// The default depth limit of the tree, below which leaves are never split.
+    pub const DEFAULT_MAX_DEPTH: usize = 24;
+
+    /// Creates an empty tree covering the bounds.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the bounds are not finite or have no volume.
+    pub fn new(bounds: Aabb<V>) -> Self {
+        Self::with_config(bounds, Self::DEFAULT_LEAF_CAPACITY, Self::DEFAULT_MAX_DEPTH)
+    }
+
+    /// Creates an empty tree covering the bounds with the given leaf capacity
+    /// and depth limit.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the bounds are not finite or have no volume,
+    /// or if `leaf_capacity` is zero.
+    pub fn with_config(bounds: Aabb<V>, leaf_capacity: usize, max_depth: usize) -> Self {
+        assert!(leaf_capacity > 0, "leaf capacity must be positive");
+        assert!(
+            bounds.min.is_finite()
+                && bounds.max.is_finite()
+                && (0..V::DIM).all(|i| bounds.min[i] < bounds.max[i]),
+            "bounds must be finite and have volume: {bounds:?}"
+        );
+        Self {
+            root: Node::leaf::<D>(bounds),
+            entries: Vec::new(),
+            vacant: Vec::new(),
+            config: Config {
+                leaf_capacity,
+                max_depth,
+            },
+        }
+    }
+
+    /// The number of items.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.root.len
+    }
+
+    /// Checks if there are no items.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.root.len == 0
+    }
+
+    /// The bounds covered by the root node.
+    #[inline]
+    pub fn bounds(&self) -> Aabb<V> {
+        self.root.bounds
+    }
+
+    /// The aggregate of all the items.
+    #[inline]
+    pub fn aggregate(&self) -> &A {
+        &self.root.aggregate
+    }
+
+    /// The root node, for custom traversals.
+    #[inline]
+    pub fn root(&self) -> NodeRef<'_, V, D, A> {
+        NodeRef {
+            node: &self.root,
+            entries: &self.entries,
+        }
+    }
+
+    /// The position and the data of the item.
+    pub fn get(&self, id: ItemId) -> Option<(V, &D)> {
+        let entry = self.entries.get(id.0)?.as_ref()?;
+        Some((entry.position, &entry.data))
+    }
+
+    /// Iterates over all the items in the order of their handles.
+    pub fn iter(&self) -> impl Iterator<Item = (ItemId, V, &D)> + '_ {
+        self.entries.iter().enumerate().filter_map(|(i, e)| {
+            let e = e.as_ref()?;
+            Some((ItemId(i), e.position, &e.data))
+        })
+    }
+
+    /// Inserts an item at the position, growing the bounds if needed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the position is not finite.
+    pub fn insert(&mut self, position: V, data: D) -> ItemId {
+        assert!(
+            position.is_finite(),
+            "position must be finite: {position:?}"
+        );
+        let entry = Some(Entry { position, data });
+        let index = match self.vacant.pop() {
+            Some(index) => {
+                self.entries[index] = entry;
+                index
+            }
+            None => {
+                self.entries.push(entry);
+                self.entries.len() - 1
+            }
+        };
+        if self.root.bounds.contains(position) {
+            self.root.insert(index, &self.entries, 0, self.config);
+        } else {
+            self.rebuild(grown(self.root.bounds, position));
+        }
+        ItemId(index)
+    }
+
+    /// Removes the item, returning its position and data.
+    pub fn remove(&mut self, id: ItemId) -> Option<(V, D)> {
+        let entry = self.entries.get_mut(id.0)?.take()?;
+        self.root
+            .remove(id.0, entry.position, &self.entries, self.config);
+        self.vacant.push(id.0);
+        Some((entry.position, entry.data))
+    }
+
+    /// Moves the item to the position, growing the bounds if needed.
+    ///
+    /// Returns `false` if there is no such item.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the position is not finite.
+    pub fn relocate(&mut self, id: ItemId, position: V) -> bool {
+        assert!(
+            position.is_finite(),
+            "position must be finite: {position:?}"
+        );
+        let Some(entry) = self.entries.get_mut(id.0).and_then(Option::as_mut) else {
+            return false;
+        };
+        let old = std::mem::replace(&mut entry.position, position);
+        if self.root.bounds.contains(position) {
+            self.root.remove(id.0, old, &self.entries, self.config);
+            self.root.insert(id.0, &self.entries, 0, self.config);
+        } else {
+            self.rebuild(grown(self.root.bounds, position));
+        }
+        true
+    }
+
+    /// Replaces the data of the item, returning the old one.
+    pub fn replace(&mut self, id: ItemId, data: D) -> Option<D> {
+        let entry = self.entries.get_mut(id.0)?.as_mut()?;
+        let old = std::mem::replace(&mut entry.data, data);
+        let position = entry.position;
+        self.root.refresh_path(position, &self.entries);
+        Some(old)
+    }
+
+    /// Removes all the items, keeping the current bounds.
+    pub fn clear(&mut self) {
+        self.root = Node::leaf::<D>(self.root.bounds);
+        self.entries.clear();
+        self.vacant.clear();
+    }
+
+    /// The items inside of the region or on its surface.
+    pub fn query_aabb(&self, region: &Aabb<V>) -> Vec<ItemId> {
+        let mut found = Vec::new();
+        self.root.query(
+            &|b| b.intersects(region),
+            &|p| region.contains(p),
+            &self.entries,
+            &mut found,
+        );
+        found
+    }
+
+    /// The items whose distance from the center is not greater than the radius.
+    pub fn query_ball(&self, center: V, radius: V::Cmp) -> Vec<ItemId> {
+        let radius_sqr = radius * radius;
+        let mut found = Vec::new();
+        self.root.query(
+            &|b| b.distance_sqr(center) <= radius_sqr,
+            &|p| p.distance_sqr(center) <= radius_sqr,
+            &self.entries,
+            &mut found,
+        );
+        found
+    }
+
+    /// The items whose distance from the center in the metric is not greater than the radius.
+    pub fn query_ball_by<M: Metric<V>>(
+        &self,
+        metric: &M,
+        center: V,
+        radius: V::Cmp,
+    ) -> Vec<ItemId> {
+        let mut found = Vec::new();
+        self.root.query(
+            &|b| metric.distance_to_box(b, center) <= radius,
+            &|p| metric.distance(p, center) <= radius,
+            &self.entries,
+            &mut found,
+        );
+        found
+    }
+
+    /// The nearest item from the point.
+    #[inline]
+    pub fn nearest(&self, point: V) -> Option<ItemId> {
+        self.k_nearest(point, 1).pop()
+    }
+
+    /// At most `k` items nearest from the point, in ascending order of the distance.
+    pub fn k_nearest(&self, point: V, k: usize) -> Vec<ItemId> {
+        self.k_nearest_with(&|b| b.distance_sqr(point), &|p| p.distance_sqr(point), k)
+    }
+
+    /// The nearest item from the point in the metric.
+    #[inline]
+    pub fn nearest_by<M: Metric<V>>(&self, metric: &M, point: V) -> Option<ItemId> {
+        self.k_nearest_by(metric, point, 1).pop()
+    }
+
+    /// At most `k` items nearest from the point in the metric, in ascending order of the
+    /// distance.
+    ///
+    /// The subtrees are pruned by [`Metric::distance_to_box`].
+    pub fn k_nearest_by<M: Metric<V>>(&self, metric: &M, point: V, k: usize) -> Vec<ItemId> {
+        self.k_nearest_with(
+            &|b| metric.distance_to_box(b, point),
+            &|p| metric.distance(p, point),
+            k,
+        )
+    }
+
+    fn k_nearest_with(
+        &self,
+        bound: &impl Fn(&Aabb<V>) -> V::Cmp,
+        distance: &impl Fn(V) -> V::Cmp,
+        k: usize,
+    ) -> Vec<ItemId> {
+        let mut best = Vec::with_capacity(k + 1);
+        if k > 0 {
+            self.root
+                .nearest(bound, distance, k, &self.entries, &mut best);
+        }
+        best.into_iter().map(|(_, i)| ItemId(i)).collect()
+    }
+
+    fn rebuild(&mut self, bounds: Aabb<V>) {
+        let mut root = Node::leaf::<D>(bounds);
+        for (index, entry) in self.entries.iter().enumerate() {
+            if entry.is_some() {
+                root.insert(index, &self.entries, 0, self.config);
+            }
+        }
+        self.root = root;
+    }
+}
+
+/// Read-only view of a node of [`Orthtree`].
+pub struct NodeRef<'a, V, D, A> {
+    node: &'a Node<V, A>,
+    entries: &'a [Option<Entry<V, D>>],
+}
+
+impl<V, D, A> Clone for NodeRef<'_, V, D, A> {
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<V, D, A> Copy for NodeRef<'_, V, D, A> {}
+
+impl<'a, V: Copy, D, A> NodeRef<'a, V, D, A> {
+    /// The region covered by the node.
+    #[inline]
+    pub fn bounds(&self) -> &'a Aabb<V> {
+        &self.node.bounds
+    }
+
+    /// The number of items in the subtree.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.node.len
+    }
+
+    /// Checks if there are no items in the subtree.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.node.len == 0
+    }
+
+    /// The aggregate of the subtree.
+    #[inline]
+    pub fn aggregate(&self) -> &'a A {
+        &self.node.aggregate
+    }
+
+    /// Checks if the node has no children.
+    #[inline]
+    pub fn is_leaf(&self) -> bool {
+        matches!(self.node.kind, Kind::Leaf(_))
+    }
+
+    /// The children of a branch, or nothing for a leaf.
+    pub fn children(&self) -> impl Iterator<Item = NodeRef<'a, V, D, A>> + 'a {
+        let children: &'a [Node<V, A>] = match &self.node.kind {
+            Kind::Leaf(_) => &[],
+            Kind::Branch(children) => children,
+        };
+        let entries = self.entries;
+        children.iter().map(move |node| NodeRef { node, entries })
+    }
+
+    /// The items held by a leaf, or nothing for a branch.
+    pub fn items(&self) -> impl Iterator<Item = (ItemId, V, &'a D)> + 'a {
+        let indices: &'a [usize] = match &self.node.kind {
+            Kind::Leaf(indices) => indices,
+            Kind::Branch(_) => &[],
+        };
+        let entries = self.entries;
+        indices.iter().map(move |&i| {
+            let entry = live(entries, i);
+            (ItemId(i), entry.position, &entry.data)
+        })
+    }
+}
+
+#[inline]
+fn live<V, D>(entries: &[Option<Entry<V, D>>], index: usize) -> &Entry<V, D> {
+    entries[index]
+        .as_ref()
+        .expect("nodes must refer to live entries")
+}
+
+fn grown<V: Components>(mut bounds: Aabb<V>, position: V) -> Aabb<V>
+where
+    for<'l> &'l V: VectorRefOps<V::Cmp, V>,
+{
+    while !bounds.contains(position) {
+        for i in 0..V::DIM {
+            let size = bounds.max[i] - bounds.min[i];
+            if position[i] < bounds.min[i] {
+                bounds.min[i] -= size;
+            } else if position[i] > bounds.max[i] {
+                bounds.max[i] += size;
+            }
+        }
+    }
+    bounds
+}
+
+fn orthant<V: Components>(bounds: &Aabb<V>, position: V) -> usize
+where
+    for<'l> &'l V: VectorRefOps<V::Cmp, V>,
+{
+    let center = bounds.center();
+    (0..V::DIM)
+        .filter(|&i| position[i] >= center[i])
+        .fold(0, |k, i| k | 1 << i)
+}
+
+fn child_bounds<V: Components>(bounds: &Aabb<V>, orthant: usize) -> Aabb<V>
+where
+    for<'l> &'l V: VectorRefOps<V::Cmp, V>,
+{
+    let center = bounds.center();
+    let mut child = *bounds;
+    for i in 0..V::DIM {
+        if orthant >> i & 1 == 1 {
+            child.min[i] = center[i];
+        } else {
+            child.max[i] = center[i];
+        }
+    }
+    child
+}
+
+fn by_distance<T: Float>(a: &T, b: &T) -> Ordering {
+    a.partial_cmp(b).unwrap_or(Ordering::Equal)
+}
+
+impl<V: Components, A> Node<V, A>
+where
+    for<'l> &'l V: VectorRefOps<V::Cmp, V>,
+{
+    fn leaf<D>(bounds: Aabb<V>) -> Self
+    where
+        A: Aggregate<V, D>,
+    {
+        Self {
+            bounds,
+            len: 0,
+            aggregate: A::empty(),
+            kind: Kind::Leaf(Vec::new()),
+        }
+    }
+
+    fn insert<D>(
+        &mut self,
+        index: usize,
+        entries: &[Option<Entry<V, D>>],
+        depth: usize,
+        config: Config,
+    ) where
+        A: Aggregate<V, D>,
+    {
+        self.len += 1;
+        let overflow = match &mut self.kind {
+            Kind::Leaf(indices) => {
+                indices.push(index);
+                indices.len() > config.leaf_capacity && depth < config.max_depth
+            }
+            Kind::Branch(children) => {
+                let k = orthant(&self.bounds, live(entries, index).position);
+                children[k].insert(index, entries, depth + 1, config);
+                false
+            }
+        };
+        if overflow {
+            self.split(entries, depth, config);
+        }
+        self.refresh(entries);
+    }
+
+    fn split<D>(&mut self, entries: &[Option<Entry<V, D>>], depth: usize, config: Config)
+    where
+        A: Aggregate<V, D>,
+    {
+        let Kind::Leaf(indices) = std::mem::replace(&mut self.kind, Kind::Leaf(Vec::new())) else {
+            unreachable!("only leaves are split");
+        };
+        let mut children: Box<[Self]> = (0..1 << V::DIM)
+            .map(|k| Self::leaf::<D>(child_bounds(&self.bounds, k)))
+            .collect();
+        for index in indices {
+            let k = orthant(&self.bounds, live(entries, index).position);
+            children[k].insert(index, entries, depth + 1, config);
+        }
+        self.kind = Kind::Branch(children);
+    }
+
+    fn remove<D>(
+        &mut self,
+        index: usize,
+        position: V,
+        entries: &[Option<Entry<V, D>>],
+        config: Config,
+    ) where
+        A: Aggregate<V, D>,
+    {
+        self.len -= 1;
+        match &mut self.kind {
+            Kind::Leaf(indices) => {
+                let at = indices
+                    .iter()
+                    .position(|&i| i == index)
+                    .expect("removed item must be in the leaf of its position");
+                indices.swap_remove(at);
+            }
+            Kind::Branch(children) => {
+                let k = orthant(&self.bounds, position);
+                children[k].remove(index, position, entries, config);
+                if self.len <= config.leaf_capacity {
+                    let mut indices = Vec::with_capacity(self.len);
+                    self.collect(&mut indices);
+                    self.kind = Kind::Leaf(indices);
+                }
+            }
+        }
+        self.refresh(entries);
+    }
+
+    fn refresh_path<D>(&mut self, position: V, entries: &[Option<Entry<V, D>>])
+    where
+        A: Aggregate<V, D>,
+    {
+        if let Kind::Branch(children) = &mut self.kind {
+            children[orthant(&self.bounds, position)].refresh_path(position, entries);
+        }
+        self.refresh(entries);
+    }
+
+    fn refresh<D>(&mut self, entries: &[Option<Entry<V, D>>])
+    where
+        A: Aggregate<V, D>,
+    {
+        let mut aggregate = A::empty();
+        match &self.kind {
+            Kind::Leaf(indices) => {
+                for &i in indices {
+                    let entry = live(entries, i);
+                    aggregate.add(&entry.position, &entry.data);
+                }
+            }
+            Kind::Branch(children) => {
+                for child in children.iter() {
+                    aggregate.merge(&child.aggregate);
+                }
+            }
+        }
+        self.aggregate = aggregate;
+    }
+
+    fn collect(&self, indices: &mut Vec<usize>) {
+        match &self.kind {
+            Kind::Leaf(leaf) => indices.extend_from_slice(leaf),
+            Kind::Branch(children) => children.iter().for_each(|c| c.collect(indices)),
+        }
+    }
+
+    fn query<D>(
+        &self,
+        visit: &impl Fn(&Aabb<V>) -> bool,
+        accept: &impl Fn(V) -> bool,
+        entries: &[Option<Entry<V, D>>],
+        found: &mut Vec<ItemId>,
+    ) {
+        if self.len == 0 || !visit(&self.bounds) {
+            return;
+        }
+        match &self.kind {
+            Kind::Leaf(indices) => found.extend(
+                indices
+                    .iter()
+                    .filter(|&&i| accept(live(entries, i).position))
+                    .map(|&i| ItemId(i)),
+            ),
+            Kind::Branch(children) => {
+                for child in children.iter() {
+                    child.query(visit, accept, entries, found);
+                }
+            }
+        }
+    }
+
+    fn nearest<D>(
+        &self,
+        bound: &impl Fn(&Aabb<V>) -> V::Cmp,
+        distance: &impl Fn(V) -> V::Cmp,
+        k: usize,
+        entries: &[Option<Entry<V, D>>],
+        best: &mut Vec<(V::Cmp, usize)>,
+    ) {
+        let worst = |best: &Vec<(V::Cmp, usize)>| match best.last() {
+            Some(&(d, _)) if best.len() == k => d,
+            _ => V::Cmp::infinity(),
+        };
+        match &self.kind {
+            Kind::Leaf(indices) => {
+                for &i in indices {
+                    let d = distance(live(entries, i).position);
+                    if d < worst(best) {
+                        let at = best.partition_point(|(e, _)| *e <= d);
+                        best.insert(at, (d, i));
+                        best.truncate(k);
+                    }
+                }
+            }
+            Kind::Branch(children) => {
+                let mut order: Vec<_> = children
+                    .iter()
+                    .filter(|c| c.len > 0)
+                    .map(|c| (bound(&c.bounds), c))
+                    .collect();
+                order.sort_by(|a, b| by_distance(&a.0, &b.0));
+                for (d, child) in order {
+                    if d > worst(best) {
+                        break;
+                    }
+                    child.nearest(bound, distance, k, entries, best);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,273 @@
+use crate::testing::Lcg;
+use crate::*;
+
+impl Lcg {
+    /// A point in the unit cube.
+    fn cube(&mut self) -> V3D64 {
+        Vec3D::new(self.unit(), self.unit(), self.unit())
+    }
+}
+
+#[derive(Debug)]
+struct Mass {
+    total: f64,
+    moment: V3D64,
+}
+
+impl Aggregate<V3D64, f64> for Mass {
+    fn empty() -> Self {
+        Mass {
+            total: 0.0,
+            moment: Vec3D::zero(),
+        }
+    }
+
+    fn add(&mut self, position: &V3D64, mass: &f64) {
+        self.total += mass;
+        self.moment += position * mass;
+    }
+
+    fn merge(&mut self, other: &Self) {
+        self.total += other.total;
+        self.moment += other.moment;
+    }
+}
+
+fn unit_cube() -> Aabb<V3D64> {
+    Aabb::new(Vec3D::new(0.0, 0.0, 0.0), Vec3D::new(1.0, 1.0, 1.0))
+}
+
+fn check_aggregates(node: NodeRef<'_, V3D64, f64, Mass>) -> usize {
+    let len = if node.is_leaf() {
+        node.items()
+            .inspect(|&(_, p, _)| assert!(node.bounds().contains(p)))
+            .count()
+    } else {
+        let mut total = 0f64;
+        let mut len = 0;
+        for child in node.children() {
+            len += check_aggregates(child);
+            total += child.aggregate().total;
+        }
+        assert!((total - node.aggregate().total).abs() < 1e-9);
+        len
+    };
+    assert_eq!(len, node.len());
+    len
+}
+
+#[test]
+fn insert_and_get() {
+    let mut tree = Octree::<f64, &str>::new(unit_cube());
+    assert!(tree.is_empty());
+    let a = tree.insert(Vec3D::new(0.1, 0.2, 0.3), "a");
+    let b = tree.insert(Vec3D::new(0.9, 0.8, 0.7), "b");
+    assert_eq!(tree.len(), 2);
+    assert_eq!(tree.get(a), Some((Vec3D::new(0.1, 0.2, 0.3), &"a")));
+    assert_eq!(tree.get(b), Some((Vec3D::new(0.9, 0.8, 0.7), &"b")));
+    assert_eq!(tree.iter().count(), 2);
+}
+
+#[test]
+fn split_and_collapse() {
+    let mut tree = Octree::<f64, f64, Mass>::with_config(unit_cube(), 2, 8);
+    let mut rng = Lcg(1);
+    let ids: Vec<_> = (0..100).map(|_| tree.insert(rng.cube(), 1.0)).collect();
+    assert!(!tree.root().is_leaf());
+    assert_eq!(check_aggregates(tree.root()), 100);
+
+    for id in ids {
+        assert!(tree.remove(id).is_some());
+        check_aggregates(tree.root());
+    }
+    assert!(tree.is_empty());
+    assert!(tree.root().is_leaf());
+    assert_eq!(tree.aggregate().total, 0.0);
+}
+
+#[test]
+fn remove() {
+    let mut tree = QuadTree::<f64, i32>::new(Aabb::new(Vec2D::new(0.0, 0.0), Vec2D::new(1.0, 1.0)));
+    let a = tree.insert(Vec2D::new(0.5, 0.5), 1);
+    assert_eq!(tree.remove(a), Some((Vec2D::new(0.5, 0.5), 1)));
+    assert_eq!(tree.remove(a), None);
+    assert_eq!(tree.get(a), None);
+    assert!(!tree.relocate(a, Vec2D::new(0.1, 0.1)));
+}
+
+#[test]
+fn identical_positions() {
+    let mut tree = Octree::<f64, usize>::with_config(unit_cube(), 1, 4);
+    for i in 0..20 {
+        tree.insert(Vec3D::new(0.25, 0.25, 0.25), i);
+    }
+    assert_eq!(tree.len(), 20);
+    assert_eq!(tree.k_nearest(Vec3D::new(0.0, 0.0, 0.0), 30).len(), 20);
+}
+
+#[test]
+fn aggregate_center_of_mass() {
+    let mut tree = Octree::<f64, f64, Mass>::with_config(unit_cube(), 4, 16);
+    let mut rng = Lcg(2);
+    let ids: Vec<_> = (0..200)
+        .map(|_| tree.insert(rng.cube(), rng.unit() + 0.5))
+        .collect();
+    for &id in ids.iter().step_by(3) {
+        tree.relocate(id, rng.cube());
+    }
+    for &id in ids.iter().step_by(7) {
+        tree.replace(id, 2.0);
+    }
+    for &id in ids.iter().skip(1).step_by(5) {
+        tree.remove(id);
+    }
+    check_aggregates(tree.root());
+
+    let (total, moment) = tree
+        .iter()
+        .fold((0f64, V3D64::zero()), |(t, m), (_, p, w)| {
+            (t + w, m + p * *w)
+        });
+    assert!((tree.aggregate().total - total).abs() < 1e-9);
+    assert!(tree.aggregate().moment.distance(moment) < 1e-9);
+}
+
+#[test]
+fn grow_bounds() {
+    let mut tree = Octree::<f64, f64, Mass>::with_config(unit_cube(), 2, 16);
+    let mut rng = Lcg(3);
+    let ids: Vec<_> = (0..50).map(|_| tree.insert(rng.cube(), 1.0)).collect();
+    let far = tree.insert(Vec3D::new(-3.0, 0.5, 7.0), 1.0);
+    assert!(tree.bounds().contains(Vec3D::new(-3.0, 0.5, 7.0)));
+    assert!(tree.bounds().contains_aabb(&unit_cube()));
+    tree.relocate(ids[0], Vec3D::new(20.0, 20.0, -20.0));
+    assert!(tree.bounds().contains(Vec3D::new(20.0, 20.0, -20.0)));
+    assert_eq!(tree.len(), 51);
+    assert_eq!(check_aggregates(tree.root()), 51);
+    assert_eq!(tree.nearest(Vec3D::new(-3.0, 0.0, 7.0)), Some(far));
+}
+
+#[test]
+#[should_panic]
+fn non_finite_position() {
+    let mut tree = Octree::<f64, ()>::new(unit_cube());
+    tree.insert(Vec3D::new(f64::NAN, 0.0, 0.0), ());
+}
+
+#[test]
+fn query_aabb() {
+    let mut tree = Octree::<f64, ()>::with_config(unit_cube(), 3, 16);
+    let mut rng = Lcg(4);
+    for _ in 0..300 {
+        tree.insert(rng.cube(), ());
+    }
+    let region = Aabb::new(Vec3D::new(0.2, 0.1, 0.4), Vec3D::new(0.6, 0.7, 0.5));
+    let mut found = tree.query_aabb(&region);
+    found.sort();
+    let expected: Vec<_> = tree
+        .iter()
+        .filter(|&(_, p, _)| region.contains(p))
+        .map(|(id, _, _)| id)
+        .collect();
+    assert!(!expected.is_empty());
+    assert_eq!(found, expected);
+}
+
+#[test]
+fn query_ball() {
+    let mut tree = Octree::<f64, ()>::with_config(unit_cube(), 3, 16);
+    let mut rng = Lcg(5);
+    for _ in 0..300 {
+        tree.insert(rng.cube(), ());
+    }
+    let center = Vec3D::new(0.3, 0.6, 0.5);
+    let mut found = tree.query_ball(center, 0.25);
+    found.sort();
+    let expected: Vec<_> = tree
+        .iter()
+        .filter(|&(_, p, _)| p.distance(center) <= 0.25)
+        .map(|(id, _, _)| id)
+        .collect();
+    assert!(!expected.is_empty());
+    assert_eq!(found, expected);
+}
+
+#[test]
+fn nearest() {
+    let mut tree = QuadTree::<f64, ()>::with_config(
+        Aabb::new(Vec2D::new(0.0, 0.0), Vec2D::new(1.0, 1.0)),
+        2,
+        16,
+    );
+    assert_eq!(tree.nearest(Vec2D::new(0.5, 0.5)), None);
+    let mut rng = Lcg(6);
+    for _ in 0..500 {
+        tree.insert(Vec2D::new(rng.unit(), rng.unit()), ());
+    }
+    for _ in 0..20 {
+        let q = Vec2D::new(rng.unit() * 1.5 - 0.25, rng.unit() * 1.5 - 0.25);
+        let mut all: Vec<_> = tree
+            .iter()
+            .map(|(id, p, _)| (p.distance_sqr(q), id))
+            .collect();
+        all.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap());
+        assert_eq!(tree.nearest(q), Some(all[0].1));
+        let k: Vec<_> = tree
+            .k_nearest(q, 5)
+            .into_iter()
+            .map(|id| tree.get(id).unwrap().0.distance_sqr(q))
+            .collect();
+        assert_eq!(k, all[..5].iter().map(|a| a.0).collect::<Vec<_>>());
+    }
+    assert!(tree.k_nearest(Vec2D::new(0.5, 0.5), 0).is_empty());
+}
+
+fn check_metric<M: Metric<V3D64>>(metric: &M) {
+    let mut tree = Octree::<f64, ()>::with_config(unit_cube(), 3, 16);
+    let mut rng = Lcg(7);
+    for _ in 0..300 {
+        tree.insert(rng.cube(), ());
+    }
+    for _ in 0..20 {
+        let q = rng.cube() * 1.5 - Vec3D::new(0.25, 0.25, 0.25);
+        let mut found = tree.query_ball_by(metric, q, 0.3);
+        found.sort();
+        let expected: Vec<_> = tree
+            .iter()
+            .filter(|&(_, p, _)| metric.distance(p, q) <= 0.3)
+            .map(|(id, _, _)| id)
+            .collect();
+        assert_eq!(found, expected);
+
+        let mut all: Vec<_> = tree
+            .iter()
+            .map(|(id, p, _)| (metric.distance(p, q), id))
+            .collect();
+        all.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap());
+        assert_eq!(tree.nearest_by(metric, q), Some(all[0].1));
+        let k: Vec<_> = tree
+            .k_nearest_by(metric, q, 5)
+            .into_iter()
+            .map(|id| metric.distance(tree.get(id).unwrap().0, q))
+            .collect();
+        assert_eq!(k, all[..5].iter().map(|a| a.0).collect::<Vec<_>>());
+    }
+    assert!(tree
+        .k_nearest_by(metric, Vec3D::new(0.5, 0.5, 0.5), 0)
+        .is_empty());
+}
+
+#[test]
+fn queries_by_metric() {
+    check_metric(&Manhattan);
+    check_metric(&Chebyshev);
+    check_metric(&Euclidean);
+    check_metric(&Minkowski::new(3.0));
+    // without the bound of the boxes
+    let covariance = [
+        Vec3D::new(2.0, 0.5, 0.0),
+        Vec3D::new(0.5, 1.0, 0.0),
+        Vec3D::new(0.0, 0.0, 0.5),
+    ];
+    check_metric(&Mahalanobis::new(&covariance).unwrap());
+}